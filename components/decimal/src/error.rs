@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Error types for decimal formatting.
+//! Error types for decimal formatting and parsing.
 
 use displaydoc::Display;
 
@@ -15,6 +15,10 @@ pub enum DecimalError {
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("error loading data: {0}")]
     Data(icu_provider::DataError),
+    /// The input could not be parsed as a number. The value is the byte index of the first
+    /// unexpected character, or the length of the input if the number is incomplete.
+    #[displaydoc("invalid number at byte index {0}")]
+    Parse(usize),
 }
 
 #[cfg(feature = "std")]
@@ -7,6 +7,7 @@
 use crate::grouper;
 use crate::options::*;
 use crate::provider::*;
+use alloc::borrow::Cow;
 use fixed_decimal::FixedDecimal;
 use fixed_decimal::Sign;
 use writeable::Writeable;
@@ -15,18 +16,38 @@ use writeable::Writeable;
 /// Use [`Writeable`][Writeable] to render the formatted decimal to a string or buffer.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormattedFixedDecimal<'l> {
-    pub(crate) value: &'l FixedDecimal,
+    pub(crate) value: Cow<'l, FixedDecimal>,
     pub(crate) options: &'l FixedDecimalFormatterOptions,
     pub(crate) symbols: &'l DecimalSymbolsV1<'l>,
+    /// Present if the formatter was created with the accounting data.
+    pub(crate) accounting_symbols: Option<&'l AccountingSymbolsV1<'l>>,
 }
 
 impl<'l> FormattedFixedDecimal<'l> {
     fn get_affixes(&self) -> Option<&AffixesV1> {
-        match self.value.sign() {
-            Sign::None => None,
-            Sign::Negative => Some(&self.symbols.minus_sign_affixes),
-            Sign::Positive => Some(&self.symbols.plus_sign_affixes),
+        sign_affixes(
+            self.symbols,
+            self.accounting_symbols,
+            self.options,
+            self.value.sign(),
+        )
+    }
+}
+
+/// Returns the affixes to apply to a number with the given sign, if any.
+pub(crate) fn sign_affixes<'l>(
+    symbols: &'l DecimalSymbolsV1<'l>,
+    accounting_symbols: Option<&'l AccountingSymbolsV1<'l>>,
+    options: &FixedDecimalFormatterOptions,
+    sign: Sign,
+) -> Option<&'l AffixesV1<'l>> {
+    match (sign, options.negative_style, accounting_symbols) {
+        (Sign::None, _, _) => None,
+        (Sign::Negative, NegativeStyle::Accounting, Some(accounting_symbols)) => {
+            Some(&accounting_symbols.minus_sign_affixes)
         }
+        (Sign::Negative, _, _) => Some(&symbols.minus_sign_affixes),
+        (Sign::Positive, _, _) => Some(&symbols.plus_sign_affixes),
     }
 }
 
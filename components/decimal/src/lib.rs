@@ -9,8 +9,14 @@
 //! This module is published as its own crate ([`icu_decimal`](https://docs.rs/icu_decimal/latest/icu_decimal/))
 //! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
 //!
-//! Support for currencies, measurement units, and compact notation is planned. To track progress,
-//! follow [icu4x#275](https://github.com/unicode-org/icu4x/issues/275).
+//! Percentages and permilles are formatted with [`PercentFormatter`], numbers in scientific
+//! or engineering notation with [`ScientificFormatter`], and ranges of numbers with
+//! [`FixedDecimalRangeFormatter`]. Localized numbers are parsed with [`FixedDecimalParser`].
+//!
+//! Currency amounts and measurement units are formatted by the experimental
+//! [`icu_dimension`](https://docs.rs/icu_dimension/latest/icu_dimension/) crate, and compact
+//! notation, such as "1.2K", by the experimental
+//! [`icu_compactdecimal`](https://docs.rs/icu_compactdecimal/latest/icu_compactdecimal/) crate.
 //!
 //! # Examples
 //!
@@ -57,7 +63,9 @@
 //! ### Format a number using an alternative numbering system
 //!
 //! Numbering systems specified in the `-u-nu` subtag will be followed as long as the locale has
-//! symbols for that numbering system.
+//! symbols for that numbering system. The CLDR aliases `native`, `traditional` and `finance`
+//! select the corresponding numbering system of the locale; otherwise, the default numbering
+//! system of the locale is used.
 //!
 //! ```
 //! use fixed_decimal::FixedDecimal;
@@ -75,6 +83,24 @@
 //! let fixed_decimal = FixedDecimal::from(1000007);
 //!
 //! assert_writeable_eq!(fdf.format(&fixed_decimal), "๑,๐๐๐,๐๐๗");
+//!
+//! let fdf = FixedDecimalFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("th-u-nu-native").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "๑,๐๐๐,๐๐๗");
+//!
+//! let fdf = FixedDecimalFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("ar-EG-u-nu-latn").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! assert_writeable_eq!(fdf.format(&fixed_decimal), "1,000,007");
 //! ```
 //!
 //! [`FixedDecimalFormatter`]: FixedDecimalFormatter
@@ -101,14 +127,23 @@ mod error;
 mod format;
 mod grouper;
 pub mod options;
+mod parse;
+mod percent;
 pub mod provider;
+mod range;
+mod scientific;
 
 pub use error::DecimalError;
 pub use format::FormattedFixedDecimal;
+pub use parse::FixedDecimalParser;
+pub use percent::{FormattedPercent, PercentFormatter};
+pub use range::{FixedDecimalRangeFormatter, FormattedFixedDecimalRange};
+pub use scientific::{FormattedScientificDecimal, ScientificFormatter};
 
 #[doc(inline)]
 pub use DecimalError as Error;
 
+use alloc::borrow::Cow;
 use alloc::string::String;
 use fixed_decimal::FixedDecimal;
 use icu_provider::prelude::*;
@@ -120,7 +155,8 @@ use writeable::Writeable;
 ///
 /// 1. Rendering in the local numbering system
 /// 2. Locale-sensitive grouping separator positions
-/// 3. Locale-sensitive plus and minus signs
+/// 3. Locale-sensitive plus and minus signs, including accounting-style negative numbers
+/// 4. Rounding and padding to a number of fraction or significant digits
 ///
 /// Read more about the options in the [`options`] module.
 ///
@@ -128,15 +164,23 @@ use writeable::Writeable;
 pub struct FixedDecimalFormatter {
     options: options::FixedDecimalFormatterOptions,
     symbols: DataPayload<provider::DecimalSymbolsV1Marker>,
+    /// Only loaded by [`FixedDecimalFormatter::try_new_with_accounting_unstable`].
+    accounting_symbols: Option<DataPayload<provider::AccountingSymbolsV1Marker>>,
 }
 
 impl FixedDecimalFormatter {
     /// Creates a new [`FixedDecimalFormatter`] from locale data and an options bag.
     ///
+    /// This does not load the data for [`NegativeStyle::Accounting`], so negative numbers are
+    /// rendered with the minus sign of the locale; use
+    /// [`FixedDecimalFormatter::try_new_with_accounting_unstable`] for accounting style.
+    ///
     /// [📚 Help choosing a constructor](icu_provider::constructors)
     /// <div class="stab unstable">
     /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
     /// </div>
+    ///
+    /// [`NegativeStyle::Accounting`]: options::NegativeStyle::Accounting
     pub fn try_new_unstable<D: DataProvider<provider::DecimalSymbolsV1Marker> + ?Sized>(
         data_provider: &D,
         locale: &DataLocale,
@@ -148,7 +192,11 @@ impl FixedDecimalFormatter {
                 metadata: Default::default(),
             })?
             .take_payload()?;
-        Ok(Self { options, symbols })
+        Ok(Self {
+            options,
+            symbols,
+            accounting_symbols: None,
+        })
     }
 
     icu_provider::gen_any_buffer_constructors!(
@@ -157,12 +205,61 @@ impl FixedDecimalFormatter {
         error: DecimalError
     );
 
+    /// Creates a new [`FixedDecimalFormatter`] from locale data and an options bag, also
+    /// loading the data for [`NegativeStyle::Accounting`].
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// [`NegativeStyle::Accounting`]: options::NegativeStyle::Accounting
+    pub fn try_new_with_accounting_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: options::FixedDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<provider::DecimalSymbolsV1Marker>
+            + DataProvider<provider::AccountingSymbolsV1Marker>
+            + ?Sized,
+    {
+        let mut formatter = Self::try_new_unstable(data_provider, locale, options)?;
+        formatter.accounting_symbols = Some(
+            DataProvider::<provider::AccountingSymbolsV1Marker>::load(
+                data_provider,
+                DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                },
+            )?
+            .take_payload()?,
+        );
+        Ok(formatter)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: options::FixedDecimalFormatterOptions,
+        error: DecimalError,
+        functions: [
+            Self::try_new_with_accounting_unstable,
+            try_new_with_accounting_with_any_provider,
+            try_new_with_accounting_with_buffer_provider
+        ]
+    );
+
     /// Formats a [`FixedDecimal`], returning a [`FormattedFixedDecimal`].
     pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedFixedDecimal<'l> {
+        let value = match self.options.apply_to(value) {
+            Some(value) => Cow::Owned(value),
+            None => Cow::Borrowed(value),
+        };
         FormattedFixedDecimal {
             value,
             options: &self.options,
             symbols: self.symbols.get(),
+            accounting_symbols: self.accounting_symbols(),
         }
     }
 
@@ -170,4 +267,8 @@ impl FixedDecimalFormatter {
     pub fn format_to_string(&self, value: &FixedDecimal) -> String {
         self.format(value).write_to_string().into_owned()
     }
+
+    pub(crate) fn accounting_symbols(&self) -> Option<&provider::AccountingSymbolsV1<'_>> {
+        self.accounting_symbols.as_ref().map(DataPayload::get)
+    }
 }
@@ -4,13 +4,241 @@
 
 //! Options for [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
 
+use fixed_decimal::FixedDecimal;
+
+pub use fixed_decimal::{RoundingIncrement, RoundingMode, SignDisplay};
+
 /// A bag of options defining how numbers will be formatted by
 /// [`FixedDecimalFormatter`](crate::FixedDecimalFormatter).
+///
+/// By default, numbers are rendered with the digits of the [`FixedDecimal`]. The digit-count
+/// options round or pad them, in the manner of `Intl.NumberFormat`: if either significant-digit
+/// option is set, the fraction-digit options are ignored. Rounding removes trailing zeros
+/// after the rounding position up to the minimum number of digits.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+/// let value = FixedDecimal::from(12345).multiplied_pow10(-3);
+/// let format = |options| {
+///     FixedDecimalFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+///     .format_to_string(&value)
+/// };
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.max_fraction_digits = Some(2);
+/// assert_eq!(format(options.clone()), "12.35");
+///
+/// options.rounding_mode = options::RoundingMode::Trunc;
+/// assert_eq!(format(options.clone()), "12.34");
+///
+/// options.rounding_increment = options::RoundingIncrement::MultiplesOf5;
+/// assert_eq!(format(options.clone()), "12.3");
+///
+/// options.min_fraction_digits = Some(2);
+/// assert_eq!(format(options), "12.30");
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.max_significant_digits = Some(3);
+/// options.min_integer_digits = Some(3);
+/// assert_eq!(format(options), "012.3");
+///
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.max_fraction_digits = Some(1);
+/// options.sign_display = options::SignDisplay::ExceptZero;
+/// assert_eq!(format(options.clone()), "+12.3");
+/// assert_eq!(
+///     FixedDecimalFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+///     .format_to_string(&FixedDecimal::from(-1).multiplied_pow10(-2)),
+///     "0"
+/// );
+/// ```
+///
+/// [`FixedDecimal`]: fixed_decimal::FixedDecimal
 #[derive(Debug, Eq, PartialEq, Clone, Default)]
 #[non_exhaustive]
 pub struct FixedDecimalFormatterOptions {
     /// When to render grouping separators.
     pub grouping_strategy: GroupingStrategy,
+
+    /// The minimum number of digits before the decimal separator, padded with zeros.
+    pub min_integer_digits: Option<u8>,
+
+    /// The minimum number of digits after the decimal separator, padded with zeros.
+    pub min_fraction_digits: Option<u8>,
+
+    /// The maximum number of digits after the decimal separator, rounded.
+    pub max_fraction_digits: Option<u8>,
+
+    /// The minimum number of significant digits, padded with zeros.
+    pub min_significant_digits: Option<u8>,
+
+    /// The maximum number of significant digits, rounded.
+    pub max_significant_digits: Option<u8>,
+
+    /// The multiples to round to at the last digit allowed by
+    /// [`max_fraction_digits`](Self::max_fraction_digits) or
+    /// [`max_significant_digits`](Self::max_significant_digits). For example,
+    /// [`RoundingIncrement::MultiplesOf5`] with two fraction digits rounds to multiples of 0.05.
+    pub rounding_increment: RoundingIncrement,
+
+    /// How to round numbers that have more digits than allowed.
+    pub rounding_mode: RoundingMode,
+
+    /// When to render the minus sign or plus sign. The sign is chosen after rounding, so
+    /// with [`SignDisplay::ExceptZero`], numbers that round to zero have no sign.
+    pub sign_display: SignDisplay,
+
+    /// How to render the sign of negative numbers.
+    pub negative_style: NegativeStyle,
+}
+
+impl FixedDecimalFormatterOptions {
+    /// Returns `value` rounded, padded and signed according to the digit-count and sign
+    /// display options, or `None` if no such option is set.
+    ///
+    /// This is the number that [`FixedDecimalFormatter`](crate::FixedDecimalFormatter) renders
+    /// with these options, which is useful for choosing a plural form that matches it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_decimal::options::FixedDecimalFormatterOptions;
+    ///
+    /// let mut options = FixedDecimalFormatterOptions::default();
+    /// assert_eq!(options.apply_to(&FixedDecimal::from(1)), None);
+    ///
+    /// options.min_fraction_digits = Some(2);
+    /// let value = options.apply_to(&FixedDecimal::from(1)).unwrap();
+    /// assert_eq!(value.to_string(), "1.00");
+    /// ```
+    pub fn apply_to(&self, value: &FixedDecimal) -> Option<FixedDecimal> {
+        let has_significant_digits =
+            self.min_significant_digits.is_some() || self.max_significant_digits.is_some();
+        let has_fraction_digits =
+            self.min_fraction_digits.is_some() || self.max_fraction_digits.is_some();
+        if !has_significant_digits
+            && !has_fraction_digits
+            && self.min_integer_digits.is_none()
+            && self.sign_display == SignDisplay::Auto
+        {
+            return None;
+        }
+        let mut value = value.clone();
+        if has_significant_digits {
+            let position = |value: &FixedDecimal, digits: u8| {
+                value.nonzero_magnitude_start() - i16::from(digits) + 1
+            };
+            if let Some(max) = self.max_significant_digits {
+                let position = position(&value, max);
+                value.round_with_mode_and_increment(
+                    position,
+                    self.rounding_mode,
+                    self.rounding_increment,
+                );
+                value.trim_end();
+            }
+            if let Some(min) = self.min_significant_digits {
+                value.pad_end(position(&value, min));
+            }
+        } else if has_fraction_digits {
+            if let Some(max) = self.max_fraction_digits {
+                value.round_with_mode_and_increment(
+                    -i16::from(max),
+                    self.rounding_mode,
+                    self.rounding_increment,
+                );
+                value.trim_end();
+            }
+            if let Some(min) = self.min_fraction_digits {
+                value.pad_end(-i16::from(min));
+            }
+        }
+        if let Some(min) = self.min_integer_digits {
+            value.pad_start(i16::from(min));
+        }
+        value.apply_sign_display(self.sign_display);
+        Some(value)
+    }
+}
+
+/// How to render the sign of negative numbers.
+///
+/// [`NegativeStyle::Accounting`] needs a formatter created with
+/// [`FixedDecimalFormatter::try_new_with_accounting_unstable`](crate::FixedDecimalFormatter::try_new_with_accounting_unstable)
+/// or its variants; other formatters render the minus sign instead.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::FixedDecimalFormatter;
+/// use icu_locid::locale;
+///
+/// let format = |locale: &icu_locid::Locale, sign_display| {
+///     let mut options: options::FixedDecimalFormatterOptions = Default::default();
+///     options.negative_style = options::NegativeStyle::Accounting;
+///     options.sign_display = sign_display;
+///     FixedDecimalFormatter::try_new_with_accounting_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+/// };
+///
+/// let en = format(&locale!("en"), options::SignDisplay::Auto);
+/// assert_eq!(en.format_to_string(&FixedDecimal::from(-1234)), "(1,234)");
+/// assert_eq!(en.format_to_string(&FixedDecimal::from(1234)), "1,234");
+///
+/// let en = format(&locale!("en"), options::SignDisplay::Always);
+/// assert_eq!(en.format_to_string(&FixedDecimal::from(1234)), "+1,234");
+///
+/// // Not all locales use parentheses.
+/// let es = format(&locale!("es"), options::SignDisplay::Auto);
+/// assert_eq!(es.format_to_string(&FixedDecimal::from(-1234)), "-1234");
+///
+/// // Without the accounting data, the minus sign is used.
+/// let mut options: options::FixedDecimalFormatterOptions = Default::default();
+/// options.negative_style = options::NegativeStyle::Accounting;
+/// let fdf = FixedDecimalFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+/// assert_eq!(fdf.format_to_string(&FixedDecimal::from(-1234)), "-1,234");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum NegativeStyle {
+    /// Render the minus sign of the locale, as in `-1,234`.
+    Standard,
+
+    /// Render the accounting style of the locale, which is often parentheses, as in `(1,234)`.
+    Accounting,
+}
+
+impl Default for NegativeStyle {
+    fn default() -> Self {
+        Self::Standard
+    }
 }
 
 /// Configuration for how often to render grouping separators.
@@ -66,3 +294,135 @@ impl Default for GroupingStrategy {
         Self::Auto
     }
 }
+
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificFormatter`](crate::ScientificFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct ScientificFormatterOptions {
+    /// How to choose the exponent of numbers that are not already in scientific notation.
+    pub notation: ScientificNotation,
+
+    /// How to render the exponent.
+    pub exponent_style: ExponentStyle,
+}
+
+/// How to choose the exponent when converting a [`FixedDecimal`](fixed_decimal::FixedDecimal)
+/// to scientific notation.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ScientificNotation {
+    /// Use a single nonzero integer digit in the significand, as in `1.2345E4`.
+    Scientific,
+
+    /// Use an exponent that is a multiple of 3, as in `12.345E3`.
+    Engineering,
+}
+
+impl Default for ScientificNotation {
+    fn default() -> Self {
+        Self::Scientific
+    }
+}
+
+/// How to render the exponent of a number in scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options: options::ScientificFormatterOptions = Default::default();
+/// options.exponent_style = options::ExponentStyle::Superscript;
+/// let sf = ScientificFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let value = FixedDecimal::from(1729).multiplied_pow10(-7);
+/// assert_writeable_eq!(sf.format_fixed_decimal(&value), "1.729×10⁻⁴");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ExponentStyle {
+    /// Render the exponent after the locale's exponent separator, as in `1.2E3`.
+    Separator,
+
+    /// Render the exponent as a superscript power of ten, as in `1.2×10³`.
+    Superscript,
+}
+
+impl Default for ExponentStyle {
+    fn default() -> Self {
+        Self::Separator
+    }
+}
+
+/// A bag of options defining how numbers will be parsed by
+/// [`FixedDecimalParser`](crate::FixedDecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct FixedDecimalParserOptions {
+    /// How closely the input has to follow the locale's number format.
+    pub mode: ParseMode,
+}
+
+/// How closely the input of a [`FixedDecimalParser`](crate::FixedDecimalParser) has to follow
+/// the locale's number format.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParseMode {
+    /// Accept only numbers as the locale formats them: the locale's digits, sign affixes and
+    /// separators, with grouping separators at their expected positions, and nothing else.
+    Strict,
+
+    /// Also accept common variants of the locale's format:
+    ///
+    /// - ASCII digits and the signs `-`, `+`, `−` and `(...)`
+    /// - Grouping separators at any position of the integer part
+    /// - Any space as the grouping separator if the locale uses a space
+    /// - Missing integer or fraction digits next to the decimal separator
+    /// - Spaces and bidi marks around the number and its sign
+    Lenient,
+}
+
+impl Default for ParseMode {
+    fn default() -> Self {
+        Self::Lenient
+    }
+}
+
+#[test]
+fn test_digit_options() {
+    let cases: [(&str, [Option<u8>; 5], &str); 8] = [
+        // (input, [min_int, min_frac, max_frac, min_sig, max_sig], expected)
+        ("1.5", [None, None, None, None, None], "1.5"),
+        ("9.996", [None, None, Some(2), None, None], "10"),
+        ("9.996", [None, Some(2), Some(2), None, None], "10.00"),
+        ("0.00123", [None, None, None, None, Some(2)], "0.0012"),
+        ("99.95", [None, None, None, None, Some(3)], "100"),
+        ("120", [None, None, None, Some(5), None], "120.00"),
+        ("0", [None, None, None, Some(3), Some(3)], "0.00"),
+        ("-1.5", [Some(3), Some(1), Some(3), None, None], "-001.5"),
+    ];
+    for (input, digits, expected) in cases {
+        let [min_int, min_frac, max_frac, min_sig, max_sig] = digits;
+        let options = FixedDecimalFormatterOptions {
+            min_integer_digits: min_int,
+            min_fraction_digits: min_frac,
+            max_fraction_digits: max_frac,
+            min_significant_digits: min_sig,
+            max_significant_digits: max_sig,
+            ..Default::default()
+        };
+        let input: FixedDecimal = input.parse().unwrap();
+        let actual = options.apply_to(&input).unwrap_or_else(|| input.clone());
+        assert_eq!(actual.to_string(), expected, "{input} {digits:?}");
+    }
+}
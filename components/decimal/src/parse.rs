@@ -0,0 +1,396 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized numbers.
+
+use crate::options::*;
+use crate::provider::*;
+use crate::DecimalError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_provider::prelude::*;
+
+/// A parser for numbers formatted by the conventions of a locale, such as "1,234.5" in English
+/// or "1.234,5" in Spanish, producing a [`FixedDecimal`].
+///
+/// The parser uses the same symbols as [`FixedDecimalFormatter`](crate::FixedDecimalFormatter):
+/// the digits, separators and sign affixes of the locale, including accounting-style negative
+/// numbers. Read more about strict and lenient parsing in [`ParseMode`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::options;
+/// use icu_decimal::{DecimalError, FixedDecimalParser};
+/// use icu_locid::locale;
+/// use std::str::FromStr;
+///
+/// let parser = |locale: &icu_locid::Locale, mode| {
+///     let mut options: options::FixedDecimalParserOptions = Default::default();
+///     options.mode = mode;
+///     FixedDecimalParser::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+/// };
+/// let decimal = |s| FixedDecimal::from_str(s).unwrap();
+///
+/// let en = parser(&locale!("en"), options::ParseMode::Strict);
+/// assert_eq!(en.parse("-1,234.5"), Ok(decimal("-1234.5")));
+/// assert_eq!(en.parse("(1,234)"), Ok(decimal("-1234")));
+/// // The grouping separator is misplaced.
+/// assert_eq!(en.parse("12,34"), Err(DecimalError::Parse(2)));
+///
+/// let es = parser(&locale!("es"), options::ParseMode::Strict);
+/// assert_eq!(es.parse("1.234,5"), Ok(decimal("1234.5")));
+///
+/// // Lenient parsing accepts a minus sign without the Arabic letter mark.
+/// let ar = parser(&locale!("ar"), options::ParseMode::Lenient);
+/// assert_eq!(ar.parse("-٣٫٥"), Ok(decimal("-3.5")));
+/// assert_eq!(ar.parse("٣٫٥x"), Err(DecimalError::Parse(6)));
+/// ```
+pub struct FixedDecimalParser {
+    options: FixedDecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+    accounting_symbols: DataPayload<AccountingSymbolsV1Marker>,
+}
+
+impl FixedDecimalParser {
+    /// Creates a new [`FixedDecimalParser`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalParserOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<AccountingSymbolsV1Marker> + ?Sized,
+    {
+        let request = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        Ok(Self {
+            options,
+            symbols: DataProvider::<DecimalSymbolsV1Marker>::load(data_provider, request)?
+                .take_payload()?,
+            accounting_symbols: DataProvider::<AccountingSymbolsV1Marker>::load(
+                data_provider,
+                request,
+            )?
+            .take_payload()?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: FixedDecimalParserOptions,
+        error: DecimalError
+    );
+
+    /// Parses a localized number into a [`FixedDecimal`].
+    ///
+    /// Trailing fraction zeros are kept, so "1.50" has a lower magnitude of -2.
+    ///
+    /// Returns [`DecimalError::Parse`] with the byte index of the first character that could
+    /// not be parsed.
+    pub fn parse(&self, input: &str) -> Result<FixedDecimal, DecimalError> {
+        let (sign, start, end) = self.strip_sign(input);
+        self.parse_unsigned(input, start, end)
+            .map(|value| value.with_sign(sign))
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.options.mode == ParseMode::Lenient
+    }
+
+    /// Returns the sign of `input` and the byte range of the number between its sign affixes.
+    fn strip_sign(&self, input: &str) -> (Sign, usize, usize) {
+        let symbols = self.symbols.get();
+        let lenient = self.is_lenient();
+        let (start, end) = self.trim(input, 0, input.len());
+        let mut candidates: Vec<(&str, &str, Sign)> = [
+            (
+                &self.accounting_symbols.get().minus_sign_affixes,
+                Sign::Negative,
+            ),
+            (&symbols.minus_sign_affixes, Sign::Negative),
+            (&symbols.plus_sign_affixes, Sign::Positive),
+        ]
+        .iter()
+        .map(|(affixes, sign)| (&*affixes.prefix, &*affixes.suffix, *sign))
+        .collect();
+        if lenient {
+            candidates.extend_from_slice(&[
+                ("-", "", Sign::Negative),
+                ("\u{2212}", "", Sign::Negative),
+                ("+", "", Sign::Positive),
+                ("(", ")", Sign::Negative),
+            ]);
+        }
+
+        let mut best = (Sign::None, start, end);
+        let mut best_len = 0;
+        for (prefix, suffix, sign) in candidates {
+            // Bidi marks in the affixes are trimmed from the input in lenient mode.
+            let (prefix, suffix) = if lenient {
+                (
+                    prefix.trim_matches(is_bidi_mark),
+                    suffix.trim_matches(is_bidi_mark),
+                )
+            } else {
+                (prefix, suffix)
+            };
+            let len = prefix.len() + suffix.len();
+            if len <= best_len || len > end - start {
+                continue;
+            }
+            let body = match input.get(start..end) {
+                Some(body) => body,
+                None => continue,
+            };
+            if body.starts_with(prefix) && body.ends_with(suffix) {
+                let (inner_start, inner_end) =
+                    self.trim(input, start + prefix.len(), end - suffix.len());
+                best = (sign, inner_start, inner_end);
+                best_len = len;
+            }
+        }
+        best
+    }
+
+    /// Narrows the byte range `start..end` of `input` by the characters ignored around the
+    /// number in lenient mode.
+    fn trim(&self, input: &str, start: usize, end: usize) -> (usize, usize) {
+        if !self.is_lenient() {
+            return (start, end);
+        }
+        let body = match input.get(start..end) {
+            Some(body) => body,
+            None => return (start, end),
+        };
+        let is_ignorable = |c: char| c.is_whitespace() || is_bidi_mark(c);
+        let trimmed_start = body.trim_start_matches(is_ignorable);
+        let trimmed = trimmed_start.trim_end_matches(is_ignorable);
+        let start = start + body.len() - trimmed_start.len();
+        (start, start + trimmed.len())
+    }
+
+    /// Parses the digits and separators in the byte range `start..end` of `input`.
+    fn parse_unsigned(
+        &self,
+        input: &str,
+        start: usize,
+        end: usize,
+    ) -> Result<FixedDecimal, DecimalError> {
+        let symbols = self.symbols.get();
+        let lenient = self.is_lenient();
+        let body = input.get(start..end).ok_or(DecimalError::Parse(start))?;
+
+        // The number in the syntax of `FixedDecimal::from_str`.
+        let mut ascii = String::with_capacity(body.len());
+        let mut integer_digits = 0;
+        let mut fraction_digits = 0;
+        let mut decimal_position = None;
+        // The sizes of the groups of integer digits before each grouping separator, and the
+        // position of that separator.
+        let mut groups: Vec<(usize, usize)> = Vec::new();
+        let mut group_digits = 0;
+
+        let mut rest = body;
+        while let Some(c) = rest.chars().next() {
+            let position = end - rest.len();
+            let len = if let Some(digit) = self.digit_value(c) {
+                ascii.push(char::from(b'0' + digit));
+                if decimal_position.is_some() {
+                    fraction_digits += 1;
+                } else {
+                    integer_digits += 1;
+                    group_digits += 1;
+                }
+                c.len_utf8()
+            } else if decimal_position.is_none()
+                && !symbols.decimal_separator.is_empty()
+                && rest.starts_with(&*symbols.decimal_separator)
+            {
+                if integer_digits == 0 && !lenient {
+                    return Err(DecimalError::Parse(position));
+                }
+                ascii.push('.');
+                decimal_position = Some(position);
+                symbols.decimal_separator.len()
+            } else if let Some(len) = self.grouping_separator_len(rest) {
+                if decimal_position.is_some() || (group_digits == 0 && !lenient) {
+                    return Err(DecimalError::Parse(position));
+                }
+                groups.push((group_digits, position));
+                group_digits = 0;
+                len
+            } else if lenient && is_bidi_mark(c) {
+                c.len_utf8()
+            } else {
+                return Err(DecimalError::Parse(position));
+            };
+            rest = rest.get(len..).unwrap_or_default();
+        }
+
+        if integer_digits + fraction_digits == 0 {
+            return Err(DecimalError::Parse(end));
+        }
+        if !lenient {
+            if decimal_position.is_some() && fraction_digits == 0 {
+                return Err(DecimalError::Parse(end));
+            }
+            self.check_grouping(&groups, group_digits)?;
+        }
+        if ascii.ends_with('.') {
+            ascii.pop();
+        }
+        if ascii.starts_with('.') {
+            ascii.insert(0, '0');
+        }
+        FixedDecimal::from_str(&ascii).map_err(|_| DecimalError::Parse(start))
+    }
+
+    /// Returns the value of a digit of the locale, or, in lenient mode, an ASCII digit.
+    fn digit_value(&self, c: char) -> Option<u8> {
+        if let Some(digit) = self.symbols.get().digits.iter().position(|d| *d == c) {
+            return Some(digit as u8);
+        }
+        if self.is_lenient() && c.is_ascii_digit() {
+            return Some(c as u8 - b'0');
+        }
+        None
+    }
+
+    /// Returns the length of the grouping separator at the start of `s`, if there is one.
+    fn grouping_separator_len(&self, s: &str) -> Option<usize> {
+        let separator = &*self.symbols.get().grouping_separator;
+        if separator.is_empty() {
+            return None;
+        }
+        if s.starts_with(separator) {
+            return Some(separator.len());
+        }
+        // Spaces are easily confused, so any of them can stand in for a space separator.
+        if self.is_lenient() && separator.chars().all(char::is_whitespace) {
+            return s
+                .chars()
+                .next()
+                .filter(|c| c.is_whitespace())
+                .map(char::len_utf8);
+        }
+        None
+    }
+
+    /// Checks that the grouping separators are where the locale would put them.
+    ///
+    /// `groups` contains the number of digits before each grouping separator, and the
+    /// separator's position; `last_group` is the number of digits after the last separator.
+    fn check_grouping(
+        &self,
+        groups: &[(usize, usize)],
+        last_group: usize,
+    ) -> Result<(), DecimalError> {
+        let sizes = &self.symbols.get().grouping_sizes;
+        let primary = sizes.primary as usize;
+        let secondary = if sizes.secondary == 0 {
+            primary
+        } else {
+            sizes.secondary as usize
+        };
+        let mut sizes_after = groups
+            .iter()
+            .skip(1)
+            .map(|(size, _)| (*size, secondary))
+            .chain(core::iter::once((last_group, primary)));
+        for (i, (size, position)) in groups.iter().enumerate() {
+            // The first group may be shorter than the others.
+            let first_ok = i != 0 || (1..=secondary).contains(size);
+            let next_ok = match sizes_after.next() {
+                Some((size, expected)) => size == expected,
+                None => true,
+            };
+            if !first_ok || !next_ok || primary == 0 {
+                return Err(DecimalError::Parse(*position));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether `c` is a bidi control mark that may surround numbers and their signs.
+fn is_bidi_mark(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}')
+}
+
+#[test]
+fn test_parse() {
+    use alloc::borrow::Cow;
+
+    let en = DecimalSymbolsV1::default();
+    let fr = DecimalSymbolsV1 {
+        decimal_separator: Cow::Borrowed(","),
+        grouping_separator: Cow::Borrowed("\u{202f}"),
+        ..DecimalSymbolsV1::default()
+    };
+    let ar = DecimalSymbolsV1 {
+        minus_sign_affixes: AffixesV1 {
+            prefix: Cow::Borrowed("\u{61c}-"),
+            suffix: Cow::Borrowed(""),
+        },
+        decimal_separator: Cow::Borrowed("٫"),
+        grouping_separator: Cow::Borrowed("٬"),
+        digits: ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+        ..DecimalSymbolsV1::default()
+    };
+    let th = DecimalSymbolsV1 {
+        digits: ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'],
+        ..DecimalSymbolsV1::default()
+    };
+
+    let cases: [(&DecimalSymbolsV1, ParseMode, &str, Result<&str, usize>); 20] = [
+        // (symbols, mode, input, expected)
+        (&en, ParseMode::Strict, "1234", Ok("1234")),
+        (&en, ParseMode::Strict, "1,234,567.50", Ok("1234567.50")),
+        (&en, ParseMode::Strict, "+0.5", Ok("+0.5")),
+        (&en, ParseMode::Strict, "1,2345", Err(1)),
+        (&en, ParseMode::Strict, "12345,678", Err(5)),
+        (&en, ParseMode::Strict, ".5", Err(0)),
+        (&en, ParseMode::Strict, "1.", Err(2)),
+        (&en, ParseMode::Strict, " 1", Err(0)),
+        (&en, ParseMode::Strict, "(1,234)", Ok("-1234")),
+        (&en, ParseMode::Strict, "(1", Err(0)),
+        (&en, ParseMode::Strict, "-", Err(1)),
+        (&en, ParseMode::Strict, "1.2.3", Err(3)),
+        (&en, ParseMode::Strict, "1.2,3", Err(3)),
+        (&en, ParseMode::Lenient, " ( 1,2345. ) ", Ok("-12345")),
+        (&en, ParseMode::Lenient, "\u{2212}.5", Ok("-0.5")),
+        (&fr, ParseMode::Strict, "1\u{202f}234,5", Ok("1234.5")),
+        (&fr, ParseMode::Strict, "1 234,5", Err(1)),
+        (&fr, ParseMode::Lenient, "1 234,5", Ok("1234.5")),
+        (&ar, ParseMode::Strict, "\u{61c}-٣٬٤٥٦", Ok("-3456")),
+        (&th, ParseMode::Lenient, "๑,2๓", Ok("123")),
+    ];
+    for (symbols, mode, input, expected) in cases {
+        let parser = FixedDecimalParser {
+            options: FixedDecimalParserOptions { mode },
+            symbols: DataPayload::from_owned(symbols.clone()),
+            accounting_symbols: DataPayload::from_owned(AccountingSymbolsV1::default()),
+        };
+        let expected = expected
+            .map(|s| FixedDecimal::from_str(s).unwrap())
+            .map_err(DecimalError::Parse);
+        assert_eq!(parser.parse(input), expected, "{mode:?} {input:?}");
+    }
+}
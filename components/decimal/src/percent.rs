@@ -0,0 +1,167 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of percentages and permilles.
+
+use crate::format::sign_affixes;
+use crate::options::FixedDecimalFormatterOptions;
+use crate::provider::*;
+use crate::{DecimalError, FixedDecimalFormatter, FormattedFixedDecimal};
+use alloc::borrow::Cow;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// A formatter for percentages and permilles of a [`FixedDecimal`].
+///
+/// The value is a fraction of one: it is multiplied by 100 for percentages and by 1000 for
+/// permilles. The percent and permille signs, and their positions, follow the locale.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_decimal::PercentFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let format = |locale: &icu_locid::Locale| {
+///     PercentFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         Default::default(),
+///     )
+///     .expect("Data should load successfully")
+/// };
+/// let en = format(&locale!("en"));
+/// let fr = format(&locale!("fr"));
+/// let tr = format(&locale!("tr"));
+///
+/// let value = FixedDecimal::from(-125).multiplied_pow10(-3);
+///
+/// assert_writeable_eq!(en.format_percent(&value), "-12.5%");
+/// assert_writeable_eq!(en.format_permille(&value), "-125‰");
+/// assert_writeable_eq!(fr.format_percent(&value), "-12,5\u{a0}%");
+/// assert_writeable_eq!(tr.format_percent(&value), "-%12,5");
+/// ```
+pub struct PercentFormatter {
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    notation_symbols: DataPayload<NotationSymbolsV1Marker>,
+}
+
+impl PercentFormatter {
+    /// Creates a new [`PercentFormatter`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<NotationSymbolsV1Marker> + ?Sized,
+    {
+        let notation_symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                options,
+            )?,
+            notation_symbols,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: FixedDecimalFormatterOptions,
+        error: DecimalError
+    );
+
+    /// Formats a fraction of one as a percentage, such as 0.25 as "25%".
+    pub fn format_percent(&self, value: &FixedDecimal) -> FormattedPercent<'_> {
+        self.format_scaled(value, 2, &self.notation_symbols.get().percent_affixes)
+    }
+
+    /// Formats a fraction of one as a permille, such as 0.025 as "25‰".
+    pub fn format_permille(&self, value: &FixedDecimal) -> FormattedPercent<'_> {
+        self.format_scaled(value, 3, &self.notation_symbols.get().permille_affixes)
+    }
+
+    fn format_scaled<'l>(
+        &'l self,
+        value: &FixedDecimal,
+        delta: i16,
+        affixes: &'l AffixesV1<'l>,
+    ) -> FormattedPercent<'l> {
+        let value = value.clone().multiplied_pow10(delta).trimmed_start();
+        // Round before choosing the sign, which may depend on the rounded value.
+        let value = self
+            .fixed_decimal_formatter
+            .options
+            .apply_to(&value)
+            .unwrap_or(value);
+        let sign = value.sign();
+        let magnitude = value.with_sign(Sign::None);
+        FormattedPercent {
+            sign,
+            magnitude,
+            affixes,
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+        }
+    }
+}
+
+/// An intermediate structure returned by [`PercentFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted percentage to a string or buffer.
+pub struct FormattedPercent<'l> {
+    sign: Sign,
+    /// The absolute value of the percentage or permille.
+    magnitude: FixedDecimal,
+    /// The percent or permille affixes.
+    affixes: &'l AffixesV1<'l>,
+    fixed_decimal_formatter: &'l FixedDecimalFormatter,
+}
+
+impl<'l> Writeable for FormattedPercent<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        // The sign goes around the percent sign, as in "-%12" in Turkish.
+        let sign_affixes = sign_affixes(
+            self.fixed_decimal_formatter.symbols.get(),
+            self.fixed_decimal_formatter.accounting_symbols(),
+            &self.fixed_decimal_formatter.options,
+            self.sign,
+        );
+        if let Some(sign_affixes) = sign_affixes {
+            sink.write_str(&sign_affixes.prefix)?;
+        }
+        sink.write_str(&self.affixes.prefix)?;
+        // The magnitude is already rounded, so it is not passed through the options again.
+        FormattedFixedDecimal {
+            value: Cow::Borrowed(&self.magnitude),
+            options: &self.fixed_decimal_formatter.options,
+            symbols: self.fixed_decimal_formatter.symbols.get(),
+            accounting_symbols: self.fixed_decimal_formatter.accounting_symbols(),
+        }
+        .write_to(sink)?;
+        sink.write_str(&self.affixes.suffix)?;
+        if let Some(sign_affixes) = sign_affixes {
+            sink.write_str(&sign_affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedPercent<'_>);
@@ -140,3 +140,148 @@ impl Default for DecimalSymbolsV1<'static> {
         }
     }
 }
+
+/// Symbols required for formatting negative numbers in accounting style, in addition to
+/// [`DecimalSymbolsV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    AccountingSymbolsV1Marker,
+    "decimal/accounting@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct AccountingSymbolsV1<'data> {
+    /// Prefix and suffix to apply when a negative sign is needed in accounting style, such as
+    /// parentheses in `(1,234)`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minus_sign_affixes: AffixesV1<'data>,
+}
+
+impl Default for AccountingSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            minus_sign_affixes: AffixesV1 {
+                prefix: Cow::Borrowed("("),
+                suffix: Cow::Borrowed(")"),
+            },
+        }
+    }
+}
+
+/// Symbols required for formatting percentages, permilles, and numbers in scientific
+/// notation, in addition to [`DecimalSymbolsV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    NotationSymbolsV1Marker,
+    "decimal/notation@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct NotationSymbolsV1<'data> {
+    /// Prefix and suffix to apply to a percentage, including the percent sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub percent_affixes: AffixesV1<'data>,
+
+    /// Prefix and suffix to apply to a permille, including the permille sign.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub permille_affixes: AffixesV1<'data>,
+
+    /// String separating the significand from the exponent in scientific notation, such as
+    /// "E" in `1.2E3`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponent_separator: Cow<'data, str>,
+
+    /// String separating the significand from a power of ten with a superscript exponent,
+    /// such as "×" in `1.2×10³`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: Cow<'data, str>,
+}
+
+impl Default for NotationSymbolsV1<'static> {
+    fn default() -> Self {
+        Self {
+            percent_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("%"),
+            },
+            permille_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed("‰"),
+            },
+            exponent_separator: "E".into(),
+            superscripting_exponent: "×".into(),
+        }
+    }
+}
+
+/// Patterns for formatting ranges of numbers and approximate numbers, in addition to
+/// [`DecimalSymbolsV1`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(marker(
+    RangePatternsV1Marker,
+    "decimal/range@1",
+    extension_key = "nu"
+))]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_decimal::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RangePatternsV1<'data> {
+    /// Text before the start and after the end of a range.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub range_affixes: AffixesV1<'data>,
+
+    /// Text between the start and the end of a range, such as "–" in `3–5`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub range_separator: Cow<'data, str>,
+
+    /// Prefix and suffix to apply to an approximate number, such as "~" in `~5`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub approximately_affixes: AffixesV1<'data>,
+}
+
+impl Default for RangePatternsV1<'static> {
+    fn default() -> Self {
+        Self {
+            range_affixes: AffixesV1 {
+                prefix: Cow::Borrowed(""),
+                suffix: Cow::Borrowed(""),
+            },
+            range_separator: "–".into(),
+            approximately_affixes: AffixesV1 {
+                prefix: Cow::Borrowed("~"),
+                suffix: Cow::Borrowed(""),
+            },
+        }
+    }
+}
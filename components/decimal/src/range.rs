@@ -0,0 +1,199 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of ranges of numbers.
+
+use crate::options::FixedDecimalFormatterOptions;
+use crate::provider::*;
+use crate::{DecimalError, FixedDecimalFormatter, FormattedFixedDecimal};
+use alloc::borrow::Cow;
+use fixed_decimal::FixedDecimal;
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// A formatter for ranges of [`FixedDecimal`]s, such as "3–5", and for approximate numbers,
+/// such as "~5".
+///
+/// The start and end are formatted with the same options as by [`FixedDecimalFormatter`].
+/// If they are identical once rounded, the range is formatted as an approximate number.
+///
+/// The plural category of a range, which selects the form of a word such as "days" that
+/// follows it, is given by `PluralRanges` in the `icu_plurals` crate.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu::plurals::{PluralCategory, PluralRanges, PluralRules};
+/// use icu_decimal::options::FixedDecimalFormatterOptions;
+/// use icu_decimal::FixedDecimalRangeFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let locale = locale!("en").into();
+/// let mut options: FixedDecimalFormatterOptions = Default::default();
+/// options.max_fraction_digits = Some(1);
+/// let formatter = FixedDecimalRangeFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale,
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let start = FixedDecimal::from(3);
+/// let end = FixedDecimal::from(5);
+/// assert_writeable_eq!(formatter.format_range(&start, &end), "3–5");
+///
+/// // Both ends round to 5
+/// let start = FixedDecimal::from(498).multiplied_pow10(-2);
+/// let end = FixedDecimal::from(502).multiplied_pow10(-2);
+/// assert_writeable_eq!(formatter.format_range(&start, &end), "~5");
+///
+/// // "1–2 days", not "1–2 day"
+/// let rules =
+///     PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale)
+///         .expect("Data should load successfully");
+/// let ranges = PluralRanges::try_new_unstable(&icu_testdata::unstable(), &locale)
+///     .expect("Data should load successfully");
+/// assert_eq!(
+///     ranges.category_for_range(rules.category_for(1_usize), rules.category_for(2_usize)),
+///     PluralCategory::Other
+/// );
+/// ```
+pub struct FixedDecimalRangeFormatter {
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    range_patterns: DataPayload<RangePatternsV1Marker>,
+}
+
+impl FixedDecimalRangeFormatter {
+    /// Creates a new [`FixedDecimalRangeFormatter`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: FixedDecimalFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<RangePatternsV1Marker> + ?Sized,
+    {
+        let range_patterns = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self {
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                options,
+            )?,
+            range_patterns,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: FixedDecimalFormatterOptions,
+        error: DecimalError
+    );
+
+    /// Formats the range from `start` to `end`, such as "3–5".
+    ///
+    /// If `start` and `end` are identical once rounded, formats them as an approximate number.
+    pub fn format_range(
+        &self,
+        start: &FixedDecimal,
+        end: &FixedDecimal,
+    ) -> FormattedFixedDecimalRange<'_> {
+        let start = self.round(start);
+        let end = self.round(end);
+        let kind = if start == end {
+            RangeKind::Approximately(start)
+        } else {
+            RangeKind::Range(start, end)
+        };
+        FormattedFixedDecimalRange {
+            kind,
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+            range_patterns: self.range_patterns.get(),
+        }
+    }
+
+    /// Formats a number as approximate, such as "~5".
+    pub fn format_approximately(&self, value: &FixedDecimal) -> FormattedFixedDecimalRange<'_> {
+        FormattedFixedDecimalRange {
+            kind: RangeKind::Approximately(self.round(value)),
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+            range_patterns: self.range_patterns.get(),
+        }
+    }
+
+    fn round(&self, value: &FixedDecimal) -> FixedDecimal {
+        self.fixed_decimal_formatter
+            .options
+            .apply_to(value)
+            .unwrap_or_else(|| value.clone())
+    }
+}
+
+enum RangeKind {
+    Range(FixedDecimal, FixedDecimal),
+    Approximately(FixedDecimal),
+}
+
+/// An intermediate structure returned by [`FixedDecimalRangeFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted range to a string or buffer.
+pub struct FormattedFixedDecimalRange<'l> {
+    /// The rounded numbers to format.
+    kind: RangeKind,
+    fixed_decimal_formatter: &'l FixedDecimalFormatter,
+    range_patterns: &'l RangePatternsV1<'l>,
+}
+
+impl<'l> FormattedFixedDecimalRange<'l> {
+    fn write_number<W>(&self, value: &FixedDecimal, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        // The value is already rounded, so it is not passed through the options again.
+        FormattedFixedDecimal {
+            value: Cow::Borrowed(value),
+            options: &self.fixed_decimal_formatter.options,
+            symbols: self.fixed_decimal_formatter.symbols.get(),
+            accounting_symbols: self.fixed_decimal_formatter.accounting_symbols(),
+        }
+        .write_to(sink)
+    }
+}
+
+impl<'l> Writeable for FormattedFixedDecimalRange<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match &self.kind {
+            RangeKind::Range(start, end) => {
+                let affixes = &self.range_patterns.range_affixes;
+                sink.write_str(&affixes.prefix)?;
+                self.write_number(start, sink)?;
+                sink.write_str(&self.range_patterns.range_separator)?;
+                self.write_number(end, sink)?;
+                sink.write_str(&affixes.suffix)
+            }
+            RangeKind::Approximately(value) => {
+                let affixes = &self.range_patterns.approximately_affixes;
+                sink.write_str(&affixes.prefix)?;
+                self.write_number(value, sink)?;
+                sink.write_str(&affixes.suffix)
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedFixedDecimalRange<'_>);
@@ -0,0 +1,180 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of numbers in scientific notation.
+
+use crate::options::*;
+use crate::provider::*;
+use crate::{DecimalError, FixedDecimalFormatter};
+use fixed_decimal::{FixedDecimal, FixedInteger, ScientificDecimal, Sign};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// The superscript digits from 0 to 9.
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// A formatter for numbers in scientific and engineering notation, such as `1.729E3`.
+///
+/// The significand and the exponent are rendered with the digits and signs of the locale,
+/// without grouping separators. Superscript exponents always use the superscript forms of
+/// the Latin digits.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::{FixedDecimal, FixedInteger, ScientificDecimal};
+/// use icu_decimal::options;
+/// use icu_decimal::ScientificFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let format = |locale: &icu_locid::Locale, notation| {
+///     let mut options: options::ScientificFormatterOptions = Default::default();
+///     options.notation = notation;
+///     ScientificFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+/// };
+///
+/// let value = FixedDecimal::from(-123456).multiplied_pow10(-1);
+/// let sf = format(&locale!("en"), options::ScientificNotation::Scientific);
+/// assert_writeable_eq!(sf.format_fixed_decimal(&value), "-1.23456E4");
+/// let sf = format(&locale!("en"), options::ScientificNotation::Engineering);
+/// assert_writeable_eq!(sf.format_fixed_decimal(&value), "-12.3456E3");
+///
+/// // A `ScientificDecimal` is rendered with its own exponent.
+/// let value = ScientificDecimal::from(
+///     FixedDecimal::from(25).multiplied_pow10(-1),
+///     FixedInteger::from(-3),
+/// );
+/// let sf = format(&locale!("ar"), options::ScientificNotation::Engineering);
+/// assert_writeable_eq!(sf.format(&value), "٢٫٥اس\u{61c}-٣");
+/// ```
+pub struct ScientificFormatter {
+    options: ScientificFormatterOptions,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    notation_symbols: DataPayload<NotationSymbolsV1Marker>,
+}
+
+impl ScientificFormatter {
+    /// Creates a new [`ScientificFormatter`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: ScientificFormatterOptions,
+    ) -> Result<Self, DecimalError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker> + DataProvider<NotationSymbolsV1Marker> + ?Sized,
+    {
+        let notation_symbols = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let fixed_decimal_options = FixedDecimalFormatterOptions {
+            grouping_strategy: GroupingStrategy::Never,
+            ..Default::default()
+        };
+        Ok(Self {
+            options,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                fixed_decimal_options,
+            )?,
+            notation_symbols,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: ScientificFormatterOptions,
+        error: DecimalError
+    );
+
+    /// Formats a [`ScientificDecimal`] with its significand and exponent as given.
+    pub fn format(&self, value: &ScientificDecimal) -> FormattedScientificDecimal<'_> {
+        FormattedScientificDecimal {
+            significand: value.significand().clone(),
+            exponent: FixedDecimal::from(value.exponent().clone()),
+            formatter: self,
+        }
+    }
+
+    /// Formats a [`FixedDecimal`] in scientific notation, choosing the exponent according to
+    /// the [`ScientificNotation`] option.
+    ///
+    /// All digits of `value`, including trailing zeros, are kept in the significand; zero is
+    /// rendered with the exponent 0.
+    pub fn format_fixed_decimal(&self, value: &FixedDecimal) -> FormattedScientificDecimal<'_> {
+        self.format(&self.to_scientific(value))
+    }
+
+    fn to_scientific(&self, value: &FixedDecimal) -> ScientificDecimal {
+        let mut exponent = value.nonzero_magnitude_start();
+        if self.options.notation == ScientificNotation::Engineering {
+            exponent -= exponent.rem_euclid(3);
+        }
+        let significand = value.clone().multiplied_pow10(-exponent).trimmed_start();
+        ScientificDecimal::from(significand, FixedInteger::from(exponent))
+    }
+}
+
+/// An intermediate structure returned by [`ScientificFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+pub struct FormattedScientificDecimal<'l> {
+    significand: FixedDecimal,
+    exponent: FixedDecimal,
+    formatter: &'l ScientificFormatter,
+}
+
+impl<'l> Writeable for FormattedScientificDecimal<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let fixed_decimal_formatter = &self.formatter.fixed_decimal_formatter;
+        let notation_symbols = self.formatter.notation_symbols.get();
+        fixed_decimal_formatter
+            .format(&self.significand)
+            .write_to(sink)?;
+        match self.formatter.options.exponent_style {
+            ExponentStyle::Superscript => {
+                sink.write_str(&notation_symbols.superscripting_exponent)?;
+                let digits = &fixed_decimal_formatter.symbols.get().digits;
+                sink.write_char(digits[1])?;
+                sink.write_char(digits[0])?;
+                match self.exponent.sign() {
+                    Sign::None => (),
+                    Sign::Negative => sink.write_char('⁻')?,
+                    Sign::Positive => sink.write_char('⁺')?,
+                }
+                for m in self.exponent.magnitude_range().rev() {
+                    #[allow(clippy::indexing_slicing)] // digit_at in 0..=9
+                    sink.write_char(SUPERSCRIPT_DIGITS[self.exponent.digit_at(m) as usize])?;
+                }
+            }
+            ExponentStyle::Separator => {
+                sink.write_str(&notation_symbols.exponent_separator)?;
+                fixed_decimal_formatter
+                    .format(&self.exponent)
+                    .write_to(sink)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedScientificDecimal<'_>);
@@ -82,6 +82,7 @@ extern crate alloc;
 mod error;
 mod operands;
 pub mod provider;
+mod ranges;
 pub mod rules;
 
 use core::cmp::{Ord, PartialOrd};
@@ -90,7 +91,10 @@ use icu_provider::prelude::*;
 pub use operands::PluralOperands;
 use provider::CardinalV1Marker;
 use provider::ErasedPluralRulesV1Marker;
+#[cfg(feature = "experimental")]
+use provider::ErasedPluralSamplesV1Marker;
 use provider::OrdinalV1Marker;
+pub use ranges::PluralRanges;
 use rules::runtime::test_rule;
 
 #[doc(inline)]
@@ -285,7 +289,11 @@ impl PluralCategory {
 /// [`ICU4X`]: ../icu/index.html
 /// [`Plural Type`]: PluralRuleType
 /// [`Plural Category`]: PluralCategory
-pub struct PluralRules(DataPayload<ErasedPluralRulesV1Marker>);
+pub struct PluralRules {
+    rules: DataPayload<ErasedPluralRulesV1Marker>,
+    #[cfg(feature = "experimental")]
+    samples: Option<DataPayload<ErasedPluralSamplesV1Marker>>,
+}
 
 impl PluralRules {
     /// Constructs a new `PluralRules` for a given locale, [`type`] and [`data provider`].
@@ -371,15 +379,17 @@ impl PluralRules {
     where
         D: DataProvider<CardinalV1Marker> + ?Sized,
     {
-        Ok(Self(
-            data_provider
+        Ok(Self {
+            rules: data_provider
                 .load(DataRequest {
                     locale,
                     metadata: Default::default(),
                 })?
                 .take_payload()?
                 .cast(),
-        ))
+            #[cfg(feature = "experimental")]
+            samples: None,
+        })
     }
 
     icu_provider::gen_any_buffer_constructors!(
@@ -435,15 +445,17 @@ impl PluralRules {
     where
         D: DataProvider<OrdinalV1Marker> + ?Sized,
     {
-        Ok(Self(
-            data_provider
+        Ok(Self {
+            rules: data_provider
                 .load(DataRequest {
                     locale,
                     metadata: Default::default(),
                 })?
                 .take_payload()?
                 .cast(),
-        ))
+            #[cfg(feature = "experimental")]
+            samples: None,
+        })
     }
 
     icu_provider::gen_any_buffer_constructors!(
@@ -457,6 +469,192 @@ impl PluralRules {
         ]
     );
 
+    /// 🚧 \[Experimental\] Constructs a new `PluralRules` for a given locale and [`type`], which
+    /// also loads the sample numbers of each category.
+    ///
+    /// The samples are returned by [`samples_for()`](PluralRules::samples_for()), and are
+    /// meant for tooling, such as showing translators examples of each plural form.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu_plurals crate. Use with caution.
+    /// </div>
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRuleType, PluralRules};
+    ///
+    /// let rules = PluralRules::try_new_with_samples_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     PluralRuleType::Ordinal,
+    /// )
+    /// .expect("Data should be present");
+    ///
+    /// assert!(rules.samples_for(PluralCategory::Two).is_some());
+    /// ```
+    ///
+    /// [`type`]: PluralRuleType
+    #[cfg(feature = "experimental")]
+    pub fn try_new_with_samples_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        rule_type: PluralRuleType,
+    ) -> Result<Self, PluralsError>
+    where
+        D: DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<provider::CardinalSamplesV1Marker>
+            + DataProvider<provider::OrdinalSamplesV1Marker>
+            + ?Sized,
+    {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let (rules, samples) = match rule_type {
+            PluralRuleType::Cardinal => (
+                Self::try_new_cardinal_unstable(data_provider, locale)?,
+                DataProvider::<provider::CardinalSamplesV1Marker>::load(data_provider, req)?
+                    .take_payload()?
+                    .cast(),
+            ),
+            PluralRuleType::Ordinal => (
+                Self::try_new_ordinal_unstable(data_provider, locale)?,
+                DataProvider::<provider::OrdinalSamplesV1Marker>::load(data_provider, req)?
+                    .take_payload()?
+                    .cast(),
+            ),
+        };
+        Ok(Self {
+            samples: Some(samples),
+            ..rules
+        })
+    }
+
+    #[cfg(feature = "experimental")]
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        rule_type: PluralRuleType,
+        error: PluralsError,
+        functions: [
+            Self::try_new_with_samples_unstable,
+            try_new_with_samples_with_any_provider,
+            try_new_with_samples_with_buffer_provider
+        ]
+    );
+
+    /// 🚧 \[Experimental\] Constructs a new `PluralRules` from plural rules in the syntax of
+    /// [UTS 35], such as `"one: i = 1 and v = 0; other: @integer 0, 2~16"`.
+    ///
+    /// The rules are separated by semicolons, and each consists of a CLDR plural category
+    /// keyword, a colon, and the condition of the rule, optionally followed by samples, which
+    /// are returned by [`samples_for()`](PluralRules::samples_for()). Categories without a
+    /// rule never match; the rule for
+    /// [`PluralCategory::Other`] may be omitted, and must not have a condition.
+    ///
+    /// This can be used to try out rules for locales that do not have them in CLDR yet.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu_plurals crate. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::plurals::{PluralCategory, PluralRules};
+    ///
+    /// let rules = PluralRules::try_new_from_rules(
+    ///     "one: i = 1 and v = 0 @integer 1; \
+    ///      few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 @integer 2~4, 22~24; \
+    ///      other: @integer 0, 5~19, 100",
+    /// )
+    /// .expect("The rules should be valid");
+    ///
+    /// assert_eq!(rules.category_for(1_usize), PluralCategory::One);
+    /// assert_eq!(rules.category_for(23_usize), PluralCategory::Few);
+    /// assert_eq!(rules.category_for(12_usize), PluralCategory::Other);
+    ///
+    /// let mut categories = rules.categories();
+    /// assert_eq!(categories.next(), Some(PluralCategory::One));
+    /// assert_eq!(categories.next(), Some(PluralCategory::Few));
+    /// assert_eq!(categories.next(), Some(PluralCategory::Other));
+    /// assert_eq!(categories.next(), None);
+    ///
+    /// assert!(PluralRules::try_new_from_rules("one: i = 1; one: i = 2").is_err());
+    /// assert!(PluralRules::try_new_from_rules("single: i = 1").is_err());
+    /// ```
+    ///
+    /// [UTS 35]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+    #[cfg(feature = "experimental")]
+    pub fn try_new_from_rules(rules: &str) -> Result<Self, PluralsError> {
+        use alloc::borrow::Cow;
+        use alloc::string::String;
+        use rules::reference::parser::ParserError;
+
+        let mut data = provider::PluralRulesV1::default();
+        let mut samples = provider::PluralSamplesV1::default();
+        let mut has_other = false;
+        for rule in rules
+            .split(';')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+        {
+            let (keyword, rule) = rule.split_once(':').ok_or(ParserError::ExpectedCategory)?;
+            let category = PluralCategory::get_for_cldr_string(keyword.trim())
+                .ok_or(ParserError::ExpectedCategory)?;
+            let rule = rules::reference::parse(rule.as_bytes())?;
+            let sample_text = rule.samples.as_ref().map(|rule_samples| {
+                let mut text = String::new();
+                // Writing to a `String` cannot fail.
+                let _ = rules::reference::serializer::serialize_samples(rule_samples, &mut text);
+                Cow::Owned(text.trim_start().into())
+            });
+            let (field, samples_field) = match category {
+                PluralCategory::Zero => (&mut data.zero, &mut samples.zero),
+                PluralCategory::One => (&mut data.one, &mut samples.one),
+                PluralCategory::Two => (&mut data.two, &mut samples.two),
+                PluralCategory::Few => (&mut data.few, &mut samples.few),
+                PluralCategory::Many => (&mut data.many, &mut samples.many),
+                PluralCategory::Other => {
+                    if !rule.condition.0.is_empty() {
+                        return Err(ParserError::UnexpectedOtherCondition.into());
+                    }
+                    if core::mem::replace(&mut has_other, true) {
+                        return Err(ParserError::DuplicateCategory.into());
+                    }
+                    samples.other = sample_text;
+                    continue;
+                }
+            };
+            if rule.condition.0.is_empty() {
+                return Err(ParserError::ExpectedRelation.into());
+            }
+            if field.is_some() {
+                return Err(ParserError::DuplicateCategory.into());
+            }
+            *field = Some(
+                rules::runtime::ast::Rule::try_from(&rule)
+                    .map_err(|_| ParserError::ValueTooLarge)?,
+            );
+            *samples_field = sample_text;
+        }
+        Ok(Self {
+            rules: DataPayload::from_owned(data),
+            samples: Some(DataPayload::from_owned(samples)),
+        })
+    }
+
     /// Returns the [`Plural Category`] appropriate for the given number.
     ///
     /// # Examples
@@ -508,7 +706,7 @@ impl PluralRules {
     /// [`Plural Category`]: PluralCategory
     /// [`Plural Operands`]: operands::PluralOperands
     pub fn category_for<I: Into<PluralOperands>>(&self, input: I) -> PluralCategory {
-        let rules = self.0.get();
+        let rules = self.rules.get();
         let input = input.into();
 
         macro_rules! test_rule {
@@ -557,7 +755,7 @@ impl PluralRules {
     ///
     /// [`Plural Categories`]: PluralCategory
     pub fn categories(&self) -> impl Iterator<Item = PluralCategory> + '_ {
-        let rules = self.0.get();
+        let rules = self.rules.get();
 
         macro_rules! test_rule {
             ($rule:ident, $cat:ident) => {
@@ -576,4 +774,107 @@ impl PluralRules {
             .chain(test_rule!(many, Many))
             .chain(Some(PluralCategory::Other).into_iter())
     }
+
+    /// 🚧 \[Experimental\] Returns the condition of the rule for the given category, in the
+    /// syntax of [UTS 35], or `None` if the category has no rule.
+    ///
+    /// [`PluralCategory::Other`] never has a rule, since it matches all numbers that no other
+    /// category matches.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu_plurals crate. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRules};
+    ///
+    /// let rules = PluralRules::try_new_cardinal_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    /// )
+    /// .expect("Data should be present");
+    ///
+    /// assert_eq!(
+    ///     rules.rule_text_for(PluralCategory::One).as_deref(),
+    ///     Some("i = 1 and v = 0")
+    /// );
+    /// assert_eq!(rules.rule_text_for(PluralCategory::Few), None);
+    /// assert_eq!(rules.rule_text_for(PluralCategory::Other), None);
+    /// ```
+    ///
+    /// [UTS 35]: https://unicode.org/reports/tr35/tr35-numbers.html#Plural_rules_syntax
+    #[cfg(feature = "experimental")]
+    pub fn rule_text_for(&self, category: PluralCategory) -> Option<alloc::string::String> {
+        use alloc::string::ToString;
+
+        let rules = self.rules.get();
+        match category {
+            PluralCategory::Zero => rules.zero.as_ref(),
+            PluralCategory::One => rules.one.as_ref(),
+            PluralCategory::Two => rules.two.as_ref(),
+            PluralCategory::Few => rules.few.as_ref(),
+            PluralCategory::Many => rules.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .map(|rule| rule.to_string())
+    }
+
+    /// 🚧 \[Experimental\] Returns the sample numbers of the given category, or `None` if they
+    /// are not available.
+    ///
+    /// Samples are only available if the `PluralRules` was constructed with
+    /// [`try_new_with_samples_unstable()`](PluralRules::try_new_with_samples_unstable()) or
+    /// with [`try_new_from_rules()`](PluralRules::try_new_from_rules()) from rules that have
+    /// samples.
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+    /// of the icu_plurals crate. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::rules::reference::ast::DecimalValue;
+    /// use icu::plurals::{PluralCategory, PluralRuleType, PluralRules};
+    ///
+    /// let rules = PluralRules::try_new_with_samples_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("fr").into(),
+    ///     PluralRuleType::Cardinal,
+    /// )
+    /// .expect("Data should be present");
+    ///
+    /// let samples = rules
+    ///     .samples_for(PluralCategory::Many)
+    ///     .expect("French has samples for 'many'");
+    /// let integers = samples.integer.expect("Samples should include integers");
+    /// assert_eq!(
+    ///     integers.sample_ranges[1].lower_val,
+    ///     DecimalValue("1c6".into())
+    /// );
+    /// assert!(integers.ellipsis);
+    ///
+    /// assert_eq!(rules.samples_for(PluralCategory::Two), None);
+    /// ```
+    #[cfg(feature = "experimental")]
+    pub fn samples_for(&self, category: PluralCategory) -> Option<rules::reference::ast::Samples> {
+        let samples = self.samples.as_ref()?.get();
+        let text = match category {
+            PluralCategory::Zero => samples.zero.as_ref(),
+            PluralCategory::One => samples.one.as_ref(),
+            PluralCategory::Two => samples.two.as_ref(),
+            PluralCategory::Few => samples.few.as_ref(),
+            PluralCategory::Many => samples.many.as_ref(),
+            PluralCategory::Other => samples.other.as_ref(),
+        }?;
+        rules::reference::parse(text.as_bytes()).ok()?.samples
+    }
 }
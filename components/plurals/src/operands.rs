@@ -6,7 +6,7 @@ use core::isize;
 use core::num::ParseIntError;
 use core::str::FromStr;
 use displaydoc::Display;
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 
 /// A full plural operands representation of a number. See [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules) for complete operands description.
 /// Plural operands in compliance with [CLDR Plural Rules](http://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules).
@@ -18,8 +18,10 @@ use fixed_decimal::FixedDecimal;
 /// The following types can be converted to [`PluralOperands`]:
 ///
 /// - Integers, signed and unsigned
-/// - Strings representing an arbitrary-precision decimal
+/// - Strings representing an arbitrary-precision decimal, optionally followed by a compact
+///   exponent introduced by `c` or `e`
 /// - [`FixedDecimal`]
+/// - [`CompactDecimal`]
 ///
 /// This crate does not support selection from a floating-point number, because floats are not
 /// capable of carrying trailing zeros, which are required for proper plural rule selection. For
@@ -85,6 +87,27 @@ use fixed_decimal::FixedDecimal;
 ///     (&FixedDecimal::from(12345).multiplied_pow10(-2)).into()
 /// );
 /// ```
+///
+/// From [`CompactDecimal`]
+///
+/// ```
+/// use fixed_decimal::CompactDecimal;
+/// use icu::plurals::PluralOperands;
+/// use icu_plurals::rules::RawPluralOperands;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     PluralOperands::from(RawPluralOperands {
+///         i: 1,
+///         v: 1,
+///         w: 1,
+///         f: 2,
+///         t: 2,
+///         c: 6,
+///     }),
+///     (&CompactDecimal::from_str("1.2c6").unwrap()).into()
+/// );
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[allow(clippy::exhaustive_structs)] // mostly stable, new operands may be added at the cadence of ICU's release cycle
 pub struct PluralOperands {
@@ -98,7 +121,8 @@ pub struct PluralOperands {
     pub(crate) f: u64,
     /// Visible fraction digits without trailing zeros
     pub(crate) t: u64,
-    /// Exponent of the power of 10 used in compact decimal formatting
+    /// Exponent of the power of 10 used in compact decimal formatting, as the operands `c` and
+    /// `e`; the other operands are those of the significand
     pub(crate) c: usize,
 }
 
@@ -130,7 +154,8 @@ impl From<std::io::Error> for OperandsError {
 }
 
 fn get_exponent(input: &str) -> Result<(&str, usize), OperandsError> {
-    if let Some((base, exponent)) = input.split_once('e') {
+    // CLDR writes the compact exponent with `c`, and formerly with `e`.
+    if let Some((base, exponent)) = input.split_once(['c', 'e']) {
         Ok((base, exponent.parse()?))
     } else {
         Ok((input, 0))
@@ -272,3 +297,37 @@ impl From<&FixedDecimal> for PluralOperands {
         }
     }
 }
+
+impl From<&CompactDecimal> for PluralOperands {
+    /// Converts a [`fixed_decimal::CompactDecimal`] to [`PluralOperands`], with the operands of
+    /// its significand, as for a [`FixedDecimal`], and its exponent as the operands `c` and `e`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::CompactDecimal;
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRules};
+    /// use std::str::FromStr;
+    ///
+    /// let rules = PluralRules::try_new_cardinal_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("fr").into(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// // 1 million, but 2 millions de personnes.
+    /// let one_million = CompactDecimal::from_str("1c6").unwrap();
+    /// let two_million = CompactDecimal::from_str("2c6").unwrap();
+    /// let two_thousand = CompactDecimal::from_str("2c3").unwrap();
+    /// assert_eq!(rules.category_for(&one_million), PluralCategory::One);
+    /// assert_eq!(rules.category_for(&two_million), PluralCategory::Many);
+    /// assert_eq!(rules.category_for(&two_thousand), PluralCategory::Other);
+    /// ```
+    fn from(compact: &CompactDecimal) -> Self {
+        let mut operands = Self::from(compact.significand());
+        // The exponent of a `CompactDecimal` is never negative.
+        operands.c = compact.exponent() as usize;
+        operands
+    }
+}
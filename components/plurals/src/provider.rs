@@ -16,10 +16,11 @@
 //! Read more about data providers: [`icu_provider`]
 
 use crate::rules::runtime::ast::Rule;
+use alloc::borrow::Cow;
 use icu_provider::DataMarker;
 use icu_provider::{yoke, zerofrom};
+use zerovec::ZeroMap;
 
-#[cfg(doc)]
 use crate::PluralCategory;
 
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
@@ -61,8 +62,171 @@ pub struct PluralRulesV1<'data> {
     pub many: Option<Rule<'data>>,
 }
 
+/// Sample numbers of each plural category, in the syntax of the `@integer` and `@decimal`
+/// sample lists of UTS 35, such as `"@integer 0, 2~16, 100 @decimal 0.0~1.5"`. Unlike
+/// [`PluralRulesV1`], this includes the "other" category.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Samples>
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(
+    CardinalSamplesV1Marker = "plurals/cardinal_samples@1",
+    OrdinalSamplesV1Marker = "plurals/ordinal_samples@1"
+)]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct PluralSamplesV1<'data> {
+    /// Samples of [`PluralCategory::Zero`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub zero: Option<Cow<'data, str>>,
+    /// Samples of [`PluralCategory::One`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub one: Option<Cow<'data, str>>,
+    /// Samples of [`PluralCategory::Two`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub two: Option<Cow<'data, str>>,
+    /// Samples of [`PluralCategory::Few`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub few: Option<Cow<'data, str>>,
+    /// Samples of [`PluralCategory::Many`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub many: Option<Cow<'data, str>>,
+    /// Samples of [`PluralCategory::Other`], or `None` if not present.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub other: Option<Cow<'data, str>>,
+}
+
+/// The plural categories of number ranges, such as "1–3", given the categories of their start
+/// and end.
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(PluralRangesV1Marker = "plurals/ranges@1")]
+#[derive(Default, Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct PluralRangesV1<'data> {
+    /// A map from the start and end categories of a range to the category of the range.
+    ///
+    /// Ranges whose category is the category of their end are omitted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ranges: ZeroMap<'data, UnvalidatedPluralRange, RawPluralCategory>,
+}
+
+/// A [`PluralCategory`] in a form that can be stored in a [`ZeroMap`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RawPluralCategoryULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+pub enum RawPluralCategory {
+    /// [`PluralCategory::Other`]
+    Other = 0,
+    /// [`PluralCategory::Zero`]
+    Zero = 1,
+    /// [`PluralCategory::One`]
+    One = 2,
+    /// [`PluralCategory::Two`]
+    Two = 3,
+    /// [`PluralCategory::Few`]
+    Few = 4,
+    /// [`PluralCategory::Many`]
+    Many = 5,
+}
+
+impl From<PluralCategory> for RawPluralCategory {
+    fn from(category: PluralCategory) -> Self {
+        match category {
+            PluralCategory::Other => Self::Other,
+            PluralCategory::Zero => Self::Zero,
+            PluralCategory::One => Self::One,
+            PluralCategory::Two => Self::Two,
+            PluralCategory::Few => Self::Few,
+            PluralCategory::Many => Self::Many,
+        }
+    }
+}
+
+impl From<RawPluralCategory> for PluralCategory {
+    fn from(category: RawPluralCategory) -> Self {
+        match category {
+            RawPluralCategory::Other => Self::Other,
+            RawPluralCategory::Zero => Self::Zero,
+            RawPluralCategory::One => Self::One,
+            RawPluralCategory::Two => Self::Two,
+            RawPluralCategory::Few => Self::Few,
+            RawPluralCategory::Many => Self::Many,
+        }
+    }
+}
+
+/// The start and end categories of a range, packed into the high and low four bits of a byte
+/// as [`RawPluralCategory`] values.
+///
+/// The value is not validated: a byte that does not hold two categories simply matches no
+/// range.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(UnvalidatedPluralRangeULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_plurals::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct UnvalidatedPluralRange(pub u8);
+
+impl UnvalidatedPluralRange {
+    /// Packs the start and end categories of a range.
+    pub fn from_range(start: RawPluralCategory, end: RawPluralCategory) -> Self {
+        Self((start as u8) << 4 | end as u8)
+    }
+}
+
 pub(crate) struct ErasedPluralRulesV1Marker;
 
 impl DataMarker for ErasedPluralRulesV1Marker {
     type Yokeable = PluralRulesV1<'static>;
 }
+
+#[cfg(feature = "experimental")]
+pub(crate) struct ErasedPluralSamplesV1Marker;
+
+#[cfg(feature = "experimental")]
+impl DataMarker for ErasedPluralSamplesV1Marker {
+    type Yokeable = PluralSamplesV1<'static>;
+}
@@ -0,0 +1,134 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{PluralRangesV1Marker, UnvalidatedPluralRange};
+use crate::{PluralCategory, PluralsError};
+use icu_provider::prelude::*;
+
+/// A struct which provides the [`Plural Category`] of a range of numbers, such as "1–3", from
+/// the categories of its start and end.
+///
+/// The categories of the start and end are computed with [`PluralRules`](crate::PluralRules).
+///
+/// More information: <https://unicode.org/reports/tr35/tr35-numbers.html#Plural_Ranges>
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::locale;
+/// use icu::plurals::{PluralCategory, PluralRanges, PluralRules};
+///
+/// let locale = locale!("ru").into();
+/// let rules =
+///     PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale)
+///         .expect("Data should be present");
+/// let ranges = PluralRanges::try_new_unstable(&icu_testdata::unstable(), &locale)
+///     .expect("Data should be present");
+///
+/// // "1–2" is "few", like 2, as in "1–2 дня"
+/// let start = rules.category_for(1_usize);
+/// let end = rules.category_for(2_usize);
+/// assert_eq!(start, PluralCategory::One);
+/// assert_eq!(end, PluralCategory::Few);
+/// assert_eq!(
+///     ranges.category_for_range(start, end),
+///     PluralCategory::Few
+/// );
+/// ```
+///
+/// [`Plural Category`]: PluralCategory
+#[derive(Debug)]
+pub struct PluralRanges(DataPayload<PluralRangesV1Marker>);
+
+impl PluralRanges {
+    /// Constructs a new `PluralRanges` for a given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(data_provider: &D, locale: &DataLocale) -> Result<Self, PluralsError>
+    where
+        D: DataProvider<PluralRangesV1Marker> + ?Sized,
+    {
+        Ok(Self(
+            data_provider
+                .load(DataRequest {
+                    locale,
+                    metadata: Default::default(),
+                })?
+                .take_payload()?,
+        ))
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: include, options: skip, error: PluralsError);
+
+    /// Returns the [`Plural Category`] of a range whose start and end have the given categories.
+    ///
+    /// If the locale has no data for the pair of categories, the category of the end is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::locale;
+    /// use icu::plurals::{PluralCategory, PluralRanges};
+    ///
+    /// let ranges = PluralRanges::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("ar").into(),
+    /// )
+    /// .expect("Data should be present");
+    ///
+    /// assert_eq!(
+    ///     ranges.category_for_range(PluralCategory::Zero, PluralCategory::One),
+    ///     PluralCategory::Zero
+    /// );
+    /// assert_eq!(
+    ///     ranges.category_for_range(PluralCategory::One, PluralCategory::Many),
+    ///     PluralCategory::Many
+    /// );
+    /// ```
+    ///
+    /// [`Plural Category`]: PluralCategory
+    pub fn category_for_range(&self, start: PluralCategory, end: PluralCategory) -> PluralCategory {
+        let range = UnvalidatedPluralRange::from_range(start.into(), end.into());
+        self.0
+            .get()
+            .ranges
+            .get_copied(&range)
+            .map(PluralCategory::from)
+            .unwrap_or(end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{PluralRangesV1, RawPluralCategory};
+    use zerovec::ZeroMap;
+
+    #[test]
+    fn test_category_for_range() {
+        let mut ranges = ZeroMap::new();
+        ranges.insert(
+            &UnvalidatedPluralRange::from_range(RawPluralCategory::One, RawPluralCategory::Other),
+            &RawPluralCategory::One,
+        );
+        let ranges = PluralRanges(DataPayload::from_owned(PluralRangesV1 { ranges }));
+
+        assert_eq!(
+            ranges.category_for_range(PluralCategory::One, PluralCategory::Other),
+            PluralCategory::One
+        );
+        assert_eq!(
+            ranges.category_for_range(PluralCategory::Other, PluralCategory::One),
+            PluralCategory::One
+        );
+        assert_eq!(
+            ranges.category_for_range(PluralCategory::Few, PluralCategory::Many),
+            PluralCategory::Many
+        );
+    }
+}
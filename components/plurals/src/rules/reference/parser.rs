@@ -29,6 +29,12 @@ pub enum ParserError {
     ExpectedSampleType,
     #[displaydoc("Value too large")]
     ValueTooLarge,
+    #[displaydoc("expected plural category")]
+    ExpectedCategory,
+    #[displaydoc("duplicate plural category")]
+    DuplicateCategory,
+    #[displaydoc("unexpected condition for the 'other' category")]
+    UnexpectedOtherCondition,
 }
 
 #[cfg(feature = "std")]
@@ -306,8 +312,16 @@ impl<'p> Parser<'p> {
             }
         }
 
-        if self.take_if(Token::E) {
-            s.push('e');
+        // Both `e` and `c` denote the exponent of compact numbers, such as `1.1c6`.
+        let exponent = if self.take_if(Token::E) {
+            Some('e')
+        } else if self.take_if(Token::Operand(ast::Operand::C)) {
+            Some('c')
+        } else {
+            None
+        };
+        if let Some(exponent) = exponent {
+            s.push(exponent);
             match self.lexer.peek() {
                 Some(Token::Zero) => s.push('0'),
                 Some(Token::Number(v)) => {
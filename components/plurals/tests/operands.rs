@@ -6,8 +6,9 @@ mod fixtures;
 mod helpers;
 
 use std::convert::TryInto;
+use std::str::FromStr;
 
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 #[cfg(feature = "experimental")]
 use icu_plurals::rules::RawPluralOperands;
 use icu_plurals::PluralOperands;
@@ -74,3 +75,13 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+fn test_from_compact_decimals() {
+    for input in ["1c6", "1.2c6", "-1.20c3", "0.5c9", "123456"] {
+        let compact = CompactDecimal::from_str(input).expect("Failed to parse a compact decimal.");
+        let actual = PluralOperands::from(&compact);
+        let expected: PluralOperands = input.parse().expect("Failed to parse to operands.");
+        assert_eq!(expected, actual, "{input}");
+    }
+}
@@ -57,3 +57,147 @@ fn test_plural_category_all() {
     assert_eq!(categories[4], PluralCategory::Two);
     assert_eq!(categories[5], PluralCategory::Zero);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_plural_rules_from_strings() {
+    use icu_plurals::PluralOperands;
+
+    let data_rules =
+        PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale!("ru").into())
+            .unwrap();
+    let string_rules = PluralRules::try_new_from_rules(
+        "one: v = 0 and i % 10 = 1 and i % 100 != 11 @integer 1, 21, 31; \
+         few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14 @integer 2~4, 22~24; \
+         many: v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14; \
+         other: @decimal 0.0~1.5, 10.0",
+    )
+    .unwrap();
+
+    assert!(string_rules.categories().eq(data_rules.categories()));
+    for i in 0..250 {
+        for input in [format!("{i}"), format!("{i}.5"), format!("{i}.0")] {
+            let operands: PluralOperands = input.parse().unwrap();
+            assert_eq!(
+                string_rules.category_for(operands),
+                data_rules.category_for(operands),
+                "{input}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_plural_rules_from_invalid_strings() {
+    use icu_plurals::rules::reference::ParserError;
+    use icu_plurals::PluralsError;
+
+    for (rules, error) in [
+        ("one i = 1", ParserError::ExpectedCategory),
+        ("single: i = 1", ParserError::ExpectedCategory),
+        ("one: i = 1; one: i = 2", ParserError::DuplicateCategory),
+        (
+            "one: i = 1; other: i = 2",
+            ParserError::UnexpectedOtherCondition,
+        ),
+        ("one: @integer 1", ParserError::ExpectedRelation),
+        ("one: i = ", ParserError::ExpectedValue),
+    ] {
+        assert_eq!(
+            PluralRules::try_new_from_rules(rules).err(),
+            Some(PluralsError::Parser(error)),
+            "{rules}"
+        );
+    }
+    // Whitespace and empty rules are ignored.
+    assert!(PluralRules::try_new_from_rules("  one : i = 1 ;; ").is_ok());
+    assert!(PluralRules::try_new_from_rules("").is_ok());
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_plural_rules_samples() {
+    use icu_plurals::PluralOperands;
+
+    for locale in [locale!("ar"), locale!("en"), locale!("fr"), locale!("ru")] {
+        for rule_type in [PluralRuleType::Cardinal, PluralRuleType::Ordinal] {
+            let rules = PluralRules::try_new_with_samples_unstable(
+                &icu_testdata::unstable(),
+                &locale.clone().into(),
+                rule_type,
+            )
+            .unwrap();
+            for category in rules.categories() {
+                let samples = rules
+                    .samples_for(category)
+                    .unwrap_or_else(|| panic!("{locale} {rule_type:?} {category:?}"));
+                // Every sample value must be in the category it is a sample of.
+                for list in [samples.integer, samples.decimal].into_iter().flatten() {
+                    for range in list.sample_ranges {
+                        // Operands of compact values such as "1c6" are those of the
+                        // significand, while the samples use the full value.
+                        for value in core::iter::once(range.lower_val)
+                            .chain(range.upper_val)
+                            .filter(|value| !value.0.contains('c'))
+                        {
+                            let operands: PluralOperands = value.0.parse().unwrap();
+                            assert_eq!(
+                                rules.category_for(operands),
+                                category,
+                                "{locale} {rule_type:?} {}",
+                                value.0
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let rules =
+        PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale!("fr").into())
+            .unwrap();
+    assert_eq!(rules.samples_for(PluralCategory::One), None);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_plural_rules_text() {
+    let data_rules =
+        PluralRules::try_new_cardinal_unstable(&icu_testdata::unstable(), &locale!("ru").into())
+            .unwrap();
+    let rules = data_rules
+        .categories()
+        .filter_map(|category| {
+            let text = data_rules.rule_text_for(category)?;
+            Some(format!("{category:?}: {text}").to_lowercase())
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    assert_eq!(
+        rules,
+        "one: v = 0 and i % 10 = 1 and i % 100 != 11; \
+         few: v = 0 and i % 10 = 2..4 and i % 100 != 12..14; \
+         many: v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14"
+    );
+
+    // The text of rules parsed from strings round-trips, and their samples are kept.
+    let string_rules = PluralRules::try_new_from_rules(&rules).unwrap();
+    for category in data_rules.categories() {
+        assert_eq!(
+            string_rules.rule_text_for(category),
+            data_rules.rule_text_for(category)
+        );
+    }
+    assert_eq!(string_rules.samples_for(PluralCategory::One), None);
+
+    let string_rules =
+        PluralRules::try_new_from_rules("one: n = 1 @integer 1; other: @integer 0, 2~16, …")
+            .unwrap();
+    let samples = string_rules.samples_for(PluralCategory::Other).unwrap();
+    let integers = samples.integer.unwrap();
+    assert_eq!(integers.sample_ranges.len(), 2);
+    assert!(integers.ellipsis);
+    assert_eq!(samples.decimal, None);
+}
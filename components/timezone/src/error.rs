@@ -20,6 +20,15 @@ pub enum TimeZoneError {
     /// The time zone offset was invalid.
     #[displaydoc("Failed to parse time-zone offset")]
     InvalidOffset,
+    /// There is no offset data for the time zone.
+    #[displaydoc("No offset data for the time zone")]
+    MissingOffsetData,
+    /// The local time occurs twice in the time zone.
+    #[displaydoc("The local time is ambiguous in the time zone")]
+    AmbiguousLocalTime,
+    /// The local time does not occur in the time zone.
+    #[displaydoc("The local time does not exist in the time zone")]
+    NonexistentLocalTime,
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("{0}")]
     Data(DataError),
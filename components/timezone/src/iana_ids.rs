@@ -0,0 +1,129 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::{Bcp47ToIanaMapV1Marker, IanaToBcp47MapV1Marker, TimeZoneBcp47Id};
+use core::cmp::Ordering;
+use icu_provider::prelude::*;
+
+/// [`TimeZoneIdMapper`] uses data from the [data provider] to convert between IANA time zone
+/// identifiers, such as `"America/Chicago"`, and BCP-47 time zone identifiers, such as `"uschi"`.
+///
+/// IANA identifiers are matched ASCII-case-insensitively, and aliases such as `"US/Central"` are
+/// supported. Each BCP-47 identifier maps back to a single canonical IANA identifier.
+///
+/// # Examples
+///
+/// ```
+/// use icu::timezone::provider::TimeZoneBcp47Id;
+/// use icu::timezone::TimeZoneIdMapper;
+/// use tinystr::tinystr;
+///
+/// let mapper = TimeZoneIdMapper::try_new_unstable(&icu_testdata::unstable())
+///     .expect("data exists");
+///
+/// // Canonical identifier and alias:
+/// assert_eq!(
+///     mapper.iana_to_bcp47("America/Los_Angeles"),
+///     Some(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+/// );
+/// assert_eq!(
+///     mapper.iana_to_bcp47("US/Pacific"),
+///     Some(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+/// );
+///
+/// // Unknown identifier:
+/// assert_eq!(mapper.iana_to_bcp47("America/Gotham"), None);
+///
+/// // Round trip through the canonical IANA identifier:
+/// assert_eq!(
+///     mapper.bcp47_to_iana(TimeZoneBcp47Id(tinystr!(8, "uslax"))),
+///     Some("America/Los_Angeles")
+/// );
+/// assert_eq!(
+///     mapper.canonicalize_iana("us/pacific"),
+///     Some("America/Los_Angeles")
+/// );
+///
+/// // Legacy names resolve to the current IANA identifier:
+/// assert_eq!(
+///     mapper.canonicalize_iana("Asia/Calcutta"),
+///     Some("Asia/Kolkata")
+/// );
+/// ```
+///
+/// [data provider]: icu_provider
+pub struct TimeZoneIdMapper {
+    iana_to_bcp47: DataPayload<IanaToBcp47MapV1Marker>,
+    bcp47_to_iana: DataPayload<Bcp47ToIanaMapV1Marker>,
+}
+
+impl TimeZoneIdMapper {
+    /// Constructor that loads data before converting time zone identifiers.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<IanaToBcp47MapV1Marker> + DataProvider<Bcp47ToIanaMapV1Marker> + ?Sized,
+    {
+        let iana_to_bcp47 = DataProvider::<IanaToBcp47MapV1Marker>::load(
+            provider,
+            DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let bcp47_to_iana = DataProvider::<Bcp47ToIanaMapV1Marker>::load(
+            provider,
+            DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        Ok(Self {
+            iana_to_bcp47,
+            bcp47_to_iana,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: TimeZoneError);
+
+    /// Returns the BCP-47 time zone identifier for an IANA time zone identifier.
+    ///
+    /// The lookup is ASCII-case-insensitive and accepts both canonical identifiers and aliases.
+    /// Returns `None` if the identifier is not known.
+    pub fn iana_to_bcp47(&self, iana_id: &str) -> Option<TimeZoneBcp47Id> {
+        self.iana_to_bcp47
+            .get()
+            .map
+            .get_copied_by(|probe| cmp_ascii_lowercase(probe, iana_id))
+    }
+
+    /// Returns the canonical IANA time zone identifier for a BCP-47 time zone identifier.
+    ///
+    /// Returns `None` if the identifier is not known.
+    pub fn bcp47_to_iana(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        self.bcp47_to_iana.get().map.get(&bcp47_id)
+    }
+
+    /// Returns the canonical form of an IANA time zone identifier, resolving aliases.
+    ///
+    /// This is equivalent to calling [`Self::iana_to_bcp47`] followed by [`Self::bcp47_to_iana`].
+    pub fn canonicalize_iana(&self, iana_id: &str) -> Option<&str> {
+        self.iana_to_bcp47(iana_id)
+            .and_then(|bcp47_id| self.bcp47_to_iana(bcp47_id))
+    }
+}
+
+/// Compares a lowercase key from the data against an arbitrary-case input string.
+fn cmp_ascii_lowercase(lowercase: &str, input: &str) -> Ordering {
+    lowercase
+        .bytes()
+        .cmp(input.bytes().map(|b| b.to_ascii_lowercase()))
+}
@@ -30,7 +30,9 @@
 //! 1. IANA time zone IDs, like `"America/Chicago"`
 //! 2. BCP-47 time zone IDs, like `"uschi"`
 //!
-//! ICU4X uses BCP-47 time zone IDs for all of its APIs.
+//! ICU4X uses BCP-47 time zone IDs for all of its APIs. To convert from IANA time zone IDs,
+//! use [`TimeZoneIdMapper`]. Windows time zone IDs, like `"Central Standard Time"`, can be
+//! converted with [`WindowsTimeZoneMapper`].
 //!
 //! ## Metazone
 //!
@@ -74,7 +76,8 @@
 //! The following calculations are currently supported or will be supported:
 //!
 //! 1. Time Zone + Local DateTime → Meta Zone ([`MetazoneCalculator`])
-//! 2. Time Zone + Absolute Time → Offset + Zone Variant (not yet supported)
+//! 2. Time Zone + Absolute Time → Offset + Zone Variant ([`ZoneOffsetCalculator`])
+//! 3. Time Zone + Local DateTime → Absolute Time ([`ZoneOffsetCalculator`])
 //!
 //! # Examples
 //!
@@ -121,16 +124,22 @@
 extern crate alloc;
 
 mod error;
+mod iana_ids;
 mod metazone;
+mod offsets;
 pub mod provider;
 mod time_zone;
 mod types;
+mod windows_ids;
 
 pub use error::TimeZoneError;
+pub use iana_ids::TimeZoneIdMapper;
 pub use metazone::MetazoneCalculator;
+pub use offsets::{LocalTimeDisambiguation, ZoneOffsetCalculator};
 pub use provider::{MetazoneId, TimeZoneBcp47Id};
 pub use time_zone::CustomTimeZone;
 pub use types::{GmtOffset, ZoneVariant};
+pub use windows_ids::WindowsTimeZoneMapper;
 
 #[doc(inline)]
 pub use TimeZoneError as Error;
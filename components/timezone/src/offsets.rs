@@ -0,0 +1,488 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::provider::{
+    TimeZoneBcp47Id, ZoneDstRule, ZoneOffset, ZoneOffsetTransitionsV1Marker, ZoneTransitionRule,
+    ZoneTransitionRuleKind,
+};
+use crate::{GmtOffset, ZoneVariant};
+use core::convert::TryFrom;
+use icu_calendar::types::{IsoHour, IsoMinute, IsoSecond, NanoSecond, Time};
+use icu_calendar::{Date, DateDuration, DateTime, Iso};
+use icu_provider::prelude::*;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// How to resolve a local time that occurs twice or not at all in a time zone, such as during
+/// the transitions into and out of daylight saving time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LocalTimeDisambiguation {
+    /// Use the earlier of the two possible instants.
+    ///
+    /// For a repeated local time, this is the first occurrence. For a skipped local time, this
+    /// interprets the local time with the offset after the transition, resulting in an instant
+    /// before the transition.
+    Earlier,
+    /// Use the later of the two possible instants.
+    ///
+    /// For a repeated local time, this is the second occurrence. For a skipped local time, this
+    /// interprets the local time with the offset before the transition, resulting in an instant
+    /// after the transition.
+    Later,
+    /// Return an error if the local time is repeated or skipped.
+    Reject,
+}
+
+/// [`ZoneOffsetCalculator`] uses data from the [data provider] to calculate the UTC offset and
+/// zone variant of a time zone at a given time, based on the IANA time zone database.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::DateTime;
+/// use icu::timezone::provider::TimeZoneBcp47Id;
+/// use icu::timezone::{GmtOffset, ZoneOffsetCalculator, ZoneVariant};
+/// use tinystr::tinystr;
+///
+/// let zoc = ZoneOffsetCalculator::try_new_unstable(&icu_testdata::unstable())
+///     .expect("data exists");
+/// let uslax = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+///
+/// let (offset, variant) = zoc
+///     .compute_offsets_from_time_zone(
+///         uslax,
+///         &DateTime::try_new_iso_datetime(2022, 1, 1, 0, 0, 0).unwrap(),
+///     )
+///     .expect("zone has data");
+/// assert_eq!(offset.offset_seconds(), -8 * 3600);
+/// assert_eq!(variant, ZoneVariant::standard());
+///
+/// // Times after the last transition in the database use the zone's yearly rule
+/// let (offset, variant) = zoc
+///     .compute_offsets_from_time_zone(
+///         uslax,
+///         &DateTime::try_new_iso_datetime(2050, 7, 1, 0, 0, 0).unwrap(),
+///     )
+///     .expect("zone has data");
+/// assert_eq!(offset.offset_seconds(), -7 * 3600);
+/// assert_eq!(variant, ZoneVariant::daylight());
+/// ```
+///
+/// [data provider]: icu_provider
+pub struct ZoneOffsetCalculator {
+    pub(super) offset_transitions: DataPayload<ZoneOffsetTransitionsV1Marker>,
+}
+
+impl ZoneOffsetCalculator {
+    /// Constructor that loads data before calculating offsets.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<P>(zone_provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<ZoneOffsetTransitionsV1Marker> + ?Sized,
+    {
+        let offset_transitions = zone_provider
+            .load(DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(Self { offset_transitions })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: TimeZoneError);
+
+    /// Calculate the UTC offset and zone variant of a time zone at a UTC date and time.
+    ///
+    /// Returns `None` if there is no data for the time zone.
+    pub fn compute_offsets_from_time_zone(
+        &self,
+        time_zone_id: TimeZoneBcp47Id,
+        utc_datetime: &DateTime<Iso>,
+    ) -> Option<(GmtOffset, ZoneVariant)> {
+        let offset = self.offset_at(time_zone_id, seconds_since_epoch(utc_datetime))?;
+        Some((
+            GmtOffset::try_from_offset_seconds(offset.offset_seconds).ok()?,
+            if offset.is_dst {
+                ZoneVariant::daylight()
+            } else {
+                ZoneVariant::standard()
+            },
+        ))
+    }
+
+    /// Calculate the UTC date and time of a local date and time in a time zone.
+    ///
+    /// Local times that are repeated or skipped because of a change of offset, such as the
+    /// transitions into and out of daylight saving time, are resolved according to
+    /// `disambiguation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TimeZoneError::MissingOffsetData`] if there is no data for the time zone, and
+    /// [`TimeZoneError::AmbiguousLocalTime`] or [`TimeZoneError::NonexistentLocalTime`] if the
+    /// local time is repeated or skipped and `disambiguation` is
+    /// [`LocalTimeDisambiguation::Reject`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::DateTime;
+    /// use icu::timezone::provider::TimeZoneBcp47Id;
+    /// use icu::timezone::{LocalTimeDisambiguation, TimeZoneError, ZoneOffsetCalculator};
+    /// use tinystr::tinystr;
+    ///
+    /// let zoc = ZoneOffsetCalculator::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("data exists");
+    /// let uslax = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+    ///
+    /// // 01:30 happens twice on the day DST ends
+    /// let local = DateTime::try_new_iso_datetime(2022, 11, 6, 1, 30, 0).unwrap();
+    /// let earlier = zoc
+    ///     .compute_utc_from_local(uslax, &local, LocalTimeDisambiguation::Earlier)
+    ///     .expect("zone has data");
+    /// assert_eq!(earlier.time.hour.number(), 8);
+    /// let later = zoc
+    ///     .compute_utc_from_local(uslax, &local, LocalTimeDisambiguation::Later)
+    ///     .expect("zone has data");
+    /// assert_eq!(later.time.hour.number(), 9);
+    /// assert!(matches!(
+    ///     zoc.compute_utc_from_local(uslax, &local, LocalTimeDisambiguation::Reject),
+    ///     Err(TimeZoneError::AmbiguousLocalTime)
+    /// ));
+    /// ```
+    pub fn compute_utc_from_local(
+        &self,
+        time_zone_id: TimeZoneBcp47Id,
+        local_datetime: &DateTime<Iso>,
+        disambiguation: LocalTimeDisambiguation,
+    ) -> Result<DateTime<Iso>, TimeZoneError> {
+        let local = seconds_since_epoch(local_datetime);
+        // Changes of offset are far more than a day apart, so the offsets a day before and a day
+        // after are the only candidates for the offset in effect at the local time.
+        let offset_before = self
+            .offset_at(time_zone_id, local - SECONDS_PER_DAY)
+            .ok_or(TimeZoneError::MissingOffsetData)?;
+        let offset_after = self
+            .offset_at(time_zone_id, local + SECONDS_PER_DAY)
+            .ok_or(TimeZoneError::MissingOffsetData)?;
+        let utc_before = local - i64::from(offset_before.offset_seconds);
+        let utc_after = local - i64::from(offset_after.offset_seconds);
+        let is_valid = |utc, offset: ZoneOffset| {
+            self.offset_at(time_zone_id, utc)
+                .map(|o| o.offset_seconds == offset.offset_seconds)
+                .unwrap_or(false)
+        };
+
+        let utc = match (
+            is_valid(utc_before, offset_before),
+            is_valid(utc_after, offset_after),
+        ) {
+            (true, false) => utc_before,
+            (false, true) => utc_after,
+            (true, true) if utc_before == utc_after => utc_before,
+            (true, true) => match disambiguation {
+                LocalTimeDisambiguation::Earlier => utc_before.min(utc_after),
+                LocalTimeDisambiguation::Later => utc_before.max(utc_after),
+                LocalTimeDisambiguation::Reject => return Err(TimeZoneError::AmbiguousLocalTime),
+            },
+            (false, false) => match disambiguation {
+                LocalTimeDisambiguation::Earlier => utc_before.min(utc_after),
+                LocalTimeDisambiguation::Later => utc_before.max(utc_after),
+                LocalTimeDisambiguation::Reject => return Err(TimeZoneError::NonexistentLocalTime),
+            },
+        };
+        Ok(datetime_from_seconds_since_epoch(
+            utc,
+            local_datetime.time.nanosecond,
+        ))
+    }
+
+    /// Returns the offset in effect at `seconds` since the Unix epoch in UTC.
+    fn offset_at(&self, time_zone_id: TimeZoneBcp47Id, seconds: i64) -> Option<ZoneOffset> {
+        let data = self.offset_transitions.get();
+        let cursor = data.transitions.get0(&time_zone_id)?;
+        let (time, offset) = cursor.get1_copied_at_or_before(&seconds)?;
+        let (last_time, _) = cursor.get1_copied_at_or_before(&i64::MAX)?;
+        // Times after the last transition use the yearly rule, if there is one.
+        if time == last_time {
+            if let Some(offset) = data
+                .rules
+                .get_copied(&time_zone_id)
+                .and_then(|rule| offset_from_rule(&rule, seconds))
+            {
+                return Some(offset);
+            }
+        }
+        Some(offset)
+    }
+}
+
+/// Evaluates a yearly daylight saving time rule at `seconds` since the Unix epoch in UTC.
+///
+/// Returns `None` if the rule does not describe valid dates.
+fn offset_from_rule(rule: &ZoneDstRule, seconds: i64) -> Option<ZoneOffset> {
+    let std_offset = i64::from(rule.std_offset_seconds);
+    let dst_offset = i64::from(rule.dst_offset_seconds);
+    let year = datetime_from_seconds_since_epoch(seconds + std_offset, NanoSecond::zero())
+        .date
+        .year()
+        .number;
+    let start = transition_local_seconds(&rule.start, year)? - std_offset;
+    let end = transition_local_seconds(&rule.end, year)? - dst_offset;
+    let is_dst = if start <= end {
+        // Northern hemisphere: daylight saving time is within the year.
+        start <= seconds && seconds < end
+    } else {
+        // Southern hemisphere: daylight saving time spans the end of the year.
+        !(end <= seconds && seconds < start)
+    };
+    Some(ZoneOffset {
+        offset_seconds: if is_dst {
+            rule.dst_offset_seconds
+        } else {
+            rule.std_offset_seconds
+        },
+        is_dst,
+    })
+}
+
+/// Returns the local time of a transition in `year` in seconds since the local Unix epoch, or
+/// `None` if the rule does not describe a valid date.
+fn transition_local_seconds(rule: &ZoneTransitionRule, year: i32) -> Option<i64> {
+    let date = match rule.kind {
+        ZoneTransitionRuleKind::MonthWeekDay => {
+            let first = Date::try_new_iso_date(year, rule.month, 1).ok()?;
+            // `IsoWeekday` counts from 1 for Monday to 7 for Sunday, rules from 0 for Sunday.
+            let first_weekday = first.day_of_week() as u16 % 7;
+            let mut day = 1
+                + (rule.day % 7 + 7 - first_weekday) % 7
+                + 7 * u16::from(rule.week.saturating_sub(1));
+            // Week 5 means the last such day of the month, which may be in week 4.
+            while day > u16::from(first.days_in_month()) {
+                day -= 7;
+            }
+            Date::try_new_iso_date(year, rule.month, u8::try_from(day).ok()?).ok()?
+        }
+        ZoneTransitionRuleKind::JulianDay => {
+            // Julian days count from 1 and never count February 29.
+            let jan_1 = Date::try_new_iso_date(year, 1, 1).ok()?;
+            let leap_day = jan_1.days_in_year() == 366 && rule.day >= 60;
+            jan_1.added(DateDuration::new(
+                0,
+                0,
+                0,
+                i32::from(rule.day) - 1 + i32::from(leap_day),
+            ))
+        }
+        ZoneTransitionRuleKind::DayOfYear => Date::try_new_iso_date(year, 1, 1)
+            .ok()?
+            .added(DateDuration::new(0, 0, 0, i32::from(rule.day))),
+    };
+    let midnight = Time::new(
+        IsoHour::zero(),
+        IsoMinute::zero(),
+        IsoSecond::zero(),
+        NanoSecond::zero(),
+    );
+    Some(seconds_since_epoch(&DateTime::new(date, midnight)) + i64::from(rule.time_seconds))
+}
+
+fn seconds_since_epoch(datetime: &DateTime<Iso>) -> i64 {
+    i64::from(datetime.minutes_since_local_unix_epoch()) * 60
+        + i64::from(datetime.time.second.number())
+}
+
+fn datetime_from_seconds_since_epoch(seconds: i64, nanosecond: NanoSecond) -> DateTime<Iso> {
+    let mut datetime = DateTime::from_minutes_since_local_unix_epoch(seconds.div_euclid(60) as i32);
+    #[allow(clippy::unwrap_used)] // the remainder is in [0, 60)
+    {
+        datetime.time.second = IsoSecond::try_from(seconds.rem_euclid(60) as u8).unwrap();
+    }
+    datetime.time.nanosecond = nanosecond;
+    datetime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::ZoneOffsetTransitionsV1;
+    use tinystr::tinystr;
+
+    const LOS_ANGELES: TimeZoneBcp47Id = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+    const SYDNEY: TimeZoneBcp47Id = TimeZoneBcp47Id(tinystr!(8, "ausyd"));
+
+    fn dst_rule(
+        std_offset_seconds: i32,
+        (start_month, start_week, start_time): (u8, u8, i32),
+        (end_month, end_week, end_time): (u8, u8, i32),
+    ) -> ZoneDstRule {
+        let sunday = |month, week, time_seconds| ZoneTransitionRule {
+            kind: ZoneTransitionRuleKind::MonthWeekDay,
+            month,
+            week,
+            day: 0,
+            time_seconds,
+        };
+        ZoneDstRule {
+            std_offset_seconds,
+            dst_offset_seconds: std_offset_seconds + 3600,
+            start: sunday(start_month, start_week, start_time),
+            end: sunday(end_month, end_week, end_time),
+        }
+    }
+
+    fn calculator() -> ZoneOffsetCalculator {
+        let offset = |offset_seconds, is_dst| ZoneOffset {
+            offset_seconds,
+            is_dst,
+        };
+        ZoneOffsetCalculator {
+            offset_transitions: DataPayload::from_owned(ZoneOffsetTransitionsV1 {
+                transitions: [
+                    (LOS_ANGELES, i64::MIN, offset(-28378, false)),
+                    (LOS_ANGELES, -2717640000, offset(-28800, false)),
+                    (LOS_ANGELES, -1633269600, offset(-25200, true)),
+                    (LOS_ANGELES, -1615129200, offset(-28800, false)),
+                    (SYDNEY, i64::MIN, offset(36292, false)),
+                    (SYDNEY, -2364113092, offset(36000, false)),
+                ]
+                .into_iter()
+                .collect(),
+                rules: [
+                    // PST8PDT,M3.2.0,M11.1.0
+                    (LOS_ANGELES, dst_rule(-28800, (3, 2, 7200), (11, 1, 7200))),
+                    // AEST-10AEDT,M10.1.0,M4.1.0/3
+                    (SYDNEY, dst_rule(36000, (10, 1, 7200), (4, 1, 10800))),
+                ]
+                .into_iter()
+                .collect(),
+            }),
+        }
+    }
+
+    fn datetime(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> DateTime<Iso> {
+        DateTime::try_new_iso_datetime(year, month, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn offsets_from_utc() {
+        let calculator = calculator();
+        let cases = [
+            (LOS_ANGELES, datetime(1850, 1, 1, 0, 0), -28378, false),
+            (LOS_ANGELES, datetime(1918, 3, 31, 9, 59), -28800, false),
+            (LOS_ANGELES, datetime(1918, 3, 31, 10, 0), -25200, true),
+            (LOS_ANGELES, datetime(1918, 10, 27, 8, 59), -25200, true),
+            (LOS_ANGELES, datetime(2022, 1, 1, 0, 0), -28800, false),
+            (LOS_ANGELES, datetime(2022, 7, 1, 12, 0), -25200, true),
+            // Daylight saving time starts at 10:00 UTC and ends at 09:00 UTC.
+            (LOS_ANGELES, datetime(2022, 3, 13, 9, 59), -28800, false),
+            (LOS_ANGELES, datetime(2022, 3, 13, 10, 0), -25200, true),
+            (LOS_ANGELES, datetime(2022, 11, 6, 8, 59), -25200, true),
+            (LOS_ANGELES, datetime(2022, 11, 6, 9, 0), -28800, false),
+            (SYDNEY, datetime(2022, 1, 1, 0, 0), 39600, true),
+            (SYDNEY, datetime(2022, 7, 1, 0, 0), 36000, false),
+            (SYDNEY, datetime(2022, 12, 31, 23, 0), 39600, true),
+        ];
+        for (time_zone_id, utc_datetime, offset_seconds, is_dst) in cases {
+            let zone_variant = if is_dst {
+                ZoneVariant::daylight()
+            } else {
+                ZoneVariant::standard()
+            };
+            assert_eq!(
+                calculator
+                    .compute_offsets_from_time_zone(time_zone_id, &utc_datetime)
+                    .map(|(gmt_offset, zone_variant)| (gmt_offset.offset_seconds(), zone_variant)),
+                Some((offset_seconds, zone_variant)),
+                "{time_zone_id:?} at {utc_datetime:?}"
+            );
+        }
+
+        assert!(calculator
+            .compute_offsets_from_time_zone(
+                TimeZoneBcp47Id(tinystr!(8, "usnyc")),
+                &datetime(2022, 1, 1, 0, 0)
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn utc_from_local() {
+        use LocalTimeDisambiguation::*;
+        let calculator = calculator();
+        let utc = |local, disambiguation| {
+            calculator.compute_utc_from_local(LOS_ANGELES, &local, disambiguation)
+        };
+
+        // Unambiguous local times are not affected by the disambiguation.
+        for disambiguation in [Earlier, Later, Reject] {
+            assert_eq!(
+                utc(datetime(2022, 7, 1, 12, 0), disambiguation),
+                Ok(datetime(2022, 7, 1, 19, 0))
+            );
+        }
+
+        // 02:30 is skipped when daylight saving time starts.
+        let skipped = datetime(2022, 3, 13, 2, 30);
+        assert_eq!(utc(skipped, Earlier), Ok(datetime(2022, 3, 13, 9, 30)));
+        assert_eq!(utc(skipped, Later), Ok(datetime(2022, 3, 13, 10, 30)));
+        assert_eq!(
+            utc(skipped, Reject),
+            Err(TimeZoneError::NonexistentLocalTime)
+        );
+
+        // 01:30 is repeated when daylight saving time ends.
+        let repeated = datetime(2022, 11, 6, 1, 30);
+        assert_eq!(utc(repeated, Earlier), Ok(datetime(2022, 11, 6, 8, 30)));
+        assert_eq!(utc(repeated, Later), Ok(datetime(2022, 11, 6, 9, 30)));
+        assert_eq!(
+            utc(repeated, Reject),
+            Err(TimeZoneError::AmbiguousLocalTime)
+        );
+
+        assert_eq!(
+            calculator.compute_utc_from_local(
+                TimeZoneBcp47Id(tinystr!(8, "usnyc")),
+                &datetime(2022, 1, 1, 0, 0),
+                Reject
+            ),
+            Err(TimeZoneError::MissingOffsetData)
+        );
+    }
+
+    #[test]
+    fn transition_rules() {
+        let rule = |kind, month, week, day| ZoneTransitionRule {
+            kind,
+            month,
+            week,
+            day,
+            time_seconds: 0,
+        };
+        let date = |rule, year| {
+            let days = transition_local_seconds(&rule, year).unwrap() / SECONDS_PER_DAY;
+            DateTime::from_minutes_since_local_unix_epoch(days as i32 * 24 * 60).date
+        };
+        let iso = |year, month, day| Date::try_new_iso_date(year, month, day).unwrap();
+
+        // The last Sunday of October
+        let last_sunday = rule(ZoneTransitionRuleKind::MonthWeekDay, 10, 5, 0);
+        assert_eq!(date(last_sunday, 2022), iso(2022, 10, 30));
+        assert_eq!(date(last_sunday, 2023), iso(2023, 10, 29));
+        // J60 is always March 1
+        let julian = rule(ZoneTransitionRuleKind::JulianDay, 0, 0, 60);
+        assert_eq!(date(julian, 2023), iso(2023, 3, 1));
+        assert_eq!(date(julian, 2024), iso(2024, 3, 1));
+        // 59 is March 1 in common years and February 29 in leap years
+        let day_of_year = rule(ZoneTransitionRuleKind::DayOfYear, 0, 0, 59);
+        assert_eq!(date(day_of_year, 2023), iso(2023, 3, 1));
+        assert_eq!(date(day_of_year, 2024), iso(2024, 2, 29));
+    }
+}
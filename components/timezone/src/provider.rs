@@ -19,7 +19,7 @@ use core::str::FromStr;
 use icu_provider::{yoke, zerofrom};
 use tinystr::TinyAsciiStr;
 use zerovec::ule::{AsULE, ULE};
-use zerovec::{ZeroMap2d, ZeroSlice, ZeroVec};
+use zerovec::{ZeroMap, ZeroMap2d, ZeroSlice, ZeroVec};
 
 /// TimeZone ID in BCP47 format
 ///
@@ -149,3 +149,210 @@ pub struct MetazonePeriodV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub ZeroMap2d<'data, TimeZoneBcp47Id, i32, Option<MetazoneId>>,
 );
+
+/// An ICU4X mapping from IANA time zone identifiers to BCP-47 time zone identifiers.
+/// See CLDR-JSON bcp47/timezone.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(IanaToBcp47MapV1Marker = "time_zone/iana_to_bcp47@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct IanaToBcp47MapV1<'data> {
+    /// A map from ASCII-lowercased IANA time zone identifiers, including aliases, to
+    /// BCP-47 time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub map: ZeroMap<'data, str, TimeZoneBcp47Id>,
+}
+
+/// An ICU4X mapping from BCP-47 time zone identifiers to canonical IANA time zone identifiers.
+/// See CLDR-JSON bcp47/timezone.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(Bcp47ToIanaMapV1Marker = "time_zone/bcp47_to_iana@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct Bcp47ToIanaMapV1<'data> {
+    /// A map from BCP-47 time zone identifiers to their canonical IANA time zone identifier.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub map: ZeroMap<'data, TimeZoneBcp47Id, str>,
+}
+
+/// An ICU4X mapping between Windows time zone identifiers and BCP-47 time zone identifiers.
+/// See CLDR-JSON supplemental/windowsZones.json for more context.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(WindowsZonesMapV1Marker = "time_zone/windows_zones@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct WindowsZonesMapV1<'data> {
+    /// A map from Windows time zone identifiers and territories to BCP-47 time zone identifiers.
+    /// The territory `"001"` holds the default mapping for each Windows time zone.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub windows_to_bcp47: ZeroMap2d<'data, str, TinyAsciiStr<3>, TimeZoneBcp47Id>,
+    /// A map from BCP-47 time zone identifiers to Windows time zone identifiers.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub bcp47_to_windows: ZeroMap<'data, TimeZoneBcp47Id, str>,
+}
+
+/// The UTC offset and zone variant in effect in a time zone from a transition onwards.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneOffsetULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneOffset {
+    /// The offset from UTC in seconds.
+    pub offset_seconds: i32,
+    /// Whether this offset is daylight saving time.
+    pub is_dst: bool,
+}
+
+/// The form of the day in a [`ZoneTransitionRule`], following POSIX TZ strings.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneTransitionRuleKindULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+pub enum ZoneTransitionRuleKind {
+    /// The `d`th day of week `w` of month `m`, where week 5 means the last such day (`Mm.w.d`).
+    MonthWeekDay = 0,
+    /// The Julian day `n` in `[1, 365]`, ignoring February 29 (`Jn`).
+    JulianDay = 1,
+    /// The zero-based day of the year `n` in `[0, 365]`, counting February 29 (`n`).
+    DayOfYear = 2,
+}
+
+/// A yearly recurring transition between standard and daylight saving time.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneTransitionRuleULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneTransitionRule {
+    /// How `month`, `week` and `day` are to be interpreted.
+    pub kind: ZoneTransitionRuleKind,
+    /// The month in `[1, 12]`, for [`ZoneTransitionRuleKind::MonthWeekDay`].
+    pub month: u8,
+    /// The week in `[1, 5]`, for [`ZoneTransitionRuleKind::MonthWeekDay`].
+    pub week: u8,
+    /// The day of the week in `[0, 6]` starting on Sunday for
+    /// [`ZoneTransitionRuleKind::MonthWeekDay`], and the day of the year otherwise.
+    pub day: u16,
+    /// The local time of the transition in seconds since midnight. May be negative or
+    /// exceed 24 hours.
+    pub time_seconds: i32,
+}
+
+/// A yearly recurring rule for daylight saving time, in effect after the last transition of a
+/// time zone.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneDstRuleULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneDstRule {
+    /// The standard offset from UTC in seconds.
+    pub std_offset_seconds: i32,
+    /// The daylight saving offset from UTC in seconds.
+    pub dst_offset_seconds: i32,
+    /// The start of daylight saving time, in local standard time.
+    pub start: ZoneTransitionRule,
+    /// The end of daylight saving time, in local daylight saving time.
+    pub end: ZoneTransitionRule,
+}
+
+/// An ICU4X mapping from time zones to their UTC offset transitions, compiled from the
+/// IANA time zone database.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(ZoneOffsetTransitionsV1Marker = "time_zone/offset_transitions@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_timezone::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct ZoneOffsetTransitionsV1<'data> {
+    /// The offset in effect from each transition onwards. The second level key is the time of the
+    /// transition in seconds since the Unix epoch in UTC. The first transition of each time zone is
+    /// at [`i64::MIN`], holding the offset in effect before any recorded transition.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transitions: ZeroMap2d<'data, TimeZoneBcp47Id, i64, ZoneOffset>,
+    /// The rule in effect after the last transition, for time zones that observe daylight saving
+    /// time. Time zones without a rule keep the offset of their last transition.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap<'data, TimeZoneBcp47Id, ZoneDstRule>,
+}
@@ -5,6 +5,7 @@
 use crate::provider::{MetazoneId, TimeZoneBcp47Id};
 
 use crate::metazone::MetazoneCalculator;
+use crate::offsets::ZoneOffsetCalculator;
 use crate::{GmtOffset, TimeZoneError, ZoneVariant};
 use core::str::FromStr;
 use icu_calendar::{DateTime, Iso};
@@ -152,6 +153,43 @@ impl CustomTimeZone {
         }
         self
     }
+
+    /// Overwrite the GMT offset and zone variant with the ones in effect at a UTC datetime.
+    ///
+    /// Both are left unchanged if the time zone id is not set or has no offset data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::timezone::provider::TimeZoneBcp47Id;
+    /// use icu::timezone::{CustomTimeZone, GmtOffset, ZoneOffsetCalculator, ZoneVariant};
+    /// use icu_calendar::DateTime;
+    /// use tinystr::tinystr;
+    ///
+    /// let zoc = ZoneOffsetCalculator::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("data exists");
+    /// let mut tz = CustomTimeZone::new_empty();
+    /// tz.time_zone_id = Some(TimeZoneBcp47Id(tinystr!(8, "gblon")));
+    /// tz.maybe_calculate_offsets(
+    ///     &zoc,
+    ///     &DateTime::try_new_iso_datetime(2022, 7, 1, 12, 0, 0).unwrap(),
+    /// );
+    /// assert_eq!(tz.gmt_offset.map(GmtOffset::offset_seconds), Some(3600));
+    /// assert_eq!(tz.zone_variant, Some(ZoneVariant::daylight()));
+    /// ```
+    pub fn maybe_calculate_offsets(
+        &mut self,
+        zone_offset_calculator: &ZoneOffsetCalculator,
+        utc_datetime: &DateTime<Iso>,
+    ) -> &mut Self {
+        if let Some((gmt_offset, zone_variant)) = self.time_zone_id.and_then(|time_zone_id| {
+            zone_offset_calculator.compute_offsets_from_time_zone(time_zone_id, utc_datetime)
+        }) {
+            self.gmt_offset = Some(gmt_offset);
+            self.zone_variant = Some(zone_variant);
+        }
+        self
+    }
 }
 
 impl FromStr for CustomTimeZone {
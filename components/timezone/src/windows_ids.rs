@@ -0,0 +1,139 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::TimeZoneError;
+use crate::iana_ids::TimeZoneIdMapper;
+use crate::provider::{
+    Bcp47ToIanaMapV1Marker, IanaToBcp47MapV1Marker, TimeZoneBcp47Id, WindowsZonesMapV1Marker,
+};
+use icu_locid::subtags::Region;
+use icu_provider::prelude::*;
+use tinystr::{tinystr, TinyAsciiStr};
+
+/// The territory holding the default mapping for each Windows time zone.
+const WORLD: TinyAsciiStr<3> = tinystr!(3, "001");
+
+/// [`WindowsTimeZoneMapper`] uses data from the [data provider] to convert between Windows time
+/// zone identifiers, such as `"Central Standard Time"`, and BCP-47 or IANA time zone identifiers.
+///
+/// A Windows time zone covers several IANA time zones, so converting from a Windows identifier
+/// takes an optional territory into account. Without a territory, or if the territory has no
+/// specific mapping, the default (`"001"`) mapping for the Windows time zone is used.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::subtags_region as region;
+/// use icu::timezone::provider::TimeZoneBcp47Id;
+/// use icu::timezone::WindowsTimeZoneMapper;
+/// use tinystr::tinystr;
+///
+/// let mapper = WindowsTimeZoneMapper::try_new_unstable(&icu_testdata::unstable())
+///     .expect("data exists");
+///
+/// // Default mapping and territory-specific mapping:
+/// assert_eq!(
+///     mapper.windows_to_bcp47("Eastern Standard Time", None),
+///     Some(TimeZoneBcp47Id(tinystr!(8, "usnyc")))
+/// );
+/// assert_eq!(
+///     mapper.windows_to_iana("Eastern Standard Time", Some(region!("CA"))),
+///     Some("America/Toronto")
+/// );
+///
+/// // Territories without a specific mapping fall back to the default:
+/// assert_eq!(
+///     mapper.windows_to_iana("Eastern Standard Time", Some(region!("FR"))),
+///     Some("America/New_York")
+/// );
+///
+/// // And back:
+/// assert_eq!(
+///     mapper.bcp47_to_windows(TimeZoneBcp47Id(tinystr!(8, "cator"))),
+///     Some("Eastern Standard Time")
+/// );
+/// assert_eq!(
+///     mapper.iana_to_windows("US/Eastern"),
+///     Some("Eastern Standard Time")
+/// );
+/// ```
+///
+/// [data provider]: icu_provider
+pub struct WindowsTimeZoneMapper {
+    windows_zones: DataPayload<WindowsZonesMapV1Marker>,
+    id_mapper: TimeZoneIdMapper,
+}
+
+impl WindowsTimeZoneMapper {
+    /// Constructor that loads data before converting time zone identifiers.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<P>(provider: &P) -> Result<Self, TimeZoneError>
+    where
+        P: DataProvider<WindowsZonesMapV1Marker>
+            + DataProvider<IanaToBcp47MapV1Marker>
+            + DataProvider<Bcp47ToIanaMapV1Marker>
+            + ?Sized,
+    {
+        let windows_zones = DataProvider::<WindowsZonesMapV1Marker>::load(
+            provider,
+            DataRequest {
+                locale: Default::default(),
+                metadata: Default::default(),
+            },
+        )?
+        .take_payload()?;
+        let id_mapper = TimeZoneIdMapper::try_new_unstable(provider)?;
+        Ok(Self {
+            windows_zones,
+            id_mapper,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: TimeZoneError);
+
+    /// Returns the BCP-47 time zone identifier for a Windows time zone identifier in the given
+    /// territory.
+    ///
+    /// Falls back to the default mapping if `region` is `None` or has no specific mapping.
+    /// Returns `None` if the Windows identifier is not known.
+    pub fn windows_to_bcp47(
+        &self,
+        windows_id: &str,
+        region: Option<Region>,
+    ) -> Option<TimeZoneBcp47Id> {
+        let cursor = self.windows_zones.get().windows_to_bcp47.get0(windows_id)?;
+        region
+            .and_then(|region| cursor.get1_copied(&region.into()))
+            .or_else(|| cursor.get1_copied(&WORLD))
+    }
+
+    /// Returns the canonical IANA time zone identifier for a Windows time zone identifier in the
+    /// given territory.
+    ///
+    /// See [`Self::windows_to_bcp47`] for how the territory is taken into account.
+    pub fn windows_to_iana(&self, windows_id: &str, region: Option<Region>) -> Option<&str> {
+        self.windows_to_bcp47(windows_id, region)
+            .and_then(|bcp47_id| self.id_mapper.bcp47_to_iana(bcp47_id))
+    }
+
+    /// Returns the Windows time zone identifier for a BCP-47 time zone identifier.
+    ///
+    /// Returns `None` if the time zone has no Windows equivalent.
+    pub fn bcp47_to_windows(&self, bcp47_id: TimeZoneBcp47Id) -> Option<&str> {
+        self.windows_zones.get().bcp47_to_windows.get(&bcp47_id)
+    }
+
+    /// Returns the Windows time zone identifier for an IANA time zone identifier.
+    ///
+    /// The IANA identifier is resolved as in [`TimeZoneIdMapper::iana_to_bcp47`].
+    pub fn iana_to_windows(&self, iana_id: &str) -> Option<&str> {
+        self.id_mapper
+            .iana_to_bcp47(iana_id)
+            .and_then(|bcp47_id| self.bcp47_to_windows(bcp47_id))
+    }
+}
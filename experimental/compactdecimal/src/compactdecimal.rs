@@ -5,16 +5,14 @@
 use alloc::borrow::Cow;
 use core::convert::TryFrom;
 use fixed_decimal::{CompactDecimal, FixedDecimal};
-use icu_decimal::{
-    options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    FixedDecimalFormatter,
-};
+use icu_decimal::{options::FixedDecimalFormatterOptions, FixedDecimalFormatter};
 use icu_plurals::PluralRules;
 use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
 use zerovec::maps::ZeroMap2dCursor;
 
 use crate::{
     format::FormattedCompactDecimal,
+    options::CompactDecimalFormatterOptions,
     provider::{
         Count, ErasedCompactDecimalFormatDataV1Marker, LongCompactDecimalFormatDataV1Marker,
         PatternULE, ShortCompactDecimalFormatDataV1Marker,
@@ -34,6 +32,7 @@ use crate::{
 /// let short_french = CompactDecimalFormatter::try_new_short_unstable(
 ///    &icu_testdata::unstable(),
 ///    &locale!("fr").into(),
+///    Default::default(),
 /// ).unwrap();
 ///
 /// let [long_french, long_japanese, long_bangla] = [locale!("fr"), locale!("ja"), locale!("bn")]
@@ -41,6 +40,7 @@ use crate::{
 ///         CompactDecimalFormatter::try_new_long_unstable(
 ///             &icu_testdata::unstable(),
 ///             &locale.into(),
+///             Default::default(),
 ///         )
 ///         .unwrap()
 ///     });
@@ -58,6 +58,7 @@ use crate::{
 /// assert_writeable_eq!(long_french.format_i64(1000), "mille");
 /// ```
 pub struct CompactDecimalFormatter {
+    pub(crate) options: CompactDecimalFormatterOptions,
     pub(crate) plural_rules: PluralRules,
     pub(crate) fixed_decimal_format: FixedDecimalFormatter,
     pub(crate) compact_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
@@ -83,6 +84,7 @@ impl CompactDecimalFormatter {
     /// CompactDecimalFormatter::try_new_short_unstable(
     ///     &icu_testdata::unstable(),
     ///     &locale!("sv").into(),
+    ///     Default::default(),
     /// );
     /// ```
     ///
@@ -90,6 +92,7 @@ impl CompactDecimalFormatter {
     pub fn try_new_short_unstable<D>(
         data_provider: &D,
         locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
     ) -> Result<Self, CompactDecimalError>
     where
         D: DataProvider<ShortCompactDecimalFormatDataV1Marker>
@@ -97,14 +100,9 @@ impl CompactDecimalFormatter {
             + DataProvider<icu_plurals::provider::CardinalV1Marker>
             + ?Sized,
     {
-        let mut options = FixedDecimalFormatterOptions::default();
-        options.grouping_strategy = GroupingStrategy::Min2;
         Ok(Self {
-            fixed_decimal_format: FixedDecimalFormatter::try_new_unstable(
-                data_provider,
-                locale,
-                options,
-            )?,
+            fixed_decimal_format: Self::fixed_decimal_formatter(data_provider, locale, &options)?,
+            options,
             plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
             compact_data: DataProvider::<ShortCompactDecimalFormatDataV1Marker>::load(
                 data_provider,
@@ -120,7 +118,7 @@ impl CompactDecimalFormatter {
 
     icu_provider::gen_any_buffer_constructors!(
         locale: include,
-        options: skip,
+        options: CompactDecimalFormatterOptions,
         error: CompactDecimalError,
         functions: [
             Self::try_new_short_unstable,
@@ -148,6 +146,7 @@ impl CompactDecimalFormatter {
     /// CompactDecimalFormatter::try_new_long_unstable(
     ///     &icu_testdata::unstable(),
     ///     &locale!("sv").into(),
+    ///     Default::default(),
     /// );
     /// ```
     ///
@@ -155,6 +154,7 @@ impl CompactDecimalFormatter {
     pub fn try_new_long_unstable<D>(
         data_provider: &D,
         locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
     ) -> Result<Self, CompactDecimalError>
     where
         D: DataProvider<LongCompactDecimalFormatDataV1Marker>
@@ -162,14 +162,9 @@ impl CompactDecimalFormatter {
             + DataProvider<icu_plurals::provider::CardinalV1Marker>
             + ?Sized,
     {
-        let mut options = FixedDecimalFormatterOptions::default();
-        options.grouping_strategy = GroupingStrategy::Min2;
         Ok(Self {
-            fixed_decimal_format: FixedDecimalFormatter::try_new_unstable(
-                data_provider,
-                locale,
-                options,
-            )?,
+            fixed_decimal_format: Self::fixed_decimal_formatter(data_provider, locale, &options)?,
+            options,
             plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
             compact_data: DataProvider::<LongCompactDecimalFormatDataV1Marker>::load(
                 data_provider,
@@ -185,7 +180,7 @@ impl CompactDecimalFormatter {
 
     icu_provider::gen_any_buffer_constructors!(
         locale: include,
-        options: skip,
+        options: CompactDecimalFormatterOptions,
         error: CompactDecimalError,
         functions: [
             Self::try_new_long_unstable,
@@ -194,12 +189,12 @@ impl CompactDecimalFormatter {
         ]
     );
 
-    /// Formats an integer in compact decimal notation using the default
-    /// precision settings.
+    /// Formats an integer in compact decimal notation, rounding its
+    /// significand according to the options of this formatter.
     ///
-    /// The result may have a fractional digit only if it is compact and its
-    /// significand is less than 10. Trailing fractional 0s are omitted, and
-    /// a sign is shown only for negative values.
+    /// With the default options, the result may have a fractional digit only
+    /// if it is compact and its significand is less than 10. Trailing
+    /// fractional 0s are omitted, and a sign is shown only for negative values.
     /// ```
     /// # use icu_compactdecimal::CompactDecimalFormatter;
     /// # use icu_locid::locale;
@@ -207,7 +202,8 @@ impl CompactDecimalFormatter {
     /// #
     /// # let short_english = CompactDecimalFormatter::try_new_short_unstable(
     /// #    &icu_testdata::unstable(),
-    /// #    &locale!("en").into()
+    /// #    &locale!("en").into(),
+    /// #    Default::default(),
     /// # ).unwrap();
     /// assert_writeable_eq!(short_english.format_i64(0), "0");
     /// assert_writeable_eq!(short_english.format_i64(2), "2");
@@ -227,6 +223,7 @@ impl CompactDecimalFormatter {
     /// # let short_english = CompactDecimalFormatter::try_new_short_unstable(
     /// #    &icu_testdata::unstable(),
     /// #    &locale!("en").into(),
+    /// #    Default::default(),
     /// # ).unwrap();
     /// assert_writeable_eq!(short_english.format_i64(999_499), "999K");
     /// assert_writeable_eq!(short_english.format_i64(999_500), "1M");
@@ -235,20 +232,75 @@ impl CompactDecimalFormatter {
     /// assert_writeable_eq!(short_english.format_i64(1950), "2K");
     /// assert_writeable_eq!(short_english.format_i64(-1_172_700), "-1.2M");
     /// ```
+    /// The plural form of the pattern is that of the significand, together
+    /// with the compact exponent as the plural operands `c` and `e`.
+    /// ```
+    /// # use icu_compactdecimal::CompactDecimalFormatter;
+    /// # use icu_locid::locale;
+    /// # use writeable::assert_writeable_eq;
+    /// #
+    /// let long_french = CompactDecimalFormatter::try_new_long_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("fr").into(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    /// assert_writeable_eq!(long_french.format_i64(1_000), "mille");
+    /// assert_writeable_eq!(long_french.format_i64(1_000_000), "1 million");
+    /// assert_writeable_eq!(long_french.format_i64(1_200_000), "1,2 million");
+    /// assert_writeable_eq!(long_french.format_i64(2_000_000), "2 millions");
+    /// ```
     pub fn format_i64(&self, value: i64) -> FormattedCompactDecimal<'_> {
-        let unrounded = FixedDecimal::from(value);
+        self.format_fixed_decimal(FixedDecimal::from(value))
+    }
+
+    /// Formats a [`FixedDecimal`] in compact decimal notation, rounding its
+    /// significand according to the options of this formatter.
+    ///
+    /// See [`Self::format_i64()`] for the default precision settings.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::CompactDecimalFormatter;
+    /// use icu_locid::locale;
+    /// use std::str::FromStr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let short_english = CompactDecimalFormatter::try_new_short_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let value = FixedDecimal::from_str("1234.5678").unwrap();
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(value), "1.2K");
+    /// let value = FixedDecimal::from_str("0.12345").unwrap();
+    /// assert_writeable_eq!(short_english.format_fixed_decimal(value), "0.1");
+    /// ```
+    pub fn format_fixed_decimal(&self, value: FixedDecimal) -> FormattedCompactDecimal<'_> {
+        let (plural_map, compact) = self.compact(value);
+        FormattedCompactDecimal {
+            formatter: self,
+            plural_map,
+            value: Cow::Owned(compact),
+        }
+    }
+
+    /// Rounds `unrounded` to a compact number, and returns it with the patterns for its
+    /// magnitude.
+    pub(crate) fn compact(
+        &self,
+        unrounded: FixedDecimal,
+    ) -> (
+        Option<ZeroMap2dCursor<'_, '_, i8, Count, PatternULE>>,
+        CompactDecimal,
+    ) {
         let log10_type = unrounded.nonzero_magnitude_start();
         let (mut plural_map, mut exponent) = self.plural_map_and_exponent_for_magnitude(log10_type);
         let mut significand = unrounded.multiplied_pow10(-i16::from(exponent));
-        // If we have just one digit before the decimal point…
-        if significand.nonzero_magnitude_start() == 0 {
-            // …round to one fractional digit…
-            significand.half_even(-1);
-        } else {
-            // …otherwise, we have at least 2 digits before the decimal point,
-            // so round to eliminate the fractional part.
-            significand.half_even(0);
-        }
+        self.options.round(&mut significand);
         let rounded_magnitude = significand.nonzero_magnitude_start() + i16::from(exponent);
         if rounded_magnitude > log10_type {
             // We got bumped up a magnitude by rounding.
@@ -264,16 +316,13 @@ impl CompactDecimalFormatter {
             // a power of 10, is as round as it gets, and since `exponent` can
             // only have become larger, it is already the correct rounding of
             // `unrounded` to the precision we want to show.
+            significand.trim_end();
         }
-        significand.trim_end();
-        FormattedCompactDecimal {
-            formatter: self,
+        self.options.pad(&mut significand);
+        (
             plural_map,
-            value: Cow::Owned(CompactDecimal::from_significand_and_exponent(
-                significand,
-                exponent,
-            )),
-        }
+            CompactDecimal::from_significand_and_exponent(significand, exponent),
+        )
     }
 
     /// Formats a [`CompactDecimal`] object according to locale data.
@@ -305,12 +354,14 @@ impl CompactDecimalFormatter {
     /// # let short_french = CompactDecimalFormatter::try_new_short_unstable(
     /// #    &icu_testdata::unstable(),
     /// #    &locale!("fr").into(),
+    /// #    Default::default(),
     /// # ).unwrap();
     /// # let [long_french, long_bangla] = [locale!("fr"), locale!("bn")]
     /// #     .map(|locale| {
     /// #         CompactDecimalFormatter::try_new_long_unstable(
     /// #             &icu_testdata::unstable(),
     /// #             &locale.into(),
+    /// #             Default::default(),
     /// #         )
     /// #         .unwrap()
     /// #     });
@@ -408,6 +459,7 @@ impl CompactDecimalFormatter {
     ///         CompactDecimalFormatter::try_new_long_unstable(
     ///             &icu_testdata::unstable(),
     ///             &locale.into(),
+    ///             Default::default(),
     ///         )
     ///         .unwrap()
     ///     });
@@ -427,20 +479,45 @@ impl CompactDecimalFormatter {
         &self,
         magnitude: i16,
     ) -> (Option<ZeroMap2dCursor<i8, Count, PatternULE>>, u8) {
-        let plural_map = self
-            .compact_data
-            .get()
-            .patterns
-            .iter0()
-            .filter(|cursor| i16::from(*cursor.key0()) <= magnitude)
-            .last();
-        let exponent = plural_map
-            .as_ref()
-            .and_then(|map| {
-                map.get1(&Count::Other)
-                    .and_then(|pattern| u8::try_from(pattern.exponent).ok())
-            })
-            .unwrap_or(0);
-        (plural_map, exponent)
+        plural_map_and_exponent_for_magnitude(&self.compact_data, magnitude)
+    }
+
+    pub(crate) fn fixed_decimal_formatter<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: &CompactDecimalFormatterOptions,
+    ) -> Result<FixedDecimalFormatter, CompactDecimalError>
+    where
+        D: DataProvider<icu_decimal::provider::DecimalSymbolsV1Marker> + ?Sized,
+    {
+        let mut fixed_decimal_options = FixedDecimalFormatterOptions::default();
+        fixed_decimal_options.grouping_strategy = options.grouping_strategy;
+        Ok(FixedDecimalFormatter::try_new_unstable(
+            data_provider,
+            locale,
+            fixed_decimal_options,
+        )?)
     }
 }
+
+/// Returns the patterns for numbers of the given magnitude in `compact_data`, and their
+/// compact decimal exponent.
+pub(crate) fn plural_map_and_exponent_for_magnitude(
+    compact_data: &DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
+    magnitude: i16,
+) -> (Option<ZeroMap2dCursor<i8, Count, PatternULE>>, u8) {
+    let plural_map = compact_data
+        .get()
+        .patterns
+        .iter0()
+        .filter(|cursor| i16::from(*cursor.key0()) <= magnitude)
+        .last();
+    let exponent = plural_map
+        .as_ref()
+        .and_then(|map| {
+            map.get1(&Count::Other)
+                .and_then(|pattern| u8::try_from(pattern.exponent).ok())
+        })
+        .unwrap_or(0);
+    (plural_map, exponent)
+}
@@ -0,0 +1,311 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{CompactDecimal, FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_dimension::currency::CurrencyCode;
+use icu_dimension::provider::{CurrencyEssentialsV1Marker, CURRENCY_PLACEHOLDER};
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use writeable::Writeable;
+use zerovec::maps::ZeroMap2dCursor;
+
+use crate::compactdecimal::plural_map_and_exponent_for_magnitude;
+use crate::format::{select_pattern, split_pattern};
+use crate::options::CompactDecimalFormatterOptions;
+use crate::provider::{
+    Count, ErasedCompactDecimalFormatDataV1Marker, PatternULE,
+    ShortCompactCurrencyFormatDataV1Marker,
+};
+use crate::{CompactDecimalError, CompactDecimalFormatter};
+
+/// A formatter that renders locale-sensitive compact currency amounts, such as "$1.2M".
+///
+/// The significand is rounded as by [`CompactDecimalFormatter`], regardless of the number
+/// of fraction digits of the currency. Currencies without locale data are displayed with
+/// their ISO 4217 code.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_compactdecimal::CompactCurrencyFormatter;
+/// use icu_dimension::currency::CurrencyCode;
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let [en, fr, ja] = [locale!("en"), locale!("fr"), locale!("ja")].map(|locale| {
+///     CompactCurrencyFormatter::try_new_short_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         Default::default(),
+///     )
+///     .expect("Data should load successfully")
+/// });
+///
+/// let usd = CurrencyCode(tinystr!(3, "USD"));
+/// let eur = CurrencyCode(tinystr!(3, "EUR"));
+/// let chf = CurrencyCode(tinystr!(3, "CHF"));
+///
+/// assert_writeable_eq!(en.format_i64(1_234_567, usd), "$1.2M");
+/// assert_writeable_eq!(en.format_i64(-45_600, usd), "-$46K");
+/// assert_writeable_eq!(en.format_i64(843, usd), "$843");
+/// # // The following lines contain U+00A0 NO-BREAK SPACE.
+/// assert_writeable_eq!(en.format_i64(1_234_567, chf), "CHF 1.2M");
+/// assert_writeable_eq!(fr.format_i64(1_234_567, eur), "1,2 M €");
+/// assert_writeable_eq!(ja.format_i64(12_345_678, usd), "$1235万");
+/// ```
+pub struct CompactCurrencyFormatter {
+    /// The formatter for the standard patterns.
+    compact_decimal_formatter: CompactDecimalFormatter,
+    /// The patterns used if the currency symbol is alphanumeric next to the number.
+    alpha_next_to_number_data: DataPayload<ErasedCompactDecimalFormatDataV1Marker>,
+    essentials: DataPayload<CurrencyEssentialsV1Marker>,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
+}
+
+impl CompactCurrencyFormatter {
+    /// Constructor that takes a selected locale, reference to a
+    /// [data provider] and an options bag, then collects all data
+    /// necessary to format currency amounts in short compact notation for
+    /// the given locale.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    ///
+    /// [data provider]: icu_provider
+    pub fn try_new_short_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CompactDecimalFormatterOptions,
+    ) -> Result<Self, CompactDecimalError>
+    where
+        D: DataProvider<ShortCompactCurrencyFormatDataV1Marker>
+            + DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let request = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let patterns: DataPayload<ShortCompactCurrencyFormatDataV1Marker> =
+            data_provider.load(request)?.take_payload()?;
+        let alpha_next_to_number_data = patterns
+            .clone()
+            .map_project(|patterns, _| patterns.alpha_next_to_number.unwrap_or(patterns.standard));
+        Ok(Self {
+            compact_decimal_formatter: CompactDecimalFormatter {
+                fixed_decimal_format: CompactDecimalFormatter::fixed_decimal_formatter(
+                    data_provider,
+                    locale,
+                    &options,
+                )?,
+                options,
+                plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+                compact_data: patterns.map_project(|patterns, _| patterns.standard),
+            },
+            alpha_next_to_number_data,
+            essentials: DataProvider::<CurrencyEssentialsV1Marker>::load(data_provider, request)?
+                .take_payload()?,
+            symbols: DataProvider::<DecimalSymbolsV1Marker>::load(data_provider, request)?
+                .take_payload()?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CompactDecimalFormatterOptions,
+        error: CompactDecimalError,
+        functions: [
+            Self::try_new_short_unstable,
+            try_new_short_with_any_provider,
+            try_new_short_with_buffer_provider
+        ]
+    );
+
+    /// Formats an integer amount of a currency in compact notation.
+    ///
+    /// See [`CompactDecimalFormatter::format_i64()`] for the rounding of the amount.
+    pub fn format_i64(&self, value: i64, currency: CurrencyCode) -> FormattedCompactCurrency<'_> {
+        self.format_fixed_decimal(FixedDecimal::from(value), currency)
+    }
+
+    /// Formats an amount of a currency in compact notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    /// use icu_compactdecimal::options::CompactDecimalFormatterOptions;
+    /// use icu_compactdecimal::CompactCurrencyFormatter;
+    /// use icu_dimension::currency::CurrencyCode;
+    /// use icu_locid::locale;
+    /// use std::str::FromStr;
+    /// use tinystr::tinystr;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let mut options = CompactDecimalFormatterOptions::default();
+    /// options.max_significant_digits = Some(3);
+    /// let fmt = CompactCurrencyFormatter::try_new_short_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     options,
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let amount = FixedDecimal::from_str("1234.56").unwrap();
+    /// let usd = CurrencyCode(tinystr!(3, "USD"));
+    /// assert_writeable_eq!(fmt.format_fixed_decimal(amount, usd), "$1.23K");
+    /// ```
+    pub fn format_fixed_decimal(
+        &self,
+        mut value: FixedDecimal,
+        currency: CurrencyCode,
+    ) -> FormattedCompactCurrency<'_> {
+        let is_negative = value.sign() == Sign::Negative;
+        // The sign is rendered around the currency pattern.
+        value.set_sign(Sign::None);
+        let (plural_map, value) = self.compact_decimal_formatter.compact(value);
+        FormattedCompactCurrency {
+            formatter: self,
+            is_negative: is_negative && !value.significand().is_zero(),
+            value,
+            plural_map,
+            currency,
+        }
+    }
+}
+
+/// An intermediate structure returned by [`CompactCurrencyFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted currency amount to a string or buffer.
+pub struct FormattedCompactCurrency<'l> {
+    formatter: &'l CompactCurrencyFormatter,
+    /// The rounded absolute value of the amount.
+    value: CompactDecimal,
+    is_negative: bool,
+    plural_map: Option<ZeroMap2dCursor<'l, 'l, i8, Count, PatternULE>>,
+    currency: CurrencyCode,
+}
+
+impl<'l> FormattedCompactCurrency<'l> {
+    /// Writes `text`, replacing the currency placeholder with `symbol`.
+    fn write_with_symbol<W>(text: &str, symbol: &str, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        for (i, part) in text.split(CURRENCY_PLACEHOLDER).enumerate() {
+            if i != 0 {
+                sink.write_str(symbol)?;
+            }
+            sink.write_str(part)?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the symbol is alphanumeric on a side that touches the number, given the
+    /// text before and after the number.
+    fn is_alpha_next_to_number(symbol: &str, prefix: &str, suffix: &str) -> bool {
+        (prefix.ends_with(CURRENCY_PLACEHOLDER)
+            && symbol
+                .chars()
+                .next_back()
+                .map_or(false, char::is_alphanumeric))
+            || (suffix.starts_with(CURRENCY_PLACEHOLDER)
+                && symbol.chars().next().map_or(false, char::is_alphanumeric))
+    }
+
+    /// Writes a number without compact notation, using the standard currency pattern.
+    fn write_noncompact<W>(&self, symbol: &str, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let essentials = self.formatter.essentials.get();
+        let standard = &essentials.standard.positive;
+        let pattern = if Self::is_alpha_next_to_number(symbol, &standard.prefix, &standard.suffix) {
+            &essentials.standard_alpha_next_to_number
+        } else {
+            &essentials.standard
+        };
+        let affixes = if self.is_negative {
+            &pattern.negative
+        } else {
+            &pattern.positive
+        };
+        Self::write_with_symbol(&affixes.prefix, symbol, sink)?;
+        self.formatter
+            .compact_decimal_formatter
+            .fixed_decimal_format
+            .format(self.value.significand())
+            .write_to(sink)?;
+        Self::write_with_symbol(&affixes.suffix, symbol, sink)
+    }
+}
+
+impl<'l> Writeable for FormattedCompactCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let currency = &self.currency.0;
+        let symbol = self
+            .formatter
+            .essentials
+            .get()
+            .symbols
+            .get(currency)
+            .unwrap_or_else(|| currency.as_str());
+        if self.value.exponent() == 0 {
+            return self.write_noncompact(symbol, sink);
+        }
+
+        let formatter = &self.formatter.compact_decimal_formatter;
+        let significand = self.value.significand();
+        let plural_map = self.plural_map.as_ref().ok_or(core::fmt::Error)?;
+        let mut pattern = select_pattern(plural_map, &formatter.plural_rules, &self.value)
+            .ok_or(core::fmt::Error)?;
+        if let (prefix, Some(suffix)) = split_pattern(pattern)? {
+            if Self::is_alpha_next_to_number(symbol, prefix, suffix) {
+                let magnitude = significand.nonzero_magnitude_start() + self.value.exponent();
+                let (plural_map, _) = plural_map_and_exponent_for_magnitude(
+                    &self.formatter.alpha_next_to_number_data,
+                    magnitude,
+                );
+                pattern = select_pattern(
+                    plural_map.as_ref().ok_or(core::fmt::Error)?,
+                    &formatter.plural_rules,
+                    &self.value,
+                )
+                .ok_or(core::fmt::Error)?;
+            }
+        }
+
+        let minus_sign_affixes = &self.formatter.symbols.get().minus_sign_affixes;
+        if self.is_negative {
+            sink.write_str(&minus_sign_affixes.prefix)?;
+        }
+        match split_pattern(pattern)? {
+            (literal_text, None) => Self::write_with_symbol(literal_text, symbol, sink)?,
+            (prefix, Some(suffix)) => {
+                Self::write_with_symbol(prefix, symbol, sink)?;
+                formatter
+                    .fixed_decimal_format
+                    .format(significand)
+                    .write_to(sink)?;
+                Self::write_with_symbol(suffix, symbol, sink)?;
+            }
+        }
+        if self.is_negative {
+            sink.write_str(&minus_sign_affixes.suffix)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCompactCurrency<'_>);
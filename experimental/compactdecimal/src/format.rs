@@ -4,6 +4,7 @@
 
 use alloc::borrow::Cow;
 use fixed_decimal::{CompactDecimal, FixedDecimal};
+use icu_plurals::PluralRules;
 use writeable::Writeable;
 use zerovec::maps::ZeroMap2dCursor;
 
@@ -30,45 +31,59 @@ impl<'l> Writeable for FormattedCompactDecimal<'l> {
                 .write_to(sink)
         } else {
             let plural_map = self.plural_map.as_ref().ok_or(core::fmt::Error)?;
-            let chosen_pattern = (|| {
-                if self.value.significand() == &FixedDecimal::from(1) {
-                    if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
-                        return Some(pattern);
-                    }
-                }
-                let plural_category = self
-                    .formatter
-                    .plural_rules
-                    .category_for(self.value.significand());
-                plural_map
-                    .get1(&plural_category.into())
-                    .or_else(|| plural_map.get1(&Count::Other))
-            })()
-            .ok_or(core::fmt::Error)?;
-            match chosen_pattern.index {
-                u8::MAX => sink.write_str(&chosen_pattern.literal_text),
-                _ => {
-                    let i = usize::from(chosen_pattern.index);
-                    sink.write_str(
-                        chosen_pattern
-                            .literal_text
-                            .get(..i)
-                            .ok_or(core::fmt::Error)?,
-                    )?;
+            let chosen_pattern =
+                select_pattern(plural_map, &self.formatter.plural_rules, &self.value)
+                    .ok_or(core::fmt::Error)?;
+            match split_pattern(chosen_pattern)? {
+                (literal_text, None) => sink.write_str(literal_text),
+                (prefix, Some(suffix)) => {
+                    sink.write_str(prefix)?;
                     self.formatter
                         .fixed_decimal_format
                         .format(self.value.significand())
                         .write_to(sink)?;
-                    sink.write_str(
-                        chosen_pattern
-                            .literal_text
-                            .get(i..)
-                            .ok_or(core::fmt::Error)?,
-                    )
+                    sink.write_str(suffix)
                 }
             }
         }
     }
 }
 
+/// Returns the pattern of `plural_map` for a compact number.
+///
+/// The plural category is that of the significand together with the compact exponent, as the
+/// operands `e` and `c`; see [`PluralOperands`](icu_plurals::PluralOperands).
+pub(crate) fn select_pattern<'l>(
+    plural_map: &ZeroMap2dCursor<'l, 'l, i8, Count, PatternULE>,
+    plural_rules: &PluralRules,
+    value: &CompactDecimal,
+) -> Option<&'l PatternULE> {
+    if value.significand() == &FixedDecimal::from(1) {
+        if let Some(pattern) = plural_map.get1(&Count::Explicit1) {
+            return Some(pattern);
+        }
+    }
+    let plural_category = plural_rules.category_for(value);
+    plural_map
+        .get1(&plural_category.into())
+        .or_else(|| plural_map.get1(&Count::Other))
+}
+
+/// Splits the literal text of `pattern` into the text before and after the placeholder, or
+/// returns the whole text if the pattern has no placeholder.
+pub(crate) fn split_pattern(
+    pattern: &PatternULE,
+) -> Result<(&str, Option<&str>), core::fmt::Error> {
+    match pattern.index {
+        u8::MAX => Ok((&pattern.literal_text, None)),
+        index => {
+            let i = usize::from(index);
+            Ok((
+                pattern.literal_text.get(..i).ok_or(core::fmt::Error)?,
+                Some(pattern.literal_text.get(i..).ok_or(core::fmt::Error)?),
+            ))
+        }
+    }
+}
+
 writeable::impl_display_with_writeable!(FormattedCompactDecimal<'_>);
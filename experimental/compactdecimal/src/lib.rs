@@ -32,9 +32,13 @@
 extern crate alloc;
 
 mod compactdecimal;
+mod currency;
 mod error;
 mod format;
+pub mod options;
 pub mod provider;
 
 pub use compactdecimal::CompactDecimalFormatter;
+pub use currency::{CompactCurrencyFormatter, FormattedCompactCurrency};
 pub use error::CompactDecimalError;
+pub use format::FormattedCompactDecimal;
@@ -0,0 +1,133 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`CompactDecimalFormatter`](crate::CompactDecimalFormatter).
+
+use fixed_decimal::FixedDecimal;
+
+pub use icu_decimal::options::{GroupingStrategy, RoundingMode};
+
+/// A bag of options defining how numbers will be formatted by
+/// [`CompactDecimalFormatter`](crate::CompactDecimalFormatter) and
+/// [`CompactCurrencyFormatter`](crate::CompactCurrencyFormatter).
+///
+/// By default, the significand is rounded to one fractional digit if it has a single integer
+/// digit, and to an integer otherwise, and trailing fractional zeros are omitted. The
+/// digit-count options apply to the significand instead, in the manner of
+/// `Intl.NumberFormat`: if either significant-digit option is set, the fraction-digit options
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use icu_compactdecimal::options::{CompactDecimalFormatterOptions, RoundingMode};
+/// use icu_compactdecimal::CompactDecimalFormatter;
+/// use icu_locid::locale;
+///
+/// let format = |options, value| {
+///     CompactDecimalFormatter::try_new_short_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+///     .format_i64(value)
+///     .to_string()
+/// };
+///
+/// let options = CompactDecimalFormatterOptions::default();
+/// assert_eq!(format(options.clone(), 1234), "1.2K");
+///
+/// let mut options = CompactDecimalFormatterOptions::default();
+/// options.max_significant_digits = Some(3);
+/// assert_eq!(format(options.clone(), 1234), "1.23K");
+/// assert_eq!(format(options.clone(), 1200), "1.2K");
+/// assert_eq!(format(options.clone(), 999_999), "1M");
+///
+/// options.min_significant_digits = Some(3);
+/// assert_eq!(format(options.clone(), 1200), "1.20K");
+/// assert_eq!(format(options, 999_999), "1.00M");
+///
+/// let mut options = CompactDecimalFormatterOptions::default();
+/// options.max_fraction_digits = Some(2);
+/// options.rounding_mode = RoundingMode::Trunc;
+/// assert_eq!(format(options, 15_678), "15.67K");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct CompactDecimalFormatterOptions {
+    /// When to render grouping separators in the significand.
+    ///
+    /// Defaults to [`GroupingStrategy::Min2`].
+    pub grouping_strategy: GroupingStrategy,
+
+    /// The minimum number of digits after the decimal separator of the significand, padded
+    /// with zeros.
+    pub min_fraction_digits: Option<u8>,
+
+    /// The maximum number of digits after the decimal separator of the significand, rounded.
+    pub max_fraction_digits: Option<u8>,
+
+    /// The minimum number of significant digits of the significand, padded with zeros.
+    pub min_significant_digits: Option<u8>,
+
+    /// The maximum number of significant digits of the significand, rounded.
+    pub max_significant_digits: Option<u8>,
+
+    /// How to round the significand.
+    ///
+    /// Defaults to [`RoundingMode::HalfEven`].
+    pub rounding_mode: RoundingMode,
+}
+
+impl Default for CompactDecimalFormatterOptions {
+    fn default() -> Self {
+        Self {
+            grouping_strategy: GroupingStrategy::Min2,
+            min_fraction_digits: None,
+            max_fraction_digits: None,
+            min_significant_digits: None,
+            max_significant_digits: None,
+            rounding_mode: RoundingMode::HalfEven,
+        }
+    }
+}
+
+impl CompactDecimalFormatterOptions {
+    fn has_significant_digits(&self) -> bool {
+        self.min_significant_digits.is_some() || self.max_significant_digits.is_some()
+    }
+
+    /// Rounds `significand` to the maximum number of digits allowed, and removes trailing
+    /// fractional zeros.
+    pub(crate) fn round(&self, significand: &mut FixedDecimal) {
+        if self.has_significant_digits() {
+            if let Some(max) = self.max_significant_digits {
+                let position = significand.nonzero_magnitude_start() - i16::from(max) + 1;
+                significand.round_with_mode(position, self.rounding_mode);
+            }
+        } else if let Some(max) = self.max_fraction_digits {
+            significand.round_with_mode(-i16::from(max), self.rounding_mode);
+        } else if significand.nonzero_magnitude_start() <= 0 {
+            // With one digit before the decimal point, round to one fractional digit…
+            significand.round_with_mode(-1, self.rounding_mode);
+        } else {
+            // …otherwise, we have at least 2 digits before the decimal point, so round to
+            // eliminate the fractional part.
+            significand.round_with_mode(0, self.rounding_mode);
+        }
+        significand.trim_end();
+    }
+
+    /// Pads `significand` with trailing zeros to the minimum number of digits.
+    pub(crate) fn pad(&self, significand: &mut FixedDecimal) {
+        if self.has_significant_digits() {
+            if let Some(min) = self.min_significant_digits {
+                significand.pad_end(significand.nonzero_magnitude_start() - i16::from(min) + 1);
+            }
+        } else if let Some(min) = self.min_fraction_digits {
+            significand.pad_end(-i16::from(min));
+        }
+    }
+}
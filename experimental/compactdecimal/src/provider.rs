@@ -59,6 +59,34 @@ pub struct CompactDecimalPatternDataV1<'data> {
     pub patterns: ZeroMap2d<'data, i8, Count, PatternULE>,
 }
 
+/// Compact currency format V1 data struct.
+///
+/// The patterns are stored as in [`CompactDecimalPatternDataV1`], with the currency
+/// placeholder `¤` kept in their literal text; thus the CLDR pattern `¤0K` is stored as the
+/// literal text `¤K` with the placeholder at index 2. Types for which the locale does not use
+/// compact notation are formatted with the standard currency pattern of the locale.
+#[icu_provider::data_struct(
+    ShortCompactCurrencyFormatDataV1Marker = "compactdecimal/short_currency@1"
+)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_compactdecimal::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CompactCurrencyPatternDataV1<'data> {
+    /// The CLDR `standard` patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard: CompactDecimalPatternDataV1<'data>,
+    /// The CLDR `standard-alt-alphaNextToNumber` patterns, which are used instead of
+    /// `standard` if the currency symbol is alphanumeric where it touches the number, or
+    /// `None` if the locale has no such patterns.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub alpha_next_to_number: Option<CompactDecimalPatternDataV1<'data>>,
+}
+
 /// A CLDR plural keyword, or the explicit value 1.
 /// See <https://www.unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules>.
 #[zerovec::make_ule(CountULE)]
@@ -140,6 +168,7 @@ pub struct Pattern<'data> {
     /// " M" for the pattern "000 M"
     pub literal_text: Cow<'data, str>,
 }
+
 pub(crate) struct ErasedCompactDecimalFormatDataV1Marker;
 
 impl DataMarker for ErasedCompactDecimalFormatDataV1Marker {
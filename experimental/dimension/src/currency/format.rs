@@ -0,0 +1,155 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use writeable::Writeable;
+
+use super::options::{CurrencyDisplay, CurrencySign};
+use super::{CurrencyCode, CurrencyFormatter};
+use crate::provider::{Count, CurrencyPatternV1, CURRENCY_PLACEHOLDER};
+
+/// An intermediate structure returned by [`CurrencyFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted currency amount to a string or buffer.
+pub struct FormattedCurrency<'l> {
+    pub(crate) formatter: &'l CurrencyFormatter,
+    /// The rounded amount, without its sign unless it is displayed with
+    /// [`CurrencyDisplay::Name`].
+    pub(crate) value: FixedDecimal,
+    pub(crate) is_negative: bool,
+    pub(crate) currency: CurrencyCode,
+}
+
+impl<'l> FormattedCurrency<'l> {
+    /// Writes the amount using the display name of the currency and the unit pattern
+    /// for its plural category.
+    fn write_with_name<W>(&self, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let (names, plural_rules) = self.formatter.names.as_ref().ok_or(core::fmt::Error)?;
+        let names = names.get();
+        let count = Count::from(plural_rules.category_for(&self.value));
+        let pattern = names
+            .unit_patterns
+            .get(&count)
+            .or_else(|| names.unit_patterns.get(&Count::Other))
+            .ok_or(core::fmt::Error)?;
+        let currency = &self.currency.0;
+        let name = names
+            .names
+            .get0(currency)
+            .and_then(|cursor| cursor.get1(&count).or_else(|| cursor.get1(&Count::Other)))
+            .unwrap_or_else(|| currency.as_str());
+
+        let mut rest = pattern;
+        while let Some(i) = rest.find('{') {
+            sink.write_str(rest.get(..i).ok_or(core::fmt::Error)?)?;
+            rest = rest.get(i..).ok_or(core::fmt::Error)?;
+            if let Some(r) = rest.strip_prefix("{0}") {
+                self.formatter
+                    .fixed_decimal_formatter
+                    .format(&self.value)
+                    .write_to(sink)?;
+                rest = r;
+            } else if let Some(r) = rest.strip_prefix("{1}") {
+                sink.write_str(name)?;
+                rest = r;
+            } else {
+                sink.write_char('{')?;
+                rest = rest.get(1..).ok_or(core::fmt::Error)?;
+            }
+        }
+        sink.write_str(rest)
+    }
+
+    /// Writes an affix, replacing the currency placeholder with `symbol`.
+    fn write_affix<W>(affix: &str, symbol: &str, sink: &mut W) -> core::fmt::Result
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        for (i, part) in affix.split(CURRENCY_PLACEHOLDER).enumerate() {
+            if i != 0 {
+                sink.write_str(symbol)?;
+            }
+            sink.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'l> Writeable for FormattedCurrency<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let options = &self.formatter.options;
+        let essentials = self.formatter.essentials.get();
+        let currency = &self.currency.0;
+        let symbol = match options.display {
+            CurrencyDisplay::Name => return self.write_with_name(sink),
+            CurrencyDisplay::IsoCode => currency.as_str(),
+            CurrencyDisplay::NarrowSymbol => essentials
+                .narrow_symbols
+                .get(currency)
+                .or_else(|| essentials.symbols.get(currency))
+                .unwrap_or_else(|| currency.as_str()),
+            CurrencyDisplay::Symbol => essentials
+                .symbols
+                .get(currency)
+                .unwrap_or_else(|| currency.as_str()),
+        };
+
+        let (pattern, alpha_next_to_number) = match options.sign {
+            CurrencySign::Accounting => (
+                &essentials.accounting,
+                &essentials.accounting_alpha_next_to_number,
+            ),
+            CurrencySign::Standard => (
+                &essentials.standard,
+                &essentials.standard_alpha_next_to_number,
+            ),
+        };
+        // Whether the symbol is alphanumeric on the side that touches the number in the
+        // standard pattern, as in "CHF12.00".
+        let is_alpha_next_to_number = if essentials
+            .standard
+            .positive
+            .prefix
+            .ends_with(CURRENCY_PLACEHOLDER)
+        {
+            symbol
+                .chars()
+                .next_back()
+                .map_or(false, char::is_alphanumeric)
+        } else if essentials
+            .standard
+            .positive
+            .suffix
+            .starts_with(CURRENCY_PLACEHOLDER)
+        {
+            symbol.chars().next().map_or(false, char::is_alphanumeric)
+        } else {
+            false
+        };
+        let pattern: &CurrencyPatternV1 = if is_alpha_next_to_number {
+            alpha_next_to_number
+        } else {
+            pattern
+        };
+        let affixes = if self.is_negative {
+            &pattern.negative
+        } else {
+            &pattern.positive
+        };
+
+        Self::write_affix(&affixes.prefix, symbol, sink)?;
+        self.formatter
+            .fixed_decimal_formatter
+            .format(&self.value)
+            .write_to(sink)?;
+        Self::write_affix(&affixes.suffix, symbol, sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedCurrency<'_>);
@@ -0,0 +1,254 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of currency amounts, such as "$1,234.50" or "1.234,50 €".
+
+mod format;
+pub mod options;
+
+pub use format::FormattedCurrency;
+pub use options::CurrencyFormatterOptions;
+
+use crate::provider::{
+    CurrencyEssentialsV1Marker, CurrencyFractionsV1Marker, CurrencyNamesV1Marker, FractionInfo,
+};
+use crate::DimensionError;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use options::{CurrencyDisplay, CurrencyRounding};
+use tinystr::TinyAsciiStr;
+
+/// An ISO 4217 currency code, such as "USD".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct CurrencyCode(pub TinyAsciiStr<3>);
+
+/// A formatter that renders locale-sensitive currency amounts.
+///
+/// Amounts are rounded to the number of fraction digits of the currency, and formatted
+/// with the locale's currency patterns and symbols.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use std::str::FromStr;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let [en, fr, ja] = [locale!("en"), locale!("fr"), locale!("ja")].map(|locale| {
+///     CurrencyFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         Default::default(),
+///     )
+///     .expect("Data should load successfully")
+/// });
+///
+/// let amount = FixedDecimal::from_str("-1234.5").unwrap();
+/// let eur = CurrencyCode(tinystr!(3, "EUR"));
+/// let jpy = CurrencyCode(tinystr!(3, "JPY"));
+///
+/// assert_writeable_eq!(en.format(&amount, eur), "-€1,234.50");
+/// # // The following line contains U+00A0 NO-BREAK SPACE and U+202F NARROW NO-BREAK SPACE.
+/// assert_writeable_eq!(fr.format(&amount, eur), "-1 234,50 €");
+///
+/// /// The number of fraction digits depends on the currency:
+/// assert_writeable_eq!(en.format(&amount, jpy), "-¥1,234");
+/// assert_writeable_eq!(ja.format(&amount, jpy), "-￥1,234");
+/// ```
+pub struct CurrencyFormatter {
+    options: CurrencyFormatterOptions,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    essentials: DataPayload<CurrencyEssentialsV1Marker>,
+    fractions: DataPayload<CurrencyFractionsV1Marker>,
+    /// The data for [`CurrencyDisplay::Name`], which is only loaded for that option.
+    names: Option<(DataPayload<CurrencyNamesV1Marker>, PluralRules)>,
+}
+
+impl CurrencyFormatter {
+    /// Creates a new [`CurrencyFormatter`] from locale data and an options bag.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<CurrencyEssentialsV1Marker>
+            + DataProvider<CurrencyFractionsV1Marker>
+            + DataProvider<CurrencyNamesV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let request = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let names = if options.display == CurrencyDisplay::Name {
+            Some((
+                DataProvider::<CurrencyNamesV1Marker>::load(data_provider, request)?
+                    .take_payload()?,
+                PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+            ))
+        } else {
+            None
+        };
+        Ok(Self {
+            options,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                Default::default(),
+            )?,
+            essentials: DataProvider::<CurrencyEssentialsV1Marker>::load(data_provider, request)?
+                .take_payload()?,
+            fractions: DataProvider::<CurrencyFractionsV1Marker>::load(
+                data_provider,
+                Default::default(),
+            )?
+            .take_payload()?,
+            names,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: CurrencyFormatterOptions,
+        error: DimensionError
+    );
+
+    /// Formats an amount of a currency, rounding it according to the currency's fraction
+    /// digits.
+    ///
+    /// Currencies without locale data are displayed with their ISO 4217 code.
+    pub fn format(&self, value: &FixedDecimal, currency: CurrencyCode) -> FormattedCurrency<'_> {
+        let fraction_info = self.fraction_info(currency);
+        let (digits, increment) = match self.options.rounding {
+            CurrencyRounding::Standard => (fraction_info.digits, fraction_info.rounding),
+            CurrencyRounding::Cash => (fraction_info.cash_digits, fraction_info.cash_rounding),
+        };
+        let position = -i16::from(digits);
+        let mut value = value.clone();
+        round_to_increment(&mut value, position, increment);
+        value.pad_end(position);
+        let is_negative = value.sign() == Sign::Negative;
+        if self.options.display != CurrencyDisplay::Name {
+            // The sign is rendered by the currency pattern.
+            value.set_sign(Sign::None);
+        }
+        FormattedCurrency {
+            formatter: self,
+            value,
+            is_negative,
+            currency,
+        }
+    }
+
+    /// Returns the number of fraction digits and the rounding increments of a currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+    /// use icu_locid::locale;
+    /// use tinystr::tinystr;
+    ///
+    /// let fmt = CurrencyFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// let chf = fmt.fraction_info(CurrencyCode(tinystr!(3, "CHF")));
+    /// assert_eq!((chf.digits, chf.cash_rounding), (2, 5));
+    /// let jpy = fmt.fraction_info(CurrencyCode(tinystr!(3, "JPY")));
+    /// assert_eq!(jpy.digits, 0);
+    /// ```
+    pub fn fraction_info(&self, currency: CurrencyCode) -> FractionInfo {
+        self.fractions
+            .get()
+            .fractions
+            .get_copied(&currency.0)
+            .unwrap_or(self.fractions.get().default)
+    }
+}
+
+/// Rounds `value` half to even to a multiple of `increment` units at `position`.
+///
+/// Increments other than powers of ten times 1 or 5 are not used by CLDR and are treated as 1.
+fn round_to_increment(value: &mut FixedDecimal, mut position: i16, mut increment: u8) {
+    while increment >= 10 && increment % 10 == 0 {
+        increment /= 10;
+        position += 1;
+    }
+    if increment != 5 {
+        value.half_even(position);
+        return;
+    }
+    // The remainder modulo 5 units in tenths of a unit, to be compared with 2.5 units.
+    let digit = value.digit_at(position);
+    let remainder = (digit % 5) * 10 + value.digit_at(position - 1);
+    let is_tie =
+        remainder == 25 && (value.is_zero() || value.nonzero_magnitude_end() >= position - 1);
+    // On a tie, round to the even multiple of 5 units: 0 rather than 5, 10 rather than 5.
+    let round_up = if is_tie { digit >= 5 } else { remainder >= 25 };
+    if digit >= 5 && round_up {
+        value.expand(position + 1);
+    } else {
+        value.trunc(position + 1);
+        if (digit >= 5) != round_up {
+            // The digits of `value` are all above `position`, so this cannot fail.
+            let _ = value.concatenate_end(FixedDecimal::from(5).multiplied_pow10(position));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_to_increment;
+    use fixed_decimal::FixedDecimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_to_increment() {
+        #[rustfmt::skip]
+        let cases = [
+            ("12.02", -2, 5, "12.00"),
+            ("12.025", -2, 5, "12.00"),
+            ("12.0251", -2, 5, "12.05"),
+            ("12.075", -2, 5, "12.10"),
+            ("12.07", -2, 5, "12.05"),
+            ("-12.08", -2, 5, "-12.10"),
+            ("0.024", -2, 5, "0.00"),
+            ("124", 0, 50, "100"),
+            ("125", 0, 50, "100"),
+            ("175", 0, 50, "200"),
+            ("12.345", -2, 0, "12.34"),
+            ("12.355", -2, 1, "12.36"),
+        ];
+        for (input, position, increment, expected) in cases {
+            let mut value = FixedDecimal::from_str(input).unwrap();
+            round_to_increment(&mut value, position, increment);
+            value.pad_end(position);
+            assert_eq!(
+                value.to_string(),
+                expected,
+                "{input} at {position} by {increment}"
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`CurrencyFormatter`](crate::currency::CurrencyFormatter).
+
+/// A bag of options defining how currency amounts will be formatted by
+/// [`CurrencyFormatter`](crate::currency::CurrencyFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct CurrencyFormatterOptions {
+    /// How to display the currency.
+    pub display: CurrencyDisplay,
+
+    /// How to display negative amounts.
+    pub sign: CurrencySign,
+
+    /// How to round amounts.
+    pub rounding: CurrencyRounding,
+}
+
+/// Configuration for how to display the currency.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::currency::options::{
+///     CurrencyDisplay, CurrencyFormatterOptions,
+/// };
+/// use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let amount = FixedDecimal::from(1);
+/// let cad = CurrencyCode(tinystr!(3, "CAD"));
+///
+/// # // The following lines contain U+00A0 NO-BREAK SPACE.
+/// for (display, expected) in [
+///     (CurrencyDisplay::Symbol, "CA$1.00"),
+///     (CurrencyDisplay::NarrowSymbol, "$1.00"),
+///     (CurrencyDisplay::IsoCode, "CAD 1.00"),
+///     (CurrencyDisplay::Name, "1.00 Canadian dollars"),
+/// ] {
+///     let mut options = CurrencyFormatterOptions::default();
+///     options.display = display;
+///     let fmt = CurrencyFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         options,
+///     )
+///     .expect("Data should load successfully");
+///
+///     assert_writeable_eq!(fmt.format(&amount, cad), expected);
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyDisplay {
+    /// Display the locale's currency symbol, such as "CA$".
+    Symbol,
+
+    /// Display the locale's narrow currency symbol, such as "$", which may be ambiguous.
+    NarrowSymbol,
+
+    /// Display the ISO 4217 currency code, such as "CAD".
+    IsoCode,
+
+    /// Display the locale's plural-dependent name of the currency, such as "Canadian dollars".
+    Name,
+}
+
+impl Default for CurrencyDisplay {
+    fn default() -> Self {
+        Self::Symbol
+    }
+}
+
+/// Configuration for how to display negative amounts.
+///
+/// This has no effect with [`CurrencyDisplay::Name`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::currency::options::{CurrencyFormatterOptions, CurrencySign};
+/// use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = CurrencyFormatterOptions::default();
+/// options.sign = CurrencySign::Accounting;
+/// let fmt = CurrencyFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let amount = FixedDecimal::from(-1234);
+/// assert_writeable_eq!(
+///     fmt.format(&amount, CurrencyCode(tinystr!(3, "USD"))),
+///     "($1,234.00)"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencySign {
+    /// Use the locale's standard currency pattern, usually with a minus sign.
+    Standard,
+
+    /// Use the locale's accounting currency pattern, which in many locales wraps negative
+    /// amounts in parentheses.
+    Accounting,
+}
+
+impl Default for CurrencySign {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Configuration for how to round amounts.
+///
+/// Amounts are rounded half to even.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::currency::options::{
+///     CurrencyFormatterOptions, CurrencyRounding,
+/// };
+/// use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+/// use icu_locid::locale;
+/// use std::str::FromStr;
+/// use tinystr::tinystr;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = CurrencyFormatterOptions::default();
+/// options.rounding = CurrencyRounding::Cash;
+/// let fmt = CurrencyFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let amount = FixedDecimal::from_str("12.02").unwrap();
+/// # // The following line contains U+00A0 NO-BREAK SPACE.
+/// assert_writeable_eq!(
+///     fmt.format(&amount, CurrencyCode(tinystr!(3, "CHF"))),
+///     "CHF 12.00"
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CurrencyRounding {
+    /// Round to the number of fraction digits and the rounding increment that the currency
+    /// uses in most contexts.
+    Standard,
+
+    /// Round to the number of fraction digits and the rounding increment that the currency
+    /// uses for cash transactions, such as multiples of 0.05 for the Swiss franc.
+    Cash,
+}
+
+impl Default for CurrencyRounding {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
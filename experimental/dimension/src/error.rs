@@ -0,0 +1,63 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_list::ListError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in the `icu_dimension` crate.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DimensionError {
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// The unit identifier is not syntactically valid.
+    #[displaydoc("Invalid unit identifier")]
+    InvalidUnit,
+    /// The unit identifier is not supported by the locale data.
+    #[displaydoc("Unknown unit identifier")]
+    UnknownUnit,
+    /// An error originating from [`ListFormatter`](icu_list::ListFormatter).
+    #[displaydoc("Error loading ListFormatter: {0}")]
+    List(ListError),
+    /// The units of a mixed unit measure different quantities, or cannot be mixed.
+    #[displaydoc("Incompatible units in mixed unit")]
+    IncompatibleUnits,
+    /// The string is not a valid rational number.
+    #[displaydoc("Invalid rational number")]
+    InvalidRatio,
+}
+
+impl From<PluralsError> for DimensionError {
+    fn from(e: PluralsError) -> Self {
+        DimensionError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for DimensionError {
+    fn from(e: DataError) -> Self {
+        DimensionError::Data(e)
+    }
+}
+
+impl From<DecimalError> for DimensionError {
+    fn from(e: DecimalError) -> Self {
+        DimensionError::Decimal(e)
+    }
+}
+
+impl From<ListError> for DimensionError {
+    fn from(e: ListError) -> Self {
+        DimensionError::List(e)
+    }
+}
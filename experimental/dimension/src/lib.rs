@@ -0,0 +1,63 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Formatting of quantities with dimensions, such as currency amounts and
+//! measurement units.
+//!
+//! This module is published as its own crate ([`icu_dimension`](https://docs.rs/icu_dimension/latest/icu_dimension/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_dimension::currency::{CurrencyCode, CurrencyFormatter};
+//! use icu_locid::locale;
+//! use tinystr::tinystr;
+//! use writeable::assert_writeable_eq;
+//!
+//! let fmt = CurrencyFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     Default::default(),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let value = FixedDecimal::from(12345).multiplied_pow10(-1);
+//! assert_writeable_eq!(
+//!     fmt.format(&value, CurrencyCode(tinystr!(3, "USD"))),
+//!     "$1,234.50"
+//! );
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+pub mod currency;
+mod error;
+pub mod provider;
+pub mod units;
+
+pub use error::DimensionError;
@@ -0,0 +1,425 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable.
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_decimal::provider::AffixesV1;
+use icu_plurals::PluralCategory;
+use icu_provider::{yoke, zerofrom};
+use tinystr::TinyAsciiStr;
+use zerovec::{VarZeroVec, ZeroMap, ZeroMap2d};
+
+/// The character that stands for the currency symbol in the affixes of a
+/// [`CurrencyPatternV1`].
+pub const CURRENCY_PLACEHOLDER: char = '¤';
+
+/// Locale-specific data for formatting currency amounts with symbols.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(CurrencyEssentialsV1Marker = "currency/essentials@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyEssentialsV1<'data> {
+    /// The CLDR `standard` currency pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard: CurrencyPatternV1<'data>,
+
+    /// The pattern used instead of `standard` if the currency symbol would otherwise be
+    /// adjacent to the number and the adjacent character of the symbol is alphanumeric.
+    ///
+    /// This is the CLDR `standard-alphaNextToNumber` pattern if the locale has one, or else
+    /// the `standard` pattern with the CLDR currency spacing inserted next to the symbol.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub standard_alpha_next_to_number: CurrencyPatternV1<'data>,
+
+    /// The CLDR `accounting` currency pattern.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub accounting: CurrencyPatternV1<'data>,
+
+    /// The accounting counterpart of `standard_alpha_next_to_number`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub accounting_alpha_next_to_number: CurrencyPatternV1<'data>,
+
+    /// A map from ISO 4217 currency codes to currency symbols, such as "US$".
+    ///
+    /// Currencies whose symbol is the currency code itself are omitted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+
+    /// A map from ISO 4217 currency codes to narrow currency symbols, such as "$".
+    ///
+    /// Currencies whose narrow symbol is their symbol are omitted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow_symbols: ZeroMap<'data, TinyAsciiStr<3>, str>,
+}
+
+/// The affixes of a currency pattern, in which [`CURRENCY_PLACEHOLDER`] stands for the
+/// currency symbol.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+pub struct CurrencyPatternV1<'data> {
+    /// The affixes around positive numbers and zero.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub positive: AffixesV1<'data>,
+
+    /// The affixes around negative numbers, including the localized minus sign, if any.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub negative: AffixesV1<'data>,
+}
+
+/// Locale-specific data for formatting currency amounts with display names, such as
+/// "1.00 US dollars".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(CurrencyNamesV1Marker = "currency/names@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyNamesV1<'data> {
+    /// A map from plural categories to the CLDR currency unit patterns, such as "{0} {1}",
+    /// where `{0}` is the number and `{1}` the display name of the currency.
+    ///
+    /// Only the [`Count::Other`] pattern is required.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub unit_patterns: ZeroMap<'data, Count, str>,
+
+    /// A map from ISO 4217 currency codes and plural categories to display names, such as
+    /// "US dollars".
+    ///
+    /// Only the [`Count::Other`] name is required for each currency.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap2d<'data, TinyAsciiStr<3>, Count, str>,
+}
+
+/// A CLDR plural keyword.
+/// See <https://www.unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules>.
+#[zerovec::make_ule(CountULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[repr(u8)]
+pub enum Count {
+    /// The CLDR keyword `zero`.
+    Zero = 0,
+    /// The CLDR keyword `one`.
+    One = 1,
+    /// The CLDR keyword `two`.
+    Two = 2,
+    /// The CLDR keyword `few`.
+    Few = 3,
+    /// The CLDR keyword `many`.
+    Many = 4,
+    /// The CLDR keyword `other`.
+    Other = 5,
+}
+
+impl From<PluralCategory> for Count {
+    fn from(other: PluralCategory) -> Self {
+        use PluralCategory::*;
+        match other {
+            Zero => Count::Zero,
+            One => Count::One,
+            Two => Count::Two,
+            Few => Count::Few,
+            Many => Count::Many,
+            Other => Count::Other,
+        }
+    }
+}
+
+/// The number of fraction digits and the rounding increments of currencies, from CLDR
+/// `currencyData`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(CurrencyFractionsV1Marker = "currency/fractions@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyFractionsV1<'data> {
+    /// A map from ISO 4217 currency codes to their fraction data.
+    ///
+    /// Currencies that use the default fraction data are omitted.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub fractions: ZeroMap<'data, TinyAsciiStr<3>, FractionInfo>,
+
+    /// The fraction data of currencies not in `fractions`.
+    pub default: FractionInfo,
+}
+
+/// The number of fraction digits and the rounding increments of a currency.
+///
+/// A rounding increment is in units of the smallest fraction digit; for example, an
+/// increment of 5 with 2 digits means that amounts are rounded to multiples of 0.05.
+/// An increment of 0 means that there is no increment other than the smallest fraction digit.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(FractionInfoULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+pub struct FractionInfo {
+    /// The number of fraction digits used in most contexts.
+    pub digits: u8,
+    /// The rounding increment used in most contexts.
+    pub rounding: u8,
+    /// The number of fraction digits used for cash transactions.
+    pub cash_digits: u8,
+    /// The rounding increment used for cash transactions.
+    pub cash_rounding: u8,
+}
+
+/// Locale-specific patterns for formatting measurement units, such as "{0} meters".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(UnitsDisplayNamesV1Marker = "units/displaynames@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsDisplayNamesV1<'data> {
+    /// The patterns for [`Width::Long`](crate::units::options::Width::Long).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub long: UnitPatternsV1<'data>,
+
+    /// The patterns for [`Width::Short`](crate::units::options::Width::Short).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub short: UnitPatternsV1<'data>,
+
+    /// The patterns for [`Width::Narrow`](crate::units::options::Width::Narrow).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: UnitPatternsV1<'data>,
+}
+
+/// The unit patterns of a locale for one width.
+///
+/// Units are identified by their CLDR unit identifier without category, such as "meter" or
+/// "kilometer-per-hour".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPatternsV1<'data> {
+    /// A map from units and plural categories to patterns, such as "{0} meters", where `{0}`
+    /// is the number.
+    ///
+    /// Only the [`Count::Other`] pattern is required for each unit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, str, Count, str>,
+
+    /// A map from units to the patterns used when they are the denominator of a compound
+    /// unit, such as "{0} per meter", where `{0}` is the formatted numerator.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+
+    /// The pattern for compound units whose denominator has no per-unit pattern, such as
+    /// "{0} per {1}", where `{0}` is the formatted numerator and `{1}` the name of the
+    /// denominator.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per: Cow<'data, str>,
+}
+
+/// The conversion factors of measurement units to base units, from CLDR `convertUnits`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(UnitsConversionV1Marker = "units/conversion@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsConversionV1<'data> {
+    /// A map from simple units, such as "foot" or "100-kilometer", to their conversion to
+    /// base units.
+    ///
+    /// Units with SI or binary prefixes, powers, and compound units are not listed; they are
+    /// derived from the simple units they are made of.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub conversions: ZeroMap<'data, str, ConversionInfoULE>,
+}
+
+/// The conversion of a simple unit to base units: a value `x` in the unit is
+/// `x * factor + offset` in the base unit.
+///
+/// The factor and the offset are exact rational numbers in the form accepted by
+/// [`Ratio`](crate::units::Ratio), such as "381/1250".
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(
+    Debug, Clone, Default, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom, Ord, PartialOrd, Eq,
+)]
+#[zerovec::make_varule(ConversionInfoULE)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider),
+    zerovec::derive(Serialize),
+)]
+#[zerovec::derive(Debug)]
+#[cfg_attr(feature = "serde", zerovec::derive(Deserialize))]
+pub struct ConversionInfo<'data> {
+    /// The base unit, such as "meter" or "kilogram-per-meter-square-second".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_unit: Cow<'data, str>,
+    /// The factor from the unit to the base unit.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub factor: Cow<'data, str>,
+    /// The offset from the unit to the base unit, which is "0" for all units but a few
+    /// temperature units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub offset: Cow<'data, str>,
+}
+
+/// The preferred units for measuring quantities in different regions and for different
+/// usages, from CLDR `unitPreferenceData`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(UnitPreferencesV1Marker = "units/preferences@1")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct UnitPreferencesV1<'data> {
+    /// A map from base units, such as "meter-per-second", to the quantities they measure,
+    /// such as "speed".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quantities: ZeroMap<'data, str, str>,
+
+    /// A map from quantities and usages joined by a slash, such as "length/road", and
+    /// region codes, such as "US" or "001", to the preferred units.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub preferences: ZeroMap2d<'data, str, TinyAsciiStr<3>, UnitPreferenceListULE>,
+}
+
+/// The preferred units for a quantity, usage, and region, from largest to smallest.
+///
+/// The three lists have the same length. A unit is preferred for values that are at least
+/// its threshold when expressed in that unit; the last unit is used for smaller values.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(
+    Debug, Clone, Default, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom, Ord, PartialOrd, Eq,
+)]
+#[zerovec::make_varule(UnitPreferenceListULE)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_dimension::provider),
+    zerovec::derive(Serialize),
+)]
+#[zerovec::derive(Debug)]
+#[cfg_attr(feature = "serde", zerovec::derive(Deserialize))]
+pub struct UnitPreferenceList<'data> {
+    /// The preferred units, such as "mile" or "foot-and-inch".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub units: VarZeroVec<'data, str>,
+    /// The thresholds of the units, as exact rational numbers in the form accepted by
+    /// [`Ratio`](crate::units::Ratio), such as "1/2".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub geqs: VarZeroVec<'data, str>,
+    /// The number skeletons with which values in the units should be formatted, such as
+    /// "precision-increment/50", or empty strings.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub skeletons: VarZeroVec<'data, str>,
+}
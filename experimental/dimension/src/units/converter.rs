@@ -0,0 +1,278 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{MeasureUnit, Ratio};
+use crate::provider::{ConversionInfo, UnitsConversionV1Marker};
+use crate::DimensionError;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use fixed_decimal::FixedDecimal;
+use icu_provider::zerofrom::ZeroFrom;
+use icu_provider::{DataPayload, DataProvider};
+
+/// The SI and binary prefixes, with their bases and exponents.
+const PREFIXES: [(&str, i64, i32); 32] = [
+    ("quecto", 10, -30),
+    ("ronto", 10, -27),
+    ("yocto", 10, -24),
+    ("zepto", 10, -21),
+    ("atto", 10, -18),
+    ("femto", 10, -15),
+    ("pico", 10, -12),
+    ("nano", 10, -9),
+    ("micro", 10, -6),
+    ("milli", 10, -3),
+    ("centi", 10, -2),
+    ("deci", 10, -1),
+    ("deka", 10, 1),
+    ("hecto", 10, 2),
+    ("kilo", 10, 3),
+    ("mega", 10, 6),
+    ("giga", 10, 9),
+    ("tera", 10, 12),
+    ("peta", 10, 15),
+    ("exa", 10, 18),
+    ("zetta", 10, 21),
+    ("yotta", 10, 24),
+    ("ronna", 10, 27),
+    ("quetta", 10, 30),
+    ("kibi", 1024, 1),
+    ("mebi", 1024, 2),
+    ("gibi", 1024, 3),
+    ("tebi", 1024, 4),
+    ("pebi", 1024, 5),
+    ("exbi", 1024, 6),
+    ("zebi", 1024, 7),
+    ("yobi", 1024, 8),
+];
+
+/// The conversion of a unit to base units.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UnitInfo {
+    pub(crate) factor: Ratio,
+    pub(crate) offset: Ratio,
+    /// The powers of the base units the unit is made of, such as {"meter": 1, "second": -1}.
+    pub(crate) dimension: BTreeMap<String, i16>,
+}
+
+/// A factory for [`UnitsConverter`]s, backed by the CLDR conversion factors of measurement
+/// units.
+///
+/// Units are converted with exact rational arithmetic; the results are rounded only when
+/// they are converted to [`FixedDecimal`].
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::ConverterFactory;
+///
+/// let factory = ConverterFactory::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data should load successfully");
+///
+/// let converter = factory
+///     .converter(
+///         &"kilometer-per-hour".parse().unwrap(),
+///         &"mile-per-hour".parse().unwrap(),
+///     )
+///     .expect("The units are convertible");
+/// let speed = FixedDecimal::from(100);
+/// assert_eq!(
+///     converter
+///         .convert_fixed_decimal(&speed, -3)
+///         .map(|speed| speed.to_string()),
+///     Some("62.137".into())
+/// );
+///
+/// // Units of different quantities cannot be converted into each other.
+/// assert!(factory
+///     .converter(&"meter".parse().unwrap(), &"second".parse().unwrap())
+///     .is_none());
+/// ```
+pub struct ConverterFactory {
+    conversions: DataPayload<UnitsConversionV1Marker>,
+}
+
+impl ConverterFactory {
+    /// Creates a new [`ConverterFactory`] from conversion data.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(data_provider: &D) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<UnitsConversionV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            conversions: data_provider.load(Default::default())?.take_payload()?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: DimensionError);
+
+    /// Returns a converter from `input` to `output`, or `None` if either unit is not
+    /// supported or the units measure different quantities.
+    ///
+    /// Units that are the reciprocal of each other, such as "liter-per-100-kilometer" and
+    /// "mile-per-gallon", are convertible.
+    pub fn converter(&self, input: &MeasureUnit, output: &MeasureUnit) -> Option<UnitsConverter> {
+        let input = self.unit_info(input.as_str())?;
+        let output = self.unit_info(output.as_str())?;
+        if input.dimension == output.dimension {
+            let output_factor = output.factor.recip()?;
+            Some(UnitsConverter {
+                factor: &input.factor * &output_factor,
+                offset: (input.offset - &output.offset) * &output_factor,
+                is_reciprocal: false,
+            })
+        } else if input.dimension == invert(&output.dimension) {
+            Some(UnitsConverter {
+                factor: (&input.factor * &output.factor).recip()?,
+                offset: Ratio::default(),
+                is_reciprocal: true,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the conversion of a unit identifier to base units.
+    pub(crate) fn unit_info(&self, unit: &str) -> Option<UnitInfo> {
+        let mut info = UnitInfo {
+            factor: Ratio::from(1),
+            offset: Ratio::default(),
+            dimension: BTreeMap::new(),
+        };
+        let (numerator, denominator) = match unit.strip_prefix("per-") {
+            Some(denominator) => ("", denominator),
+            None => unit.split_once("-per-").unwrap_or((unit, "")),
+        };
+        self.add_product(numerator, 1, &mut info)?;
+        self.add_product(denominator, -1, &mut info)?;
+        // Only simple units have an offset.
+        if let Some(conversion) = self.conversions.get().conversions.get(unit) {
+            info.offset = ConversionInfo::zero_from(conversion).offset.parse().ok()?;
+        }
+        Some(info)
+    }
+
+    /// Multiplies `info` by the product of units `product`, such as "kilogram-square-meter",
+    /// raised to the power `sign`.
+    fn add_product(&self, product: &str, sign: i16, info: &mut UnitInfo) -> Option<()> {
+        let mut rest = product;
+        while !rest.is_empty() {
+            let (power, unit) = if let Some(unit) = rest.strip_prefix("square-") {
+                (2, unit)
+            } else if let Some(unit) = rest.strip_prefix("cubic-") {
+                (3, unit)
+            } else if let Some(unit) = rest.strip_prefix("pow") {
+                let (power, unit) = unit.split_once('-')?;
+                (power.parse().ok().filter(|p| (2..=15).contains(p))?, unit)
+            } else {
+                (1, rest)
+            };
+            // The longest prefix of `unit` made of whole parts that is a simple unit.
+            let (factor, base_unit, remainder) = unit
+                .match_indices('-')
+                .map(|(i, _)| i)
+                .chain(core::iter::once(unit.len()))
+                .rev()
+                .find_map(|end| {
+                    let (factor, base_unit) = self.simple_unit(unit.get(..end)?)?;
+                    Some((factor, base_unit, unit.get(end..)?))
+                })?;
+            let power = power * sign;
+            info.factor = &info.factor * &factor.pow(power.into())?;
+            for (unit, exponent) in base_unit {
+                *info.dimension.entry(unit).or_default() += exponent * power;
+            }
+            info.dimension.retain(|_, exponent| *exponent != 0);
+            rest = remainder.strip_prefix('-').unwrap_or(remainder);
+        }
+        Some(())
+    }
+
+    /// Returns the factor to base units and the dimension of a simple unit with an optional
+    /// prefix, such as "kilometer".
+    fn simple_unit(&self, unit: &str) -> Option<(Ratio, BTreeMap<String, i16>)> {
+        let conversions = &self.conversions.get().conversions;
+        let (prefix_factor, conversion) = match conversions.get(unit) {
+            Some(conversion) => (Ratio::from(1), conversion),
+            None => PREFIXES.iter().find_map(|&(prefix, base, exponent)| {
+                let conversion = conversions.get(unit.strip_prefix(prefix)?)?;
+                Some((Ratio::from(base).pow(exponent)?, conversion))
+            })?,
+        };
+        let conversion = ConversionInfo::zero_from(conversion);
+        let factor: Ratio = conversion.factor.parse().ok()?;
+        let dimension = if conversion.base_unit == unit {
+            BTreeMap::from([(String::from(unit), 1)])
+        } else {
+            // Base units are made of units that are their own base unit, so this terminates.
+            self.unit_info(&conversion.base_unit)?.dimension
+        };
+        Some((&prefix_factor * &factor, dimension))
+    }
+}
+
+pub(crate) fn invert(dimension: &BTreeMap<String, i16>) -> BTreeMap<String, i16> {
+    dimension
+        .iter()
+        .map(|(unit, exponent)| (unit.clone(), -exponent))
+        .collect()
+}
+
+/// A converter from values in one measurement unit to values in another, created by
+/// [`ConverterFactory::converter`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitsConverter {
+    factor: Ratio,
+    offset: Ratio,
+    /// Whether the output is proportional to the reciprocal of the input, as when converting
+    /// fuel consumption from "liter-per-100-kilometer" to "mile-per-gallon".
+    is_reciprocal: bool,
+}
+
+impl UnitsConverter {
+    /// Converts a value exactly.
+    ///
+    /// Zero converts to zero with reciprocal conversions, which have no finite result for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_dimension::units::{ConverterFactory, Ratio};
+    ///
+    /// let factory = ConverterFactory::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data should load successfully");
+    /// let converter = factory
+    ///     .converter(&"fahrenheit".parse().unwrap(), &"celsius".parse().unwrap())
+    ///     .expect("The units are convertible");
+    ///
+    /// assert_eq!(converter.convert(&Ratio::from(212)), Ratio::from(100));
+    /// assert_eq!(converter.convert(&Ratio::from(0)).to_string(), "-160/9");
+    /// ```
+    pub fn convert(&self, value: &Ratio) -> Ratio {
+        if self.is_reciprocal {
+            value
+                .recip()
+                .map(|recip| &recip * &self.factor)
+                .unwrap_or_default()
+        } else {
+            value * &self.factor + &self.offset
+        }
+    }
+
+    /// Converts a value, and rounds the result half to even to `position`.
+    ///
+    /// Returns `None` if the result has more digits than [`FixedDecimal`] supports.
+    pub fn convert_fixed_decimal(
+        &self,
+        value: &FixedDecimal,
+        position: i16,
+    ) -> Option<FixedDecimal> {
+        self.convert(&Ratio::from(value)).to_fixed_decimal(position)
+    }
+}
@@ -0,0 +1,37 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormatter;
+use writeable::Writeable;
+
+/// An intermediate structure returned by [`UnitsFormatter`](super::UnitsFormatter).
+/// Use [`Writeable`][Writeable] to render the formatted quantity to a string or buffer.
+pub struct FormattedUnit<'l> {
+    /// The unit pattern for the plural category of `value`.
+    pub(crate) pattern: &'l str,
+    pub(crate) value: &'l FixedDecimal,
+    pub(crate) fixed_decimal_formatter: &'l FixedDecimalFormatter,
+}
+
+impl<'l> Writeable for FormattedUnit<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        let mut parts = self.pattern.split("{0}");
+        if let Some(part) = parts.next() {
+            sink.write_str(part)?;
+        }
+        for part in parts {
+            self.fixed_decimal_formatter
+                .format(self.value)
+                .write_to(sink)?;
+            sink.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedUnit<'_>);
@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::DimensionError;
+use alloc::string::String;
+use core::str::FromStr;
+
+/// A CLDR unit identifier, such as "meter" or "meter-per-second".
+///
+/// See <https://www.unicode.org/reports/tr35/tr35-general.html#Unit_Identifiers>.
+///
+/// # Examples
+///
+/// ```
+/// use icu_dimension::units::MeasureUnit;
+/// use icu_dimension::DimensionError;
+///
+/// let unit: MeasureUnit = "kilometer-per-hour".parse().unwrap();
+/// assert_eq!(unit.as_str(), "kilometer-per-hour");
+///
+/// assert_eq!(
+///     "Kilometer".parse::<MeasureUnit>(),
+///     Err(DimensionError::InvalidUnit)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeasureUnit(String);
+
+impl MeasureUnit {
+    /// Returns the unit identifier as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = DimensionError;
+
+    /// Parses a unit identifier, which consists of lowercase ASCII alphanumeric parts
+    /// separated by hyphens.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_valid = s.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+        if is_valid {
+            Ok(Self(String::from(s)))
+        } else {
+            Err(DimensionError::InvalidUnit)
+        }
+    }
+}
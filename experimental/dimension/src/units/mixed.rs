@@ -0,0 +1,272 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::format::FormattedUnit;
+use super::options::{MixedUnitsFormatterOptions, Width};
+use super::{resolve_patterns, ConverterFactory, MeasureUnit, Ratio};
+use crate::provider::{Count, UnitsConversionV1Marker, UnitsDisplayNamesV1Marker};
+use crate::DimensionError;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_list::provider::UnitListV1Marker;
+use icu_list::{ListFormatter, ListLength};
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use writeable::Writeable;
+
+/// One unit of a [`MixedUnitsFormatter`].
+struct MixedUnitPart {
+    /// The patterns of the unit for each plural category, with `{0}` standing for the number.
+    patterns: BTreeMap<Count, String>,
+    /// The size of the unit in the last unit of the sequence, such as 12 for "foot" in
+    /// "foot-and-inch".
+    scale: Ratio,
+}
+
+/// A formatter that renders quantities split across a sequence of units, such as
+/// "5 ft, 10 in" or "1h 23m".
+///
+/// Mixed units are CLDR unit identifiers of units joined by "-and-", such as "foot-and-inch",
+/// from the largest unit to the smallest. Only the last unit may have a fractional part;
+/// quantities are rounded to it.
+///
+/// Each part is pluralized on its own, and the parts are joined with the unit list patterns
+/// of the locale.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::options::{MixedUnitsFormatterOptions, Width};
+/// use icu_dimension::units::MixedUnitsFormatter;
+/// use icu_locid::locale;
+///
+/// let format = |value: &FixedDecimal, unit: &str, width| {
+///     let mut options = MixedUnitsFormatterOptions::default();
+///     options.width = width;
+///     MixedUnitsFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         unit.parse().unwrap(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+///     .format(value)
+///     .expect("The value is in range")
+///     .to_string()
+/// };
+///
+/// // 5.9 feet
+/// let height = FixedDecimal::from(59).multiplied_pow10(-1);
+/// assert_eq!(format(&height, "foot-and-inch", Width::Short), "5 ft, 11 in");
+/// assert_eq!(
+///     format(&height, "foot-and-inch", Width::Long),
+///     "5 feet, 11 inches"
+/// );
+///
+/// // 1.39 hours
+/// let duration = FixedDecimal::from(139).multiplied_pow10(-2);
+/// assert_eq!(format(&duration, "hour-and-minute", Width::Narrow), "1h 23m");
+/// ```
+pub struct MixedUnitsFormatter {
+    parts: Vec<MixedUnitPart>,
+    fraction_digits: u8,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    plural_rules: PluralRules,
+    list_formatter: ListFormatter,
+}
+
+impl MixedUnitsFormatter {
+    /// Creates a new [`MixedUnitsFormatter`] for a mixed unit, such as "foot-and-inch", from
+    /// locale data and an options bag.
+    ///
+    /// Returns [`DimensionError::UnknownUnit`] if a unit is not supported, and
+    /// [`DimensionError::IncompatibleUnits`] if the units measure different quantities.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: MixedUnitsFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<UnitsDisplayNamesV1Marker>
+            + DataProvider<UnitsConversionV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<UnitListV1Marker>
+            + ?Sized,
+    {
+        let display_names: DataPayload<UnitsDisplayNamesV1Marker> = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let (unit_patterns, length) = match options.width {
+            Width::Long => (&display_names.get().long, ListLength::Wide),
+            Width::Short => (&display_names.get().short, ListLength::Short),
+            Width::Narrow => (&display_names.get().narrow, ListLength::Narrow),
+        };
+        let factory = ConverterFactory::try_new_unstable(data_provider)?;
+        let infos = unit
+            .as_str()
+            .split("-and-")
+            .map(|unit| {
+                let info = factory.unit_info(unit).ok_or(DimensionError::UnknownUnit)?;
+                let patterns =
+                    resolve_patterns(unit_patterns, unit).ok_or(DimensionError::UnknownUnit)?;
+                Ok((info, patterns))
+            })
+            .collect::<Result<Vec<_>, DimensionError>>()?;
+        let (last, _) = infos.last().ok_or(DimensionError::InvalidUnit)?;
+        let last_factor = last
+            .factor
+            .recip()
+            .ok_or(DimensionError::IncompatibleUnits)?;
+        let mut parts = Vec::with_capacity(infos.len());
+        for (info, patterns) in &infos {
+            // Units with an offset, such as "celsius", cannot be added up.
+            if info.dimension != last.dimension || !info.offset.is_zero() {
+                return Err(DimensionError::IncompatibleUnits);
+            }
+            parts.push(MixedUnitPart {
+                patterns: patterns.clone(),
+                scale: &info.factor * &last_factor,
+            });
+        }
+        Ok(Self {
+            parts,
+            fraction_digits: options.fraction_digits,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                Default::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+            list_formatter: ListFormatter::try_new_unit_with_length_unstable(
+                data_provider,
+                locale,
+                length,
+            )?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: MixedUnitsFormatterOptions,
+        error: DimensionError
+    );
+
+    /// Formats a quantity given in the first unit of the mixed unit.
+    ///
+    /// Returns `None` if the quantity in the last unit has more digits than [`FixedDecimal`]
+    /// supports.
+    pub fn format(&self, value: &FixedDecimal) -> Option<FormattedMixedUnit<'_>> {
+        self.format_ratio(&Ratio::from(value))
+    }
+
+    /// Formats a quantity given in the first unit of the mixed unit as an exact [`Ratio`],
+    /// such as the value of a [`PreferredValue`](super::PreferredValue).
+    ///
+    /// Returns `None` if the quantity in the last unit has more digits than [`FixedDecimal`]
+    /// supports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_dimension::units::{MixedUnitsFormatter, Ratio};
+    /// use icu_locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let fmt = MixedUnitsFormatter::try_new_unstable(
+    ///     &icu_testdata::unstable(),
+    ///     &locale!("en").into(),
+    ///     "foot-and-inch".parse().unwrap(),
+    ///     Default::default(),
+    /// )
+    /// .expect("Data should load successfully");
+    ///
+    /// // Only the total is rounded, so that there are never 12 inches.
+    /// let height: Ratio = "-5999/1000".parse().unwrap();
+    /// assert_writeable_eq!(fmt.format_ratio(&height).unwrap(), "-6 ft, 0 in");
+    /// ```
+    pub fn format_ratio(&self, value: &Ratio) -> Option<FormattedMixedUnit<'_>> {
+        let position = -i16::from(self.fraction_digits);
+        let first_scale = self
+            .parts
+            .first()
+            .map_or_else(|| Ratio::from(1), |part| part.scale.clone());
+        // The magnitude of the value in the last unit, rounded.
+        let rounded = Ratio::from(&(&value.abs() * &first_scale).to_fixed_decimal(position)?);
+        let is_negative = value.is_negative() && !rounded.is_zero();
+        let mut rest = rounded;
+        let mut parts = Vec::with_capacity(self.parts.len());
+        for (i, part) in self.parts.iter().enumerate() {
+            let mut number = if i + 1 == self.parts.len() {
+                rest.to_fixed_decimal(position)?
+            } else {
+                let count = part
+                    .scale
+                    .recip()
+                    .map(|recip| (&rest * &recip).trunc())
+                    .unwrap_or_default();
+                rest = &rest - &(&count * &part.scale);
+                count.to_fixed_decimal(0)?
+            };
+            if i == 0 && is_negative {
+                number.set_sign(Sign::Negative);
+            }
+            let count = Count::from(self.plural_rules.category_for(&number));
+            let pattern = part
+                .patterns
+                .get(&count)
+                .or_else(|| part.patterns.get(&Count::Other))
+                .map(String::as_str)
+                .unwrap_or("{0}");
+            parts.push((pattern, number));
+        }
+        Some(FormattedMixedUnit {
+            parts,
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+            list_formatter: &self.list_formatter,
+        })
+    }
+}
+
+/// An intermediate structure returned by [`MixedUnitsFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted quantity to a string or buffer.
+pub struct FormattedMixedUnit<'l> {
+    /// The unit pattern and the number of each part.
+    parts: Vec<(&'l str, FixedDecimal)>,
+    fixed_decimal_formatter: &'l FixedDecimalFormatter,
+    list_formatter: &'l ListFormatter,
+}
+
+impl<'l> Writeable for FormattedMixedUnit<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        self.list_formatter
+            .format(self.parts.iter().map(|(pattern, value)| FormattedUnit {
+                pattern,
+                value,
+                fixed_decimal_formatter: self.fixed_decimal_formatter,
+            }))
+            .write_to(sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedMixedUnit<'_>);
@@ -0,0 +1,204 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of measurement units, such as "12 km/h" or "3 megabytes", and conversion
+//! between them.
+
+mod converter;
+mod format;
+mod measure_unit;
+mod mixed;
+pub mod options;
+mod preferences;
+mod ratio;
+
+pub use converter::{ConverterFactory, UnitsConverter};
+pub use format::FormattedUnit;
+pub use measure_unit::MeasureUnit;
+pub use mixed::{FormattedMixedUnit, MixedUnitsFormatter};
+pub use options::{MixedUnitsFormatterOptions, UnitsFormatterOptions};
+pub use preferences::{PreferredValue, UnitPreferences};
+pub use ratio::Ratio;
+
+use crate::provider::{Count, UnitPatternsV1, UnitsDisplayNamesV1Marker};
+use crate::DimensionError;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::CardinalV1Marker;
+use icu_plurals::PluralRules;
+use icu_provider::{DataLocale, DataPayload, DataProvider, DataRequest};
+use options::Width;
+use zerovec::ule::AsULE;
+
+/// A formatter that renders quantities of a measurement unit, such as "12 km/h".
+///
+/// Compound units such as "meter-per-second" that have no patterns of their own are
+/// composed from the patterns of their numerator and denominator.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::{MeasureUnit, UnitsFormatter};
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let unit: MeasureUnit = "megabyte".parse().unwrap();
+/// let [en, fr] = [locale!("en"), locale!("fr")].map(|locale| {
+///     UnitsFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         unit.clone(),
+///         Default::default(),
+///     )
+///     .expect("Data should load successfully")
+/// });
+///
+/// let amount = FixedDecimal::from(12345).multiplied_pow10(-1);
+///
+/// assert_writeable_eq!(en.format(&amount), "1,234.5 MB");
+/// # // The following line contains U+202F NARROW NO-BREAK SPACE.
+/// assert_writeable_eq!(fr.format(&amount), "1 234,5 Mo");
+/// ```
+///
+/// The plural form of the unit is chosen with the plural rules of the locale:
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::options::{UnitsFormatterOptions, Width};
+/// use icu_dimension::units::UnitsFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = UnitsFormatterOptions::default();
+/// options.width = Width::Long;
+/// let fmt = UnitsFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("en").into(),
+///     "meter-per-second".parse().unwrap(),
+///     options,
+/// )
+/// .expect("Data should load successfully");
+///
+/// let one = FixedDecimal::from(1);
+/// let two = FixedDecimal::from(2);
+/// assert_writeable_eq!(fmt.format(&one), "1 meter per second");
+/// assert_writeable_eq!(fmt.format(&two), "2 meters per second");
+/// ```
+pub struct UnitsFormatter {
+    /// The patterns of the unit for each plural category, with `{0}` standing for the number.
+    patterns: BTreeMap<Count, String>,
+    fixed_decimal_formatter: FixedDecimalFormatter,
+    plural_rules: PluralRules,
+}
+
+impl UnitsFormatter {
+    /// Creates a new [`UnitsFormatter`] for a unit from locale data and an options bag.
+    ///
+    /// Returns [`DimensionError::UnknownUnit`] if the locale data has no patterns for the
+    /// unit and it cannot be composed from units that have.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        unit: MeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<UnitsDisplayNamesV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + ?Sized,
+    {
+        let display_names: DataPayload<UnitsDisplayNamesV1Marker> = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let unit_patterns = match options.width {
+            Width::Long => &display_names.get().long,
+            Width::Short => &display_names.get().short,
+            Width::Narrow => &display_names.get().narrow,
+        };
+        Ok(Self {
+            patterns: resolve_patterns(unit_patterns, unit.as_str())
+                .ok_or(DimensionError::UnknownUnit)?,
+            fixed_decimal_formatter: FixedDecimalFormatter::try_new_unstable(
+                data_provider,
+                locale,
+                Default::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(data_provider, locale)?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        unit: MeasureUnit,
+        options: UnitsFormatterOptions,
+        error: DimensionError
+    );
+
+    /// Formats a quantity of the unit of this formatter.
+    pub fn format<'l>(&'l self, value: &'l FixedDecimal) -> FormattedUnit<'l> {
+        let count = Count::from(self.plural_rules.category_for(value));
+        let pattern = self
+            .patterns
+            .get(&count)
+            .or_else(|| self.patterns.get(&Count::Other))
+            .map(String::as_str)
+            .unwrap_or("{0}");
+        FormattedUnit {
+            pattern,
+            value,
+            fixed_decimal_formatter: &self.fixed_decimal_formatter,
+        }
+    }
+}
+
+/// Returns the patterns of `unit` for each plural category.
+///
+/// Units without patterns of their own are composed following
+/// <https://www.unicode.org/reports/tr35/tr35-general.html#compound-units>.
+fn resolve_patterns(patterns: &UnitPatternsV1, unit: &str) -> Option<BTreeMap<Count, String>> {
+    if let Some(cursor) = patterns.patterns.get0(unit) {
+        return Some(
+            cursor
+                .iter1()
+                .map(|(count, pattern)| (Count::from_unaligned(*count), String::from(pattern)))
+                .collect(),
+        );
+    }
+    let (numerator, denominator) = unit.split_once("-per-")?;
+    let numerator = resolve_patterns(patterns, numerator)?;
+    if let Some(per_unit_pattern) = patterns.per_unit_patterns.get(denominator) {
+        return Some(
+            numerator
+                .into_iter()
+                .map(|(count, pattern)| (count, per_unit_pattern.replace("{0}", &pattern)))
+                .collect(),
+        );
+    }
+    // The denominator is named by its singular pattern without the placeholder.
+    let denominator = patterns.patterns.get0(denominator)?;
+    let denominator = denominator
+        .get1(&Count::One)
+        .or_else(|| denominator.get1(&Count::Other))?
+        .replace("{0}", "");
+    let per = patterns.per.replace("{1}", denominator.trim());
+    Some(
+        numerator
+            .into_iter()
+            .map(|(count, pattern)| (count, per.replace("{0}", &pattern)))
+            .collect(),
+    )
+}
@@ -0,0 +1,77 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`UnitsFormatter`](crate::units::UnitsFormatter) and
+//! [`MixedUnitsFormatter`](crate::units::MixedUnitsFormatter).
+
+/// A bag of options defining how quantities will be formatted by
+/// [`UnitsFormatter`](crate::units::UnitsFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct UnitsFormatterOptions {
+    /// The width of the unit.
+    pub width: Width,
+}
+
+/// A bag of options defining how quantities will be formatted by
+/// [`MixedUnitsFormatter`](crate::units::MixedUnitsFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct MixedUnitsFormatterOptions {
+    /// The width of the units, which also determines the width of the list pattern joining
+    /// them.
+    pub width: Width,
+    /// The number of fraction digits of the last unit, to which quantities are rounded.
+    pub fraction_digits: u8,
+}
+
+/// Configuration for the width of the unit.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::options::{UnitsFormatterOptions, Width};
+/// use icu_dimension::units::UnitsFormatter;
+/// use icu_locid::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let amount = FixedDecimal::from(12);
+///
+/// for (width, expected) in [
+///     (Width::Long, "12 kilometers per hour"),
+///     (Width::Short, "12 km/h"),
+///     (Width::Narrow, "12km/h"),
+/// ] {
+///     let mut options = UnitsFormatterOptions::default();
+///     options.width = width;
+///     let fmt = UnitsFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("en").into(),
+///         "kilometer-per-hour".parse().unwrap(),
+///         options,
+///     )
+///     .expect("Data should load successfully");
+///
+///     assert_writeable_eq!(fmt.format(&amount), expected);
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Width {
+    /// Spell out the unit, such as "12 kilometers per hour".
+    Long,
+
+    /// Abbreviate the unit, such as "12 km/h".
+    Short,
+
+    /// Abbreviate the unit as much as possible, such as "12km/h".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Short
+    }
+}
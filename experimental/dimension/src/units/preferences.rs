@@ -0,0 +1,174 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::converter::invert;
+use super::{ConverterFactory, MeasureUnit, Ratio};
+use crate::provider::{UnitPreferenceList, UnitPreferencesV1Marker, UnitsConversionV1Marker};
+use crate::DimensionError;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use icu_locid::subtags::Region;
+use icu_provider::zerofrom::ZeroFrom;
+use icu_provider::{DataPayload, DataProvider};
+use tinystr::tinystr;
+
+/// A value converted to a preferred unit by [`UnitPreferences::convert`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct PreferredValue {
+    /// The preferred unit, such as "mile" or "foot-and-inch".
+    pub unit: MeasureUnit,
+    /// The converted value. For mixed units, such as "foot-and-inch", it is in the first
+    /// unit of the sequence.
+    pub value: Ratio,
+    /// The increment to which the value should be rounded for display, if any, such as 50
+    /// for "precision-increment/50".
+    pub precision_increment: Option<Ratio>,
+}
+
+/// The units preferred in a region for measuring a quantity, such as "mile" for road
+/// distances in the United States, from CLDR `unitPreferenceData`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_dimension::units::{Ratio, UnitPreferences};
+/// use icu_locid::subtags_region as region;
+///
+/// let preferences = UnitPreferences::try_new_unstable(&icu_testdata::unstable())
+///     .expect("Data should load successfully");
+/// let meter = "meter".parse().unwrap();
+///
+/// let road = |meters: i64, region| {
+///     let preferred = preferences
+///         .convert(&Ratio::from(meters), &meter, "road", region)
+///         .expect("Meters measure a length");
+///     (
+///         preferred.unit.as_str().to_owned(),
+///         preferred.value.to_fixed_decimal(-1).unwrap().to_string(),
+///     )
+/// };
+///
+/// assert_eq!(road(16093, region!("US")), ("mile".into(), "10.0".into()));
+/// assert_eq!(road(50, region!("US")), ("foot".into(), "164.0".into()));
+/// assert_eq!(road(16093, region!("FR")), ("kilometer".into(), "16.1".into()));
+/// ```
+pub struct UnitPreferences {
+    factory: ConverterFactory,
+    preferences: DataPayload<UnitPreferencesV1Marker>,
+}
+
+impl UnitPreferences {
+    /// Creates a new [`UnitPreferences`] from unit preference and conversion data.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(data_provider: &D) -> Result<Self, DimensionError>
+    where
+        D: DataProvider<UnitPreferencesV1Marker> + DataProvider<UnitsConversionV1Marker> + ?Sized,
+    {
+        Ok(Self {
+            factory: ConverterFactory::try_new_unstable(data_provider)?,
+            preferences: DataProvider::<UnitPreferencesV1Marker>::load(
+                data_provider,
+                Default::default(),
+            )?
+            .take_payload()?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(locale: skip, options: skip, error: DimensionError);
+
+    /// Returns the quantity measured by a unit, such as "speed" for "mile-per-hour", or
+    /// `None` if the unit is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_dimension::units::UnitPreferences;
+    ///
+    /// let preferences = UnitPreferences::try_new_unstable(&icu_testdata::unstable())
+    ///     .expect("Data should load successfully");
+    ///
+    /// let quantity = |unit: &str| preferences.quantity(&unit.parse().unwrap());
+    /// assert_eq!(quantity("mile-per-hour"), Some("speed"));
+    /// assert_eq!(quantity("mile-per-gallon"), Some("consumption"));
+    /// assert_eq!(quantity("acre-foot"), Some("volume"));
+    /// ```
+    pub fn quantity(&self, unit: &MeasureUnit) -> Option<&str> {
+        let dimension = self.factory.unit_info(unit.as_str())?.dimension;
+        let inverse = invert(&dimension);
+        let quantities = &self.preferences.get().quantities;
+        let find = |dimension: &BTreeMap<String, i16>| {
+            quantities.iter().find_map(|(base_unit, quantity)| {
+                if self.factory.unit_info(base_unit)?.dimension == *dimension {
+                    Some(quantity)
+                } else {
+                    None
+                }
+            })
+        };
+        // Reciprocal units, such as "mile-per-gallon", measure the quantity of their inverse.
+        find(&dimension).or_else(|| find(&inverse))
+    }
+
+    /// Converts a value in `unit` to the unit preferred in `region` for `usage`, such as
+    /// "road" or "person-height".
+    ///
+    /// The preferred unit depends on the magnitude of the value; for example, short road
+    /// distances are given in feet rather than miles in the United States. The usage
+    /// "default" is used if the quantity has no preferences for `usage`, and the region
+    /// "001" if the usage has no preferences for `region`.
+    ///
+    /// Returns `None` if the unit is not supported or its quantity has no preferences.
+    pub fn convert(
+        &self,
+        value: &Ratio,
+        unit: &MeasureUnit,
+        usage: &str,
+        region: Region,
+    ) -> Option<PreferredValue> {
+        let quantity = self.quantity(unit)?;
+        let preferences = &self.preferences.get().preferences;
+        let cursor = preferences
+            .get0(&format!("{quantity}/{usage}"))
+            .or_else(|| preferences.get0(&format!("{quantity}/default")))?;
+        let list = cursor
+            .get1(&region.into())
+            .or_else(|| cursor.get1(&tinystr!(3, "001")))?;
+        let list = UnitPreferenceList::zero_from(list);
+        let count = list.units.len();
+        for (i, ((preferred, geq), skeleton)) in list
+            .units
+            .iter()
+            .zip(list.geqs.iter())
+            .zip(list.skeletons.iter())
+            .enumerate()
+        {
+            let preferred: MeasureUnit = preferred.parse().ok()?;
+            // Values in mixed units, such as "foot-and-inch", are compared in the first unit.
+            let first: MeasureUnit = preferred
+                .as_str()
+                .split("-and-")
+                .next()
+                .unwrap_or_default()
+                .parse()
+                .ok()?;
+            let converted = self.factory.converter(unit, &first)?.convert(value);
+            if i + 1 == count || converted.abs() >= geq.parse().ok()? {
+                return Some(PreferredValue {
+                    unit: preferred,
+                    value: converted,
+                    precision_increment: skeleton
+                        .strip_prefix("precision-increment/")
+                        .and_then(|increment| increment.parse().ok()),
+                });
+            }
+        }
+        None
+    }
+}
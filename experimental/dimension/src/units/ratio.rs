@@ -0,0 +1,624 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::DimensionError;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use core::str::FromStr;
+use fixed_decimal::{FixedDecimal, Sign};
+
+/// The largest magnitude of the exponent accepted when parsing a [`Ratio`], such as 100 in
+/// "1E100".
+const MAX_EXPONENT: i32 = 100;
+
+/// An arbitrary-precision unsigned integer, stored as little-endian base 2^32 limbs without
+/// trailing zero limbs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct BigUint(Vec<u32>);
+
+impl BigUint {
+    fn from_u64(value: u64) -> Self {
+        let mut result = Self(vec![value as u32, (value >> 32) as u32]);
+        result.normalize();
+        result
+    }
+
+    fn pow10(exponent: u32) -> Self {
+        let mut result = Self::from_u64(1);
+        for _ in 0..exponent {
+            result.mul_add_small(10, 0);
+        }
+        result
+    }
+
+    fn normalize(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn is_odd(&self) -> bool {
+        self.0.first().map_or(false, |limb| limb & 1 == 1)
+    }
+
+    fn bits(&self) -> usize {
+        self.0
+            .last()
+            .map_or(0, |top| self.0.len() * 32 - top.leading_zeros() as usize)
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.0
+            .get(i / 32)
+            .map_or(false, |limb| (limb >> (i % 32)) & 1 == 1)
+    }
+
+    /// Sets `self` to `self * factor + addend`.
+    fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = u64::from(addend);
+        for limb in self.0.iter_mut() {
+            let t = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = t as u32;
+            carry = t >> 32;
+        }
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// Divides `self` by `divisor` in place and returns the remainder.
+    fn div_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.0.iter_mut().rev() {
+            let t = (remainder << 32) | u64::from(*limb);
+            *limb = (t / u64::from(divisor)) as u32;
+            remainder = t % u64::from(divisor);
+        }
+        self.normalize();
+        remainder as u32
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut result = Vec::with_capacity(self.0.len().max(other.0.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let t = u64::from(self.0.get(i).copied().unwrap_or(0))
+                + u64::from(other.0.get(i).copied().unwrap_or(0))
+                + carry;
+            result.push(t as u32);
+            carry = t >> 32;
+        }
+        result.push(carry as u32);
+        let mut result = Self(result);
+        result.normalize();
+        result
+    }
+
+    /// Returns `self - other`, which must not be negative.
+    fn sub(&self, other: &Self) -> Self {
+        debug_assert!(*self >= *other);
+        let mut result = Vec::with_capacity(self.0.len());
+        let mut borrow = 0i64;
+        for (i, &limb) in self.0.iter().enumerate() {
+            let mut t = i64::from(limb) - i64::from(other.0.get(i).copied().unwrap_or(0)) - borrow;
+            borrow = 0;
+            if t < 0 {
+                t += 1 << 32;
+                borrow = 1;
+            }
+            result.push(t as u32);
+        }
+        let mut result = Self(result);
+        result.normalize();
+        result
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut result = vec![0u32; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            // The extra zero limb flushes the final carry into the next limb of the result.
+            for (&b, slot) in other
+                .0
+                .iter()
+                .chain(core::iter::once(&0))
+                .zip(result.iter_mut().skip(i))
+            {
+                let t = u64::from(a) * u64::from(b) + u64::from(*slot) + carry;
+                *slot = t as u32;
+                carry = t >> 32;
+            }
+        }
+        let mut result = Self(result);
+        result.normalize();
+        result
+    }
+
+    /// Returns the quotient and the remainder of `self / divisor`, using binary long division.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if let [small] = *divisor.0.as_slice() {
+            let mut quotient = self.clone();
+            let remainder = quotient.div_small(small);
+            return (quotient, Self::from_u64(remainder.into()));
+        }
+        let mut quotient = vec![0u32; self.0.len()];
+        let mut remainder = Self::default();
+        for i in (0..self.bits()).rev() {
+            remainder.mul_add_small(2, self.bit(i).into());
+            if remainder >= *divisor {
+                remainder = remainder.sub(divisor);
+                if let Some(limb) = quotient.get_mut(i / 32) {
+                    *limb |= 1 << (i % 32);
+                }
+            }
+        }
+        let mut quotient = Self(quotient);
+        quotient.normalize();
+        (quotient, remainder)
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Groups of nine decimal digits, least significant first.
+        let mut groups = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            groups.push(rest.div_small(1_000_000_000));
+        }
+        let mut groups = groups.iter().rev();
+        write!(f, "{}", groups.next().copied().unwrap_or(0))?;
+        for group in groups {
+            write!(f, "{group:09}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An exact rational number, used for unit conversions.
+///
+/// Ratios can be parsed from decimal numbers with an optional exponent, such as "0.3048" or
+/// "6.02214076E+23", and from fractions of those, such as "5/9".
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_dimension::units::Ratio;
+///
+/// let ratio: Ratio = "2298.35/9".parse().unwrap();
+/// assert_eq!(ratio.to_string(), "45967/180");
+///
+/// let third = Ratio::from(1) - &"2/3".parse::<Ratio>().unwrap();
+/// assert_eq!(third.to_fixed_decimal(-3).unwrap().to_string(), "0.333");
+///
+/// let value = FixedDecimal::from(-125).multiplied_pow10(-2);
+/// assert_eq!(Ratio::from(&value).to_string(), "-5/4");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    /// Whether the ratio is below zero; always false for zero.
+    is_negative: bool,
+    numerator: BigUint,
+    /// The denominator, which is positive and coprime with the numerator.
+    denominator: BigUint,
+}
+
+impl Ratio {
+    fn new(is_negative: bool, numerator: BigUint, denominator: BigUint) -> Self {
+        let gcd = numerator.gcd(&denominator);
+        let (numerator, denominator) = if gcd.is_zero() {
+            (numerator, denominator)
+        } else {
+            (numerator.div_rem(&gcd).0, denominator.div_rem(&gcd).0)
+        };
+        Self {
+            is_negative: is_negative && !numerator.is_zero(),
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Returns whether this ratio is zero.
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Returns whether this ratio is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Returns the absolute value of this ratio.
+    pub fn abs(&self) -> Self {
+        Self {
+            is_negative: false,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the integer part of this ratio, rounding toward zero.
+    pub fn trunc(&self) -> Self {
+        Self::new(
+            self.is_negative,
+            self.numerator.div_rem(&self.denominator).0,
+            BigUint::from_u64(1),
+        )
+    }
+
+    /// Returns the reciprocal of this ratio, or `None` if it is zero.
+    pub fn recip(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(Self {
+            is_negative: self.is_negative,
+            numerator: self.denominator.clone(),
+            denominator: self.numerator.clone(),
+        })
+    }
+
+    /// Returns this ratio raised to an integer power, or `None` if it is zero and the
+    /// exponent is negative.
+    pub fn pow(&self, exponent: i32) -> Option<Self> {
+        let base = if exponent < 0 {
+            self.recip()?
+        } else {
+            self.clone()
+        };
+        Some((0..exponent.unsigned_abs()).fold(Self::from(1), |result, _| result * &base))
+    }
+
+    /// Rounds this ratio half to even to the given position, and returns it as a
+    /// [`FixedDecimal`] with digits down to that position.
+    ///
+    /// Returns `None` if the result has more digits than [`FixedDecimal`] supports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_dimension::units::Ratio;
+    ///
+    /// let ratio: Ratio = "-1001/8".parse().unwrap();
+    /// assert_eq!(ratio.to_fixed_decimal(-2).unwrap().to_string(), "-125.12");
+    /// assert_eq!(ratio.to_fixed_decimal(1).unwrap().to_string(), "-130");
+    ///
+    /// // The result would have 32,801 digits.
+    /// let ratio: Ratio = "1E100".parse().unwrap();
+    /// assert_eq!(ratio.to_fixed_decimal(-32700), None);
+    /// ```
+    pub fn to_fixed_decimal(&self, position: i16) -> Option<FixedDecimal> {
+        let shift = BigUint::pow10(u32::from(position.unsigned_abs()));
+        let (numerator, denominator) = if position < 0 {
+            (self.numerator.mul(&shift), self.denominator.clone())
+        } else {
+            (self.numerator.clone(), self.denominator.mul(&shift))
+        };
+        let (mut quotient, remainder) = numerator.div_rem(&denominator);
+        let round_up = match remainder.add(&remainder).cmp(&denominator) {
+            Ordering::Greater => true,
+            Ordering::Equal => quotient.is_odd(),
+            Ordering::Less => false,
+        };
+        if round_up {
+            quotient.mul_add_small(1, 1);
+        }
+        // The decimal representation of an integer is always a valid FixedDecimal, unless it
+        // has more digits than FixedDecimal supports.
+        let mut result = FixedDecimal::from_str(&quotient.to_string()).ok()?;
+        // `multiply_pow10` clears the value if the highest magnitude overflows.
+        result.magnitude_range().end().checked_add(position)?;
+        result.multiply_pow10(position);
+        result.pad_end(position);
+        if self.is_negative && !quotient.is_zero() {
+            result.set_sign(Sign::Negative);
+        }
+        Some(result)
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl From<i64> for Ratio {
+    fn from(value: i64) -> Self {
+        Self {
+            is_negative: value < 0,
+            numerator: BigUint::from_u64(value.unsigned_abs()),
+            denominator: BigUint::from_u64(1),
+        }
+    }
+}
+
+impl From<&FixedDecimal> for Ratio {
+    fn from(value: &FixedDecimal) -> Self {
+        let range = value.magnitude_range();
+        let lowest = *range.start();
+        let mut numerator = BigUint::default();
+        for magnitude in range.rev() {
+            numerator.mul_add_small(10, value.digit_at(magnitude).into());
+        }
+        let shift = BigUint::pow10(u32::from(lowest.unsigned_abs()));
+        let (numerator, denominator) = if lowest < 0 {
+            (numerator, shift)
+        } else {
+            (numerator.mul(&shift), BigUint::from_u64(1))
+        };
+        Self::new(value.sign() == Sign::Negative, numerator, denominator)
+    }
+}
+
+impl FromStr for Ratio {
+    type Err = DimensionError;
+
+    /// Parses a decimal number with an optional exponent, or a fraction of two such numbers.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            let numerator: Ratio = numerator.parse()?;
+            let denominator: Ratio = denominator.parse()?;
+            return Ok(&numerator * &denominator.recip().ok_or(DimensionError::InvalidRatio)?);
+        }
+        let s = s.trim();
+        let (is_negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mantissa, exponent) = match s.split_once(['E', 'e']) {
+            Some((mantissa, exponent)) => (
+                mantissa,
+                exponent
+                    .strip_prefix('+')
+                    .unwrap_or(exponent)
+                    .parse::<i32>()
+                    .map_err(|_| DimensionError::InvalidRatio)?,
+            ),
+            None => (s, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(DimensionError::InvalidRatio);
+        }
+        let mut numerator = BigUint::default();
+        for b in integer.bytes().chain(fraction.bytes()) {
+            if !b.is_ascii_digit() {
+                return Err(DimensionError::InvalidRatio);
+            }
+            numerator.mul_add_small(10, u32::from(b - b'0'));
+        }
+        // Larger exponents are not needed for unit conversions, and would take unbounded
+        // time and memory to expand.
+        if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return Err(DimensionError::InvalidRatio);
+        }
+        let exponent = i32::try_from(fraction.len())
+            .ok()
+            .and_then(|len| exponent.checked_sub(len))
+            .ok_or(DimensionError::InvalidRatio)?;
+        let shift = BigUint::pow10(exponent.unsigned_abs());
+        let (numerator, denominator) = if exponent < 0 {
+            (numerator, shift)
+        } else {
+            (numerator.mul(&shift), BigUint::from_u64(1))
+        };
+        Ok(Self::new(is_negative, numerator, denominator))
+    }
+}
+
+impl fmt::Display for Ratio {
+    /// Writes the ratio as an integer, such as "-3", or a fraction, such as "5/9".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.numerator)?;
+        if self.denominator != BigUint::from_u64(1) {
+            write!(f, "/{}", self.denominator)?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative, other.is_negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (is_negative, _) => {
+                let ordering = self
+                    .numerator
+                    .mul(&other.denominator)
+                    .cmp(&other.numerator.mul(&self.denominator));
+                if is_negative {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Mul<&Ratio> for &Ratio {
+    type Output = Ratio;
+
+    fn mul(self, rhs: &Ratio) -> Ratio {
+        Ratio::new(
+            self.is_negative != rhs.is_negative,
+            self.numerator.mul(&rhs.numerator),
+            self.denominator.mul(&rhs.denominator),
+        )
+    }
+}
+
+impl Add<&Ratio> for &Ratio {
+    type Output = Ratio;
+
+    fn add(self, rhs: &Ratio) -> Ratio {
+        let a = self.numerator.mul(&rhs.denominator);
+        let b = rhs.numerator.mul(&self.denominator);
+        let denominator = self.denominator.mul(&rhs.denominator);
+        if self.is_negative == rhs.is_negative {
+            Ratio::new(self.is_negative, a.add(&b), denominator)
+        } else if a >= b {
+            Ratio::new(self.is_negative, a.sub(&b), denominator)
+        } else {
+            Ratio::new(rhs.is_negative, b.sub(&a), denominator)
+        }
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Ratio;
+
+    fn neg(self) -> Ratio {
+        Ratio {
+            is_negative: !self.is_negative && !self.is_zero(),
+            ..self
+        }
+    }
+}
+
+impl Sub<&Ratio> for &Ratio {
+    type Output = Ratio;
+
+    fn sub(self, rhs: &Ratio) -> Ratio {
+        self + &-rhs.clone()
+    }
+}
+
+impl Mul<&Ratio> for Ratio {
+    type Output = Ratio;
+
+    fn mul(self, rhs: &Ratio) -> Ratio {
+        &self * rhs
+    }
+}
+
+impl Add<&Ratio> for Ratio {
+    type Output = Ratio;
+
+    fn add(self, rhs: &Ratio) -> Ratio {
+        &self + rhs
+    }
+}
+
+impl Sub<&Ratio> for Ratio {
+    type Output = Ratio;
+
+    fn sub(self, rhs: &Ratio) -> Ratio {
+        &self - rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigUint, Ratio};
+
+    #[test]
+    fn test_big_uint() {
+        let a: Ratio = "123456789012345678901234567890".parse().unwrap();
+        let b: Ratio = "987654321098765432109876543210".parse().unwrap();
+        assert_eq!(
+            (&a * &b).to_string(),
+            "121932631137021795226185032733622923332237463801111263526900"
+        );
+        assert_eq!((&a - &b).to_string(), "-864197532086419753208641975320");
+        let (q, r) = b.numerator.div_rem(&a.numerator);
+        assert_eq!(
+            (q.to_string(), r.to_string()),
+            ("8".into(), "9000000000900000000090".into())
+        );
+        assert_eq!(BigUint::pow10(20).to_string(), "100000000000000000000");
+        assert_eq!(BigUint::default().to_string(), "0");
+    }
+
+    #[test]
+    fn test_parse() {
+        #[rustfmt::skip]
+        let cases = [
+            ("0.3048", "381/1250"),
+            ("1.49241808560E-10", "1865522607/12500000000000000000"),
+            ("6.3781E6", "6378100"),
+            ("411557987 / 131002976", "411557987/131002976"),
+            ("-0.5", "-1/2"),
+            ("-0", "0"),
+        ];
+        for (input, expected) in cases {
+            let ratio: Ratio = input.parse().unwrap();
+            assert_eq!(ratio.to_string(), expected, "{input}");
+        }
+        for input in ["", ".", "1/0", "1.2.3", "E5", "x", "1E101", "1e-2000000000"] {
+            assert!(input.parse::<Ratio>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_to_fixed_decimal() {
+        let ratio: Ratio = "1E100".parse().unwrap();
+        assert_eq!(ratio.to_fixed_decimal(0).unwrap().to_string().len(), 101);
+        assert_eq!(ratio.to_fixed_decimal(-32700), None);
+        let ratio: Ratio = "1E-100".parse().unwrap();
+        assert!(!ratio.to_fixed_decimal(-32700).unwrap().is_zero());
+        assert!(ratio.to_fixed_decimal(-99).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_trunc() {
+        for (input, expected) in [("7/2", "3"), ("-7/2", "-3"), ("-1/2", "0"), ("4", "4")] {
+            let ratio: Ratio = input.parse().unwrap();
+            assert_eq!(ratio.trunc().to_string(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let values: [Ratio; 4] = ["-2", "-1/3", "0", "1/3"].map(|s| s.parse().unwrap());
+        for pair in values.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+}
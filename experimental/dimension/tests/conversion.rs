@@ -0,0 +1,132 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_dimension::units::{ConverterFactory, Ratio, UnitPreferences};
+use icu_locid::subtags_region as region;
+use std::str::FromStr;
+
+fn convert(value: &str, input: &str, output: &str, position: i16) -> Option<String> {
+    let factory = ConverterFactory::try_new_unstable(&icu_testdata::unstable()).unwrap();
+    let converter = factory.converter(&input.parse().unwrap(), &output.parse().unwrap())?;
+    let value = FixedDecimal::from_str(value).unwrap();
+    converter
+        .convert_fixed_decimal(&value, position)
+        .map(|value| value.to_string())
+}
+
+#[test]
+fn test_conversions() {
+    #[rustfmt::skip]
+    let cases = [
+        ("1", "foot", "meter", -4, "0.3048"),
+        ("1", "mile", "kilometer", -6, "1.609344"),
+        ("1", "square-mile", "acre", 0, "640"),
+        ("1", "cubic-foot", "liter", -3, "28.317"),
+        ("1", "kibibyte", "bit", 0, "8192"),
+        ("2.5", "megawatt-hour", "gigajoule", -1, "9.0"),
+        ("100", "celsius", "fahrenheit", 0, "212"),
+        ("-40", "fahrenheit", "celsius", 0, "-40"),
+        ("0", "kelvin", "celsius", -2, "-273.15"),
+        ("10", "meter-per-square-second", "g-force", -4, "1.0197"),
+        ("1", "pound-force-foot", "newton-meter", -3, "1.356"),
+        // Fuel consumption is converted between reciprocal units.
+        ("30", "mile-per-gallon", "liter-per-100-kilometer", -2, "7.84"),
+        ("5", "liter-per-100-kilometer", "mile-per-gallon-imperial", -1, "56.5"),
+    ];
+    for (value, input, output, position, expected) in cases {
+        assert_eq!(
+            convert(value, input, output, position).as_deref(),
+            Some(expected),
+            "{value} {input} in {output}"
+        );
+    }
+}
+
+#[test]
+fn test_inconvertible() {
+    for (input, output) in [
+        ("meter", "second"),
+        ("meter-per-second", "meter-per-square-second"),
+        ("celsius", "joule"),
+        ("zorkmid", "meter"),
+        ("kilometer-and-meter", "meter"),
+    ] {
+        assert_eq!(convert("1", input, output, 0), None, "{input} in {output}");
+    }
+}
+
+#[test]
+fn test_preferences() {
+    let preferences = UnitPreferences::try_new_unstable(&icu_testdata::unstable()).unwrap();
+    let centimeter = "centimeter".parse().unwrap();
+
+    // Person heights in the United States are given in feet and inches, but small heights
+    // in inches.
+    let preferred = preferences
+        .convert(
+            &Ratio::from(180),
+            &centimeter,
+            "person-height",
+            region!("US"),
+        )
+        .unwrap();
+    assert_eq!(preferred.unit.as_str(), "foot-and-inch");
+    assert_eq!(
+        preferred.value.to_fixed_decimal(-3).unwrap().to_string(),
+        "5.906"
+    );
+    let preferred = preferences
+        .convert(
+            &Ratio::from(50),
+            &centimeter,
+            "person-height",
+            region!("US"),
+        )
+        .unwrap();
+    assert_eq!(preferred.unit.as_str(), "inch");
+
+    // Regions and usages without preferences fall back to "001" and "default".
+    let preferred = preferences
+        .convert(
+            &Ratio::from(180),
+            &centimeter,
+            "person-height",
+            region!("DE"),
+        )
+        .unwrap();
+    assert_eq!(preferred.unit.as_str(), "centimeter");
+    let preferred = preferences
+        .convert(
+            &Ratio::from(180),
+            &centimeter,
+            "unknown-usage",
+            region!("US"),
+        )
+        .unwrap();
+    assert_eq!(preferred.unit.as_str(), "foot");
+
+    // Road distances come with a rounding increment.
+    let preferred = preferences
+        .convert(
+            &Ratio::from(500),
+            &"meter".parse().unwrap(),
+            "road",
+            region!("GB"),
+        )
+        .unwrap();
+    assert_eq!(preferred.unit.as_str(), "yard");
+    assert_eq!(preferred.precision_increment, Some(Ratio::from(50)));
+}
+
+#[test]
+fn test_overflow() {
+    // Rounded to 32,767 fraction digits, 100 feet in meters has more digits than
+    // FixedDecimal supports.
+    assert_eq!(
+        convert("1", "foot", "meter", -32767).map(|value| value.len()),
+        Some(32769)
+    );
+    assert_eq!(convert("100", "foot", "meter", -32767), None);
+}
@@ -0,0 +1,72 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_dimension::units::options::{MixedUnitsFormatterOptions, Width};
+use icu_dimension::units::MixedUnitsFormatter;
+use icu_dimension::DimensionError;
+use icu_locid::{locale, Locale};
+use std::str::FromStr;
+use writeable::assert_writeable_eq;
+
+fn formatter(
+    locale: Locale,
+    unit: &str,
+    width: Width,
+    fraction_digits: u8,
+) -> Result<MixedUnitsFormatter, DimensionError> {
+    let mut options = MixedUnitsFormatterOptions::default();
+    options.width = width;
+    options.fraction_digits = fraction_digits;
+    MixedUnitsFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale.into(),
+        unit.parse()?,
+        options,
+    )
+}
+
+#[test]
+fn test_rounding() {
+    let fmt = formatter(locale!("en"), "foot-and-inch", Width::Short, 1).unwrap();
+    for (value, expected) in [
+        ("5.99", "5 ft, 11.9 in"),
+        ("5.999", "6 ft, 0.0 in"),
+        ("0.5", "0 ft, 6.0 in"),
+        ("-0.001", "0 ft, 0.0 in"),
+    ] {
+        let value = FixedDecimal::from_str(value).unwrap();
+        assert_writeable_eq!(fmt.format(&value).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_plurals() {
+    let fmt = formatter(locale!("en"), "hour-and-minute-and-second", Width::Long, 0).unwrap();
+    let hours = FixedDecimal::from_str("1.0172222").unwrap();
+    assert_writeable_eq!(fmt.format(&hours).unwrap(), "1 hour, 1 minute, 2 seconds");
+
+    let fmt = formatter(locale!("fr"), "meter-and-centimeter", Width::Long, 0).unwrap();
+    let value = FixedDecimal::from_str("2.01").unwrap();
+    assert_writeable_eq!(
+        fmt.format(&value).unwrap(),
+        "2\u{a0}mètres et 1\u{a0}centimètre"
+    );
+}
+
+#[test]
+fn test_errors() {
+    for (unit, error) in [
+        ("foot-and-second", DimensionError::IncompatibleUnits),
+        ("fahrenheit-and-celsius", DimensionError::IncompatibleUnits),
+        ("foot-and-zorkmid", DimensionError::UnknownUnit),
+        ("foot-and-", DimensionError::InvalidUnit),
+    ] {
+        assert_eq!(
+            formatter(locale!("en"), unit, Width::Short, 0).err(),
+            Some(error),
+            "{unit}"
+        );
+    }
+}
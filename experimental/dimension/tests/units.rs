@@ -0,0 +1,50 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::FixedDecimal;
+use icu_dimension::units::options::{UnitsFormatterOptions, Width};
+use icu_dimension::units::UnitsFormatter;
+use icu_dimension::DimensionError;
+use icu_locid::locale;
+use writeable::assert_writeable_eq;
+
+fn formatter(unit: &str, width: Width) -> Result<UnitsFormatter, DimensionError> {
+    let mut options = UnitsFormatterOptions::default();
+    options.width = width;
+    UnitsFormatter::try_new_unstable(
+        &icu_testdata::unstable(),
+        &locale!("en").into(),
+        unit.parse()?,
+        options,
+    )
+}
+
+#[test]
+fn test_compound_units() {
+    let value = FixedDecimal::from(3);
+    // The denominator has a per-unit pattern.
+    let fmt = formatter("gigabyte-per-second", Width::Long).unwrap();
+    assert_writeable_eq!(fmt.format(&value), "3 gigabytes per second");
+    // The denominator has no per-unit pattern, so the compound pattern is used.
+    let fmt = formatter("gigabyte-per-mile", Width::Long).unwrap();
+    assert_writeable_eq!(fmt.format(&value), "3 gigabytes per mile");
+    let fmt = formatter("gigabyte-per-mile", Width::Short).unwrap();
+    assert_writeable_eq!(fmt.format(&value), "3 GB/mi");
+}
+
+#[test]
+fn test_unknown_units() {
+    assert!(matches!(
+        formatter("furlong", Width::Long),
+        Err(DimensionError::UnknownUnit)
+    ));
+    assert!(matches!(
+        formatter("meter-per-furlong", Width::Long),
+        Err(DimensionError::UnknownUnit)
+    ));
+    assert!(matches!(
+        formatter("meter--second", Width::Long),
+        Err(DimensionError::InvalidUnit)
+    ));
+}
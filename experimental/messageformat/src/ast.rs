@@ -0,0 +1,171 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The data model of MessageFormat 2 messages.
+//!
+//! A [`Message`] is produced by parsing a message with [`str::parse`], and is written back in
+//! the message syntax by its [`Writeable`](writeable::Writeable) and [`Display`](core::fmt::Display)
+//! implementations.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_messageformat::ast::*;
+//!
+//! let message: Message = "{Hello, {$name}!}".parse().expect("The message is valid");
+//!
+//! assert_eq!(
+//!     message,
+//!     Message {
+//!         declarations: vec![],
+//!         body: Body::Pattern(Pattern {
+//!             parts: vec![
+//!                 PatternPart::Text("Hello, ".into()),
+//!                 PatternPart::Expression(Expression {
+//!                     operand: Some(Operand::Variable("name".into())),
+//!                     annotation: None,
+//!                 }),
+//!                 PatternPart::Text("!".into()),
+//!             ],
+//!         }),
+//!     }
+//! );
+//! assert_eq!(message.to_string(), "{Hello, {$name}!}");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A complete message: a list of [`Declaration`]s followed by a [`Body`].
+///
+/// # Examples
+///
+/// ```
+/// use icu_messageformat::ast::Message;
+///
+/// let message: Message = "let $count = {$items :number} {You have {$count} items.}"
+///     .parse()
+///     .expect("The message is valid");
+///
+/// assert_eq!(message.declarations.len(), 1);
+/// assert_eq!(message.declarations[0].variable, "count");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Message {
+    /// The `let` declarations of local variables, in order.
+    pub declarations: Vec<Declaration>,
+    /// The pattern, or the selectors and variants, of the message.
+    pub body: Body,
+}
+
+/// A declaration of a local variable, such as `let $count = {$items :number}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Declaration {
+    /// The name of the variable, without the `$`.
+    pub variable: String,
+    /// The value of the variable.
+    pub expression: Expression,
+}
+
+/// The body of a [`Message`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // part of the MessageFormat 2 data model
+pub enum Body {
+    /// A single pattern, such as `{Hello, {$name}!}`.
+    Pattern(Pattern),
+    /// A selection of a pattern, such as `match {$count :plural} when one {…} when * {…}`.
+    Select(Select),
+}
+
+/// The selectors and variants of a [`Message`] that selects one of several patterns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Select {
+    /// The expressions that the keys of the variants are matched against.
+    pub selectors: Vec<Expression>,
+    /// The variants, in order. Each has one key per selector.
+    pub variants: Vec<Variant>,
+}
+
+/// A variant of a [`Select`], such as `when one {You have one item.}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Variant {
+    /// The keys, one per selector.
+    pub keys: Vec<VariantKey>,
+    /// The pattern that is formatted if the variant is selected.
+    pub pattern: Pattern,
+}
+
+/// A key of a [`Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // part of the MessageFormat 2 data model
+pub enum VariantKey {
+    /// A key that matches a value, such as `one` or `|1|`.
+    Literal(String),
+    /// The catch-all key `*`.
+    Wildcard,
+}
+
+/// A pattern: text with placeholders, such as `{Hello, {$name}!}`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Pattern {
+    /// The text and placeholders of the pattern, in order.
+    pub parts: Vec<PatternPart>,
+}
+
+/// A part of a [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // part of the MessageFormat 2 data model
+pub enum PatternPart {
+    /// Literal text, with escapes resolved.
+    Text(String),
+    /// A placeholder, such as `{$count :number}`.
+    Expression(Expression),
+}
+
+/// An expression, such as `{$count :number maximumFractionDigits=2}`.
+///
+/// An expression has an operand, an annotation, or both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Expression {
+    /// The value of the expression.
+    pub operand: Option<Operand>,
+    /// The function that is applied to the value.
+    pub annotation: Option<Annotation>,
+}
+
+/// The operand of an [`Expression`], or the value of a [`FunctionOption`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)] // part of the MessageFormat 2 data model
+pub enum Operand {
+    /// A literal, such as `|1.5|`, with escapes resolved.
+    Literal(String),
+    /// A variable, such as `$count`. The name does not include the `$`.
+    Variable(String),
+}
+
+/// A function and its options, such as `:number maximumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct Annotation {
+    /// The name of the function, without the `:`.
+    pub function: String,
+    /// The options of the function, in order.
+    pub options: Vec<FunctionOption>,
+}
+
+/// An option of an [`Annotation`], such as `maximumFractionDigits=2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_structs)] // part of the MessageFormat 2 data model
+pub struct FunctionOption {
+    /// The name of the option.
+    pub name: String,
+    /// The value of the option.
+    pub value: Operand,
+}
@@ -0,0 +1,78 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_datetime::DateTimeError;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in the `icu_messageformat` crate.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MessageFormatError {
+    /// The message is not syntactically valid. The value is the byte offset of the error.
+    #[displaydoc("Syntax error at byte {0}")]
+    Syntax(usize),
+    /// A variable is declared more than once.
+    #[displaydoc("Duplicate declaration")]
+    DuplicateDeclaration,
+    /// A variant does not have one key per selector.
+    #[displaydoc("Variant key count does not match the selector count")]
+    VariantKeyMismatch,
+    /// No variant has only `*` keys.
+    #[displaydoc("Missing fallback variant")]
+    MissingFallbackVariant,
+    /// A selector has no function to select with.
+    #[displaydoc("Missing selector annotation")]
+    MissingSelectorAnnotation,
+    /// A selector uses a function that cannot select, such as `:number`.
+    #[displaydoc("Function cannot be used as a selector")]
+    InvalidSelector,
+    /// The message uses a function that is not supported.
+    #[displaydoc("Unknown function")]
+    UnknownFunction,
+    /// A function has an option that it does not support, or an invalid option value.
+    #[displaydoc("Invalid function option")]
+    InvalidOption,
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// An error originating from [`DateTimeFormatter`](icu_datetime::DateTimeFormatter).
+    #[displaydoc("Error loading DateTimeFormatter: {0}")]
+    DateTime(DateTimeError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MessageFormatError {}
+
+impl From<PluralsError> for MessageFormatError {
+    fn from(e: PluralsError) -> Self {
+        MessageFormatError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for MessageFormatError {
+    fn from(e: DataError) -> Self {
+        MessageFormatError::Data(e)
+    }
+}
+
+impl From<DecimalError> for MessageFormatError {
+    fn from(e: DecimalError) -> Self {
+        MessageFormatError::Decimal(e)
+    }
+}
+
+impl From<DateTimeError> for MessageFormatError {
+    fn from(e: DateTimeError) -> Self {
+        MessageFormatError::DateTime(e)
+    }
+}
@@ -0,0 +1,1085 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::ast;
+use crate::serializer::write_expression;
+use crate::MessageFormatError;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::FixedDecimal;
+use icu_calendar::provider::{
+    JapaneseErasV1Marker, JapaneseExtendedErasV1Marker, WeekDataV1Marker,
+};
+use icu_calendar::{AnyCalendar, DateTime};
+use icu_datetime::options::length;
+use icu_datetime::provider::calendar::*;
+use icu_datetime::DateTimeFormatter;
+use icu_decimal::options::{
+    FixedDecimalFormatterOptions, GroupingStrategy, RoundingMode, SignDisplay,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+/// The [`Part`](writeable::Part)s of a [`FormattedMessage`].
+pub mod parts {
+    use writeable::Part;
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark the text of
+    /// the pattern.
+    pub const LITERAL: Part = Part {
+        category: "messageformat",
+        value: "literal",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a placeholder
+    /// formatted as a number.
+    pub const NUMBER: Part = Part {
+        category: "messageformat",
+        value: "number",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a placeholder
+    /// formatted as a date or time.
+    pub const DATETIME: Part = Part {
+        category: "messageformat",
+        value: "datetime",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a placeholder
+    /// whose value is a string.
+    pub const STRING: Part = Part {
+        category: "messageformat",
+        value: "string",
+    };
+
+    /// The [`Part`] used by [`FormattedMessage`](super::FormattedMessage) to mark a placeholder
+    /// that could not be formatted, such as `{$name}` if there is no argument `name`.
+    pub const FALLBACK: Part = Part {
+        category: "messageformat",
+        value: "fallback",
+    };
+}
+
+/// The value of an argument of a message.
+#[non_exhaustive]
+pub enum MessageValue<'a> {
+    /// A string, which can be formatted as is, or selected on with `:select`.
+    String(Cow<'a, str>),
+    /// A number, which can be formatted with `:number` and selected on with `:plural`.
+    Number(FixedDecimal),
+    /// A date and time, which can be formatted with `:datetime`.
+    DateTime(DateTime<AnyCalendar>),
+}
+
+impl<'a> From<&'a str> for MessageValue<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::String(Cow::Borrowed(value))
+    }
+}
+
+impl From<String> for MessageValue<'_> {
+    fn from(value: String) -> Self {
+        Self::String(Cow::Owned(value))
+    }
+}
+
+impl From<FixedDecimal> for MessageValue<'_> {
+    fn from(value: FixedDecimal) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i64> for MessageValue<'_> {
+    fn from(value: i64) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<DateTime<AnyCalendar>> for MessageValue<'_> {
+    fn from(value: DateTime<AnyCalendar>) -> Self {
+        Self::DateTime(value)
+    }
+}
+
+/// A built-in function, with its options resolved.
+enum Function {
+    /// `:number`, formatting with the decimal formatter at the index.
+    Number(usize),
+    /// `:plural`, selecting with the plural rules of the type and formatting with the decimal
+    /// formatter at the index.
+    Plural {
+        rule_type: PluralRuleType,
+        decimal: usize,
+        min_fraction_digits: Option<u8>,
+    },
+    /// `:select`, selecting on the string value.
+    Select,
+    /// `:datetime`, formatting with the date time formatter at the index.
+    DateTime(usize),
+}
+
+struct CompiledExpression {
+    operand: Option<ast::Operand>,
+    function: Option<Function>,
+    /// The text written if the expression cannot be formatted, such as `{$name}`.
+    fallback: String,
+}
+
+enum CompiledPart {
+    Text(String),
+    Expression(CompiledExpression),
+}
+
+enum CompiledBody {
+    Pattern(Vec<CompiledPart>),
+    Select {
+        selectors: Vec<CompiledExpression>,
+        variants: Vec<(Vec<ast::VariantKey>, Vec<CompiledPart>)>,
+    },
+}
+
+/// The formatters that the functions of a message need.
+struct Requirements {
+    decimal: Vec<FixedDecimalFormatterOptions>,
+    datetime: Vec<length::Bag>,
+    cardinal: bool,
+    ordinal: bool,
+}
+
+impl Requirements {
+    fn new() -> Self {
+        Self {
+            // Numbers without a function use the default options.
+            decimal: vec![Default::default()],
+            datetime: vec![],
+            cardinal: false,
+            ordinal: false,
+        }
+    }
+
+    fn decimal(&mut self, options: FixedDecimalFormatterOptions) -> usize {
+        self.decimal
+            .iter()
+            .position(|o| *o == options)
+            .unwrap_or_else(|| {
+                self.decimal.push(options);
+                self.decimal.len() - 1
+            })
+    }
+
+    fn datetime(&mut self, bag: length::Bag) -> usize {
+        self.datetime
+            .iter()
+            .position(|b| *b == bag)
+            .unwrap_or_else(|| {
+                self.datetime.push(bag);
+                self.datetime.len() - 1
+            })
+    }
+
+    fn compile_pattern(
+        &mut self,
+        pattern: &ast::Pattern,
+    ) -> Result<Vec<CompiledPart>, MessageFormatError> {
+        pattern
+            .parts
+            .iter()
+            .map(|part| match part {
+                ast::PatternPart::Text(text) => Ok(CompiledPart::Text(text.clone())),
+                ast::PatternPart::Expression(expression) => Ok(CompiledPart::Expression(
+                    self.compile_expression(expression)?,
+                )),
+            })
+            .collect()
+    }
+
+    fn compile_expression(
+        &mut self,
+        expression: &ast::Expression,
+    ) -> Result<CompiledExpression, MessageFormatError> {
+        let function = expression
+            .annotation
+            .as_ref()
+            .map(|annotation| self.compile_function(annotation))
+            .transpose()?;
+        let mut fallback = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = write_expression(
+            &ast::Expression {
+                operand: expression.operand.clone(),
+                annotation: match (&expression.operand, &expression.annotation) {
+                    (None, Some(annotation)) => Some(ast::Annotation {
+                        function: annotation.function.clone(),
+                        options: vec![],
+                    }),
+                    _ => None,
+                },
+            },
+            &mut fallback,
+        );
+        Ok(CompiledExpression {
+            operand: expression.operand.clone(),
+            function,
+            fallback,
+        })
+    }
+
+    fn compile_function(
+        &mut self,
+        annotation: &ast::Annotation,
+    ) -> Result<Function, MessageFormatError> {
+        let options = annotation.options.iter().map(|option| {
+            match &option.value {
+                ast::Operand::Literal(value) => Ok((option.name.as_str(), value.as_str())),
+                // Options change which data is loaded, so they must be known in advance.
+                ast::Operand::Variable(_) => Err(MessageFormatError::InvalidOption),
+            }
+        });
+        let digits = |value: &str| value.parse().map_err(|_| MessageFormatError::InvalidOption);
+
+        match annotation.function.as_str() {
+            "number" => {
+                let mut decimal_options = FixedDecimalFormatterOptions::default();
+                for option in options {
+                    match option? {
+                        ("minimumIntegerDigits", value) => {
+                            decimal_options.min_integer_digits = Some(digits(value)?)
+                        }
+                        ("minimumFractionDigits", value) => {
+                            decimal_options.min_fraction_digits = Some(digits(value)?)
+                        }
+                        ("maximumFractionDigits", value) => {
+                            decimal_options.max_fraction_digits = Some(digits(value)?)
+                        }
+                        ("minimumSignificantDigits", value) => {
+                            decimal_options.min_significant_digits = Some(digits(value)?)
+                        }
+                        ("maximumSignificantDigits", value) => {
+                            decimal_options.max_significant_digits = Some(digits(value)?)
+                        }
+                        ("useGrouping", value) => {
+                            decimal_options.grouping_strategy = match value {
+                                "auto" => GroupingStrategy::Auto,
+                                "always" => GroupingStrategy::Always,
+                                "min2" => GroupingStrategy::Min2,
+                                "never" => GroupingStrategy::Never,
+                                _ => return Err(MessageFormatError::InvalidOption),
+                            }
+                        }
+                        ("signDisplay", value) => {
+                            decimal_options.sign_display = match value {
+                                "auto" => SignDisplay::Auto,
+                                "always" => SignDisplay::Always,
+                                "exceptZero" => SignDisplay::ExceptZero,
+                                "negative" => SignDisplay::Negative,
+                                "never" => SignDisplay::Never,
+                                _ => return Err(MessageFormatError::InvalidOption),
+                            }
+                        }
+                        ("roundingMode", value) => {
+                            decimal_options.rounding_mode = match value {
+                                "ceil" => RoundingMode::Ceil,
+                                "floor" => RoundingMode::Floor,
+                                "expand" => RoundingMode::Expand,
+                                "trunc" => RoundingMode::Trunc,
+                                "halfCeil" => RoundingMode::HalfCeil,
+                                "halfFloor" => RoundingMode::HalfFloor,
+                                "halfExpand" => RoundingMode::HalfExpand,
+                                "halfTrunc" => RoundingMode::HalfTrunc,
+                                "halfEven" => RoundingMode::HalfEven,
+                                _ => return Err(MessageFormatError::InvalidOption),
+                            }
+                        }
+                        _ => return Err(MessageFormatError::InvalidOption),
+                    }
+                }
+                Ok(Function::Number(self.decimal(decimal_options)))
+            }
+            "plural" => {
+                let mut rule_type = PluralRuleType::Cardinal;
+                let mut min_fraction_digits = None;
+                for option in options {
+                    match option? {
+                        ("type", "cardinal") => rule_type = PluralRuleType::Cardinal,
+                        ("type", "ordinal") => rule_type = PluralRuleType::Ordinal,
+                        ("minimumFractionDigits", value) => {
+                            min_fraction_digits = Some(digits(value)?)
+                        }
+                        _ => return Err(MessageFormatError::InvalidOption),
+                    }
+                }
+                if rule_type == PluralRuleType::Ordinal {
+                    self.ordinal = true;
+                } else {
+                    self.cardinal = true;
+                }
+                let mut decimal_options = FixedDecimalFormatterOptions::default();
+                decimal_options.min_fraction_digits = min_fraction_digits;
+                Ok(Function::Plural {
+                    rule_type,
+                    decimal: self.decimal(decimal_options),
+                    min_fraction_digits,
+                })
+            }
+            "select" => {
+                if annotation.options.is_empty() {
+                    Ok(Function::Select)
+                } else {
+                    Err(MessageFormatError::InvalidOption)
+                }
+            }
+            "datetime" => {
+                let mut date = None;
+                let mut time = None;
+                for option in options {
+                    match option? {
+                        ("dateStyle", value) => {
+                            date = Some(match value {
+                                "full" => length::Date::Full,
+                                "long" => length::Date::Long,
+                                "medium" => length::Date::Medium,
+                                "short" => length::Date::Short,
+                                _ => return Err(MessageFormatError::InvalidOption),
+                            })
+                        }
+                        ("timeStyle", value) => {
+                            time = Some(match value {
+                                "full" => length::Time::Full,
+                                "long" => length::Time::Long,
+                                "medium" => length::Time::Medium,
+                                "short" => length::Time::Short,
+                                _ => return Err(MessageFormatError::InvalidOption),
+                            })
+                        }
+                        _ => return Err(MessageFormatError::InvalidOption),
+                    }
+                }
+                let bag = match (date, time) {
+                    (Some(date), Some(time)) => length::Bag::from_date_time_style(date, time),
+                    (Some(date), None) => length::Bag::from_date_style(date),
+                    (None, Some(time)) => length::Bag::from_time_style(time),
+                    (None, None) => {
+                        length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short)
+                    }
+                };
+                Ok(Function::DateTime(self.datetime(bag)))
+            }
+            _ => Err(MessageFormatError::UnknownFunction),
+        }
+    }
+}
+
+/// Returns the function of a selector, which is either its own, or that of the declaration of
+/// its variable.
+fn selector_function<'a>(
+    declarations: &'a [(String, CompiledExpression)],
+    expression: &'a CompiledExpression,
+) -> Option<&'a Function> {
+    if let Some(function) = &expression.function {
+        return Some(function);
+    }
+    if let Some(ast::Operand::Variable(name)) = &expression.operand {
+        let i = declarations.iter().rposition(|(n, _)| n == name)?;
+        let (before, declaration) = declarations.split_at(i);
+        return selector_function(before, &declaration.first()?.1);
+    }
+    None
+}
+
+/// A resolved value, with the function that applies to it.
+struct Resolved<'a> {
+    value: ValueRef<'a>,
+    function: Option<&'a Function>,
+}
+
+enum ValueRef<'a> {
+    String(&'a str),
+    Number(Cow<'a, FixedDecimal>),
+    DateTime(&'a DateTime<AnyCalendar>),
+}
+
+impl<'a> ValueRef<'a> {
+    /// Returns the value as a number, parsing strings such as literals.
+    fn to_decimal(&self) -> Option<Cow<'a, FixedDecimal>> {
+        match self {
+            ValueRef::String(value) => value.parse().ok().map(Cow::Owned),
+            ValueRef::Number(value) => Some(value.clone()),
+            ValueRef::DateTime(_) => None,
+        }
+    }
+}
+
+/// The value of a selector, against which the keys of the variants are matched.
+enum SelectorValue<'a> {
+    Plural {
+        value: FixedDecimal,
+        category: PluralCategory,
+    },
+    String(Cow<'a, str>),
+    /// The selector could not be resolved, so only `*` matches.
+    Failed,
+}
+
+impl SelectorValue<'_> {
+    /// Returns how well a key matches: `0` for an exact match, `1` for a plural category, and
+    /// `2` for `*`. Lower is better.
+    fn match_key(&self, key: &ast::VariantKey) -> Option<u8> {
+        let key = match key {
+            ast::VariantKey::Wildcard => return Some(2),
+            ast::VariantKey::Literal(key) => key,
+        };
+        match self {
+            SelectorValue::Plural { value, category } => {
+                let trimmed = |value: FixedDecimal| value.trimmed_start().trimmed_end();
+                if key
+                    .parse::<FixedDecimal>()
+                    .map_or(false, |key| trimmed(key) == trimmed(value.clone()))
+                {
+                    Some(0)
+                } else if PluralCategory::get_for_cldr_string(key) == Some(*category) {
+                    Some(1)
+                } else {
+                    None
+                }
+            }
+            SelectorValue::String(value) => (key == value).then(|| 0),
+            SelectorValue::Failed => None,
+        }
+    }
+}
+
+/// A formatter for messages in the syntax of [MessageFormat 2].
+///
+/// The message is parsed into an [`ast::Message`] with [`str::parse`], and the data for its
+/// functions is loaded when the formatter is constructed. The following functions are
+/// supported:
+///
+/// - `:number` formats a number with [`FixedDecimalFormatter`]. It supports the options
+///   `minimumIntegerDigits`, `minimumFractionDigits`, `maximumFractionDigits`,
+///   `minimumSignificantDigits`, `maximumSignificantDigits`, `useGrouping` (`auto`, `always`,
+///   `min2`, `never`), `signDisplay` (`auto`, `always`, `exceptZero`, `negative`, `never`) and
+///   `roundingMode` (such as `halfEven`).
+/// - `:plural` selects on the plural category of a number with [`PluralRules`], and formats
+///   it as a number. It supports the options `type` (`cardinal`, `ordinal`) and
+///   `minimumFractionDigits`. Keys that are numbers, such as `1`, match that exact value, and
+///   are preferred over keys that are plural categories, such as `one`.
+/// - `:select` selects on a string.
+/// - `:datetime` formats a date with [`DateTimeFormatter`]. It supports the options
+///   `dateStyle` and `timeStyle` (`full`, `long`, `medium`, `short`), and defaults to a medium
+///   date with a short time.
+///
+/// Options must be literals, since they determine which data is loaded. Values without a
+/// function are formatted as is if they are strings, and with the default options if they are
+/// numbers.
+///
+/// Placeholders that cannot be formatted, for example because an argument is missing or
+/// has the wrong type, are written as their fallback representation, such as `{$name}`.
+/// If a selector cannot be resolved, only `*` keys match it.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid::locale;
+/// use icu_messageformat::MessageFormatter;
+///
+/// let message = "match {$count :plural}
+///     when 0 {Вы не получили сообщений.}
+///     when one {Вы получили {$count} сообщение.}
+///     when few {Вы получили {$count} сообщения.}
+///     when * {Вы получили {$count} сообщений.}";
+///
+/// let formatter = MessageFormatter::try_new_unstable(
+///     &icu_testdata::unstable(),
+///     &locale!("ru").into(),
+///     message.parse().expect("The message is valid"),
+/// )
+/// .expect("Data should load successfully");
+///
+/// for (count, expected) in [
+///     (0, "Вы не получили сообщений."),
+///     (21, "Вы получили 21 сообщение."),
+///     (34, "Вы получили 34 сообщения."),
+///     (11, "Вы получили 11 сообщений."),
+/// ] {
+///     assert_eq!(
+///         formatter.format_to_string(&[("count", count.into())]),
+///         expected
+///     );
+/// }
+/// ```
+///
+/// [MessageFormat 2]: https://github.com/unicode-org/message-format-wg
+pub struct MessageFormatter {
+    declarations: Vec<(String, CompiledExpression)>,
+    body: CompiledBody,
+    decimal_formatters: Vec<FixedDecimalFormatter>,
+    /// The options of the formatters in `decimal_formatters`.
+    decimal_options: Vec<FixedDecimalFormatterOptions>,
+    datetime_formatters: Vec<DateTimeFormatter>,
+    cardinal_rules: Option<PluralRules>,
+    ordinal_rules: Option<PluralRules>,
+}
+
+impl MessageFormatter {
+    /// Creates a new [`MessageFormatter`] for a message, loading the data of the functions
+    /// that the message uses.
+    ///
+    /// This fails if the message uses unknown functions or options, or if a selector has no
+    /// function that can select.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        message: ast::Message,
+    ) -> Result<Self, MessageFormatError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + DataProvider<JapaneseErasV1Marker>
+            + DataProvider<JapaneseExtendedErasV1Marker>
+            + ?Sized,
+    {
+        let mut requirements = Requirements::new();
+        let declarations = message
+            .declarations
+            .iter()
+            .map(|declaration| {
+                Ok((
+                    declaration.variable.clone(),
+                    requirements.compile_expression(&declaration.expression)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, MessageFormatError>>()?;
+        let body = match &message.body {
+            ast::Body::Pattern(pattern) => {
+                CompiledBody::Pattern(requirements.compile_pattern(pattern)?)
+            }
+            ast::Body::Select(select) => CompiledBody::Select {
+                selectors: select
+                    .selectors
+                    .iter()
+                    .map(|selector| requirements.compile_expression(selector))
+                    .collect::<Result<_, _>>()?,
+                variants: select
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        Ok((
+                            variant.keys.clone(),
+                            requirements.compile_pattern(&variant.pattern)?,
+                        ))
+                    })
+                    .collect::<Result<_, MessageFormatError>>()?,
+            },
+        };
+        if let CompiledBody::Select { selectors, .. } = &body {
+            for selector in selectors {
+                match selector_function(&declarations, selector) {
+                    Some(Function::Plural { .. }) | Some(Function::Select) => {}
+                    Some(_) => return Err(MessageFormatError::InvalidSelector),
+                    None => return Err(MessageFormatError::MissingSelectorAnnotation),
+                }
+            }
+        }
+
+        let decimal_formatters = requirements
+            .decimal
+            .iter()
+            .map(|options| {
+                FixedDecimalFormatter::try_new_unstable(data_provider, locale, options.clone())
+            })
+            .collect::<Result<_, _>>()?;
+        let datetime_formatters = requirements
+            .datetime
+            .into_iter()
+            .map(|bag| DateTimeFormatter::try_new_unstable(data_provider, locale, bag.into()))
+            .collect::<Result<_, _>>()?;
+        let cardinal_rules = requirements
+            .cardinal
+            .then(|| PluralRules::try_new_cardinal_unstable(data_provider, locale))
+            .transpose()?;
+        let ordinal_rules = requirements
+            .ordinal
+            .then(|| PluralRules::try_new_ordinal_unstable(data_provider, locale))
+            .transpose()?;
+
+        Ok(Self {
+            declarations,
+            body,
+            decimal_formatters,
+            decimal_options: requirements.decimal,
+            datetime_formatters,
+            cardinal_rules,
+            ordinal_rules,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        message: ast::Message,
+        error: MessageFormatError
+    );
+
+    /// Formats the message with the given arguments, returning a [`FormattedMessage`].
+    ///
+    /// Each argument is a pair of a variable name, without the `$`, and a value.
+    pub fn format<'l>(
+        &'l self,
+        arguments: &'l [(&'l str, MessageValue<'l>)],
+    ) -> FormattedMessage<'l> {
+        FormattedMessage {
+            formatter: self,
+            arguments,
+        }
+    }
+
+    /// Formats the message with the given arguments, returning a [`String`].
+    pub fn format_to_string(&self, arguments: &[(&str, MessageValue)]) -> String {
+        self.format(arguments).write_to_string().into_owned()
+    }
+
+    /// Resolves the value of an expression. Variables are looked up in `scope`, and then in
+    /// the arguments.
+    fn resolve<'a>(
+        &'a self,
+        scope: &'a [(String, CompiledExpression)],
+        expression: &'a CompiledExpression,
+        arguments: &'a [(&'a str, MessageValue<'a>)],
+    ) -> Option<Resolved<'a>> {
+        let mut resolved = match expression.operand.as_ref()? {
+            ast::Operand::Literal(value) => Resolved {
+                value: ValueRef::String(value),
+                function: None,
+            },
+            ast::Operand::Variable(name) => {
+                if let Some(i) = scope.iter().rposition(|(n, _)| n == name) {
+                    // A declaration can only refer to the declarations before it.
+                    let (before, declaration) = scope.split_at(i);
+                    self.resolve(before, &declaration.first()?.1, arguments)?
+                } else {
+                    let (_, value) = arguments.iter().find(|(n, _)| n == name)?;
+                    Resolved {
+                        value: match value {
+                            MessageValue::String(value) => ValueRef::String(value),
+                            MessageValue::Number(value) => ValueRef::Number(Cow::Borrowed(value)),
+                            MessageValue::DateTime(value) => ValueRef::DateTime(value),
+                        },
+                        function: None,
+                    }
+                }
+            }
+        };
+        if let Some(function) = &expression.function {
+            // A function that replaces the `:number` or `:plural` of a declaration applies to
+            // the number as the declaration formats it, for example rounded.
+            if let Some(Function::Number(i)) | Some(Function::Plural { decimal: i, .. }) =
+                resolved.function
+            {
+                if let (Some(options), Some(value)) =
+                    (self.decimal_options.get(*i), resolved.value.to_decimal())
+                {
+                    if let Some(value) = options.apply_to(&value) {
+                        resolved.value = ValueRef::Number(Cow::Owned(value));
+                    }
+                }
+            }
+            resolved.function = Some(function);
+        }
+        Some(resolved)
+    }
+
+    fn selector_value<'a>(
+        &'a self,
+        selector: &'a CompiledExpression,
+        arguments: &'a [(&'a str, MessageValue<'a>)],
+    ) -> SelectorValue<'a> {
+        let resolved = match self.resolve(&self.declarations, selector, arguments) {
+            Some(resolved) => resolved,
+            None => return SelectorValue::Failed,
+        };
+        match (resolved.function, resolved.value) {
+            (
+                Some(Function::Plural {
+                    rule_type,
+                    min_fraction_digits,
+                    ..
+                }),
+                value,
+            ) => {
+                let rules = if *rule_type == PluralRuleType::Ordinal {
+                    self.ordinal_rules.as_ref()
+                } else {
+                    self.cardinal_rules.as_ref()
+                };
+                match (rules, value.to_decimal()) {
+                    (Some(rules), Some(value)) => {
+                        let mut value = value.into_owned();
+                        if let Some(digits) = min_fraction_digits {
+                            value.pad_end(-i16::from(*digits));
+                        }
+                        SelectorValue::Plural {
+                            category: rules.category_for(&value),
+                            value,
+                        }
+                    }
+                    _ => SelectorValue::Failed,
+                }
+            }
+            (Some(Function::Select), ValueRef::String(value)) => {
+                SelectorValue::String(Cow::Borrowed(value))
+            }
+            (Some(Function::Select), ValueRef::Number(value)) => {
+                SelectorValue::String(Cow::Owned(value.write_to_string().into_owned()))
+            }
+            _ => SelectorValue::Failed,
+        }
+    }
+
+    /// Returns the pattern of the best matching variant: the keys of each selector are
+    /// compared in order, preferring exact matches, then plural categories, then `*`.
+    fn select<'a>(
+        &'a self,
+        selectors: &'a [CompiledExpression],
+        variants: &'a [(Vec<ast::VariantKey>, Vec<CompiledPart>)],
+        arguments: &'a [(&'a str, MessageValue<'a>)],
+    ) -> Option<&'a [CompiledPart]> {
+        let values = selectors
+            .iter()
+            .map(|selector| self.selector_value(selector, arguments))
+            .collect::<Vec<_>>();
+        let mut best: Option<(Vec<u8>, &[CompiledPart])> = None;
+        for (keys, pattern) in variants {
+            let scores = keys
+                .iter()
+                .zip(&values)
+                .map(|(key, value)| value.match_key(key))
+                .collect::<Option<Vec<_>>>();
+            if let Some(scores) = scores {
+                if best.as_ref().map_or(true, |(best, _)| scores < *best) {
+                    best = Some((scores, pattern));
+                }
+            }
+        }
+        best.map(|(_, pattern)| pattern)
+    }
+
+    fn write_expression<'a, S: PartsWrite + ?Sized>(
+        &'a self,
+        expression: &'a CompiledExpression,
+        arguments: &'a [(&'a str, MessageValue<'a>)],
+        sink: &mut S,
+    ) -> fmt::Result {
+        if let Some(resolved) = self.resolve(&self.declarations, expression, arguments) {
+            match (resolved.function, resolved.value) {
+                (Some(Function::DateTime(i)), ValueRef::DateTime(value)) => {
+                    if let Some(Ok(formatted)) = self
+                        .datetime_formatters
+                        .get(*i)
+                        .map(|formatter| formatter.format(value))
+                    {
+                        return sink.with_part(parts::DATETIME, |s| formatted.write_to_parts(s));
+                    }
+                }
+                (Some(Function::DateTime(_)), _) => {}
+                (None | Some(Function::Select), ValueRef::String(value)) => {
+                    return sink.with_part(parts::STRING, |s| s.write_str(value));
+                }
+                (function, value) => {
+                    let i = match function {
+                        Some(Function::Number(i)) | Some(Function::Plural { decimal: i, .. }) => *i,
+                        _ => 0,
+                    };
+                    if let (Some(formatter), Some(value)) =
+                        (self.decimal_formatters.get(i), value.to_decimal())
+                    {
+                        return sink.with_part(parts::NUMBER, |s| {
+                            formatter.format(&value).write_to_parts(s)
+                        });
+                    }
+                }
+            }
+        }
+        sink.with_part(parts::FALLBACK, |s| s.write_str(&expression.fallback))
+    }
+}
+
+/// A message with its arguments, returned by [`MessageFormatter::format`].
+pub struct FormattedMessage<'l> {
+    formatter: &'l MessageFormatter,
+    arguments: &'l [(&'l str, MessageValue<'l>)],
+}
+
+impl Writeable for FormattedMessage<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let pattern = match &self.formatter.body {
+            CompiledBody::Pattern(pattern) => Some(pattern.as_slice()),
+            CompiledBody::Select {
+                selectors,
+                variants,
+            } => self.formatter.select(selectors, variants, self.arguments),
+        };
+        for part in pattern.into_iter().flatten() {
+            match part {
+                CompiledPart::Text(text) => {
+                    sink.with_part(parts::LITERAL, |s| s.write_str(text))?
+                }
+                CompiledPart::Expression(expression) => {
+                    self.formatter
+                        .write_expression(expression, self.arguments, sink)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedMessage<'_>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locid::locale;
+    use writeable::assert_writeable_parts_eq;
+
+    fn new_formatter(locale: icu_locid::Locale, message: &str) -> MessageFormatter {
+        MessageFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &locale.into(),
+            message.parse().unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn try_formatter(message: &str) -> Result<MessageFormatter, MessageFormatError> {
+        MessageFormatter::try_new_unstable(
+            &icu_testdata::unstable(),
+            &locale!("en").into(),
+            message.parse()?,
+        )
+    }
+
+    #[test]
+    fn test_format() {
+        let formatter = new_formatter(
+            locale!("en"),
+            "let $total = {$amount :number minimumFractionDigits=2}
+            {{$name} owes {$total}, or about {$amount :number maximumFractionDigits=0}.}",
+        );
+        let amount: FixedDecimal = "1234.5".parse().unwrap();
+        assert_eq!(
+            formatter.format_to_string(&[("name", "Ana".into()), ("amount", amount.into())]),
+            "Ana owes 1,234.50, or about 1,235."
+        );
+
+        // Strings are parsed as numbers by `:number`, and numbers are formatted without one.
+        let formatter = new_formatter(
+            locale!("en"),
+            "{{|1234.5| :number useGrouping=never signDisplay=always} {$n}}",
+        );
+        assert_eq!(
+            formatter.format_to_string(&[("n", 1000000.into())]),
+            "+1234.5 1,000,000"
+        );
+    }
+
+    #[test]
+    fn test_datetime() {
+        let datetime = DateTime::try_new_gregorian_datetime(2023, 5, 1, 14, 30, 0)
+            .unwrap()
+            .to_any();
+        let arguments = [("when", datetime.into())];
+        let formatter = new_formatter(
+            locale!("en"),
+            "{Sent {$when :datetime dateStyle=long}, {$when :datetime timeStyle=short}}",
+        );
+        assert_eq!(
+            formatter.format_to_string(&arguments),
+            "Sent May 1, 2023, 2:30\u{202f}PM"
+        );
+
+        // Dates need a function to be formatted.
+        let formatter = new_formatter(locale!("en"), "{Sent {$when}}");
+        assert_eq!(formatter.format_to_string(&arguments), "Sent {$when}");
+    }
+
+    #[test]
+    fn test_plural() {
+        let formatter = new_formatter(
+            locale!("en"),
+            "match {$n :plural}
+            when 1 {exactly one}
+            when one {one: {$n}}
+            when * {other: {$n}}",
+        );
+        let format = |n: &str| formatter.format_to_string(&[("n", n.into())]);
+        assert_eq!(format("1"), "exactly one");
+        assert_eq!(format("1.0"), "exactly one");
+        assert_eq!(format("2"), "other: 2");
+        // The argument is a string, so it is formatted as is without a function.
+        assert_eq!(format("1000"), "other: 1000");
+
+        let formatter = new_formatter(
+            locale!("en"),
+            "match {$n :plural minimumFractionDigits=1}
+            when one {one: {$n}}
+            when * {other: {$n :number minimumFractionDigits=1}}",
+        );
+        assert_eq!(formatter.format_to_string(&[("n", 1.into())]), "other: 1.0");
+
+        // The selector applies to the number as the declaration formats it.
+        let formatter = new_formatter(
+            locale!("en"),
+            "let $x = {$n :number maximumFractionDigits=0}
+            match {$x :plural}
+            when 2 {exactly two: {$x}}
+            when one {one: {$x}}
+            when * {other: {$x}}",
+        );
+        let format = |n: &str| {
+            let n: FixedDecimal = n.parse().unwrap();
+            formatter.format_to_string(&[("n", n.into())])
+        };
+        assert_eq!(format("1.5"), "exactly two: 2");
+        assert_eq!(format("1.4"), "one: 1");
+        assert_eq!(format("2.5"), "other: 3");
+
+        let formatter = new_formatter(
+            locale!("en"),
+            "let $x = {$n :number minimumFractionDigits=1}
+            match {$x :plural}
+            when one {one: {$x}}
+            when * {other: {$x}}",
+        );
+        assert_eq!(formatter.format_to_string(&[("n", 1.into())]), "other: 1.0");
+
+        let formatter = new_formatter(
+            locale!("en"),
+            "match {$n :plural type=ordinal}
+            when one {{$n}st}
+            when two {{$n}nd}
+            when few {{$n}rd}
+            when * {{$n}th}",
+        );
+        let format = |n: i64| formatter.format_to_string(&[("n", n.into())]);
+        assert_eq!(format(1), "1st");
+        assert_eq!(format(22), "22nd");
+        assert_eq!(format(103), "103rd");
+        assert_eq!(format(11), "11th");
+    }
+
+    #[test]
+    fn test_select() {
+        let formatter = new_formatter(
+            locale!("en"),
+            "let $role = {$user :select}
+            match {$role} {$count :plural}
+            when admin one {An admin has one task.}
+            when admin * {An admin has {$count} tasks.}
+            when * 0 {Nobody has tasks.}
+            when * * {Someone has {$count} tasks.}",
+        );
+        let format = |user: &str, count: i64| {
+            formatter.format_to_string(&[("user", user.into()), ("count", count.into())])
+        };
+        assert_eq!(format("admin", 1), "An admin has one task.");
+        assert_eq!(format("admin", 0), "An admin has 0 tasks.");
+        assert_eq!(format("guest", 0), "Nobody has tasks.");
+        assert_eq!(format("guest", 3), "Someone has 3 tasks.");
+
+        // A selector that cannot be resolved only matches `*`.
+        assert_eq!(
+            formatter.format_to_string(&[("count", 1.into())]),
+            "Someone has 1 tasks."
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let formatter = new_formatter(
+            locale!("en"),
+            "{{$missing} {$text :number} {|lit| :number} {$date :datetime}}",
+        );
+        assert_eq!(
+            formatter.format_to_string(&[("text", "abc".into()), ("date", "today".into())]),
+            "{$missing} {$text} {|lit|} {$date}"
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        let formatter = new_formatter(locale!("en"), "{Hi {$name}, {$n} {$x}!}");
+        let arguments = [("name", "Ana".into()), ("n", 1234.into())];
+        assert_writeable_parts_eq!(
+            formatter.format(&arguments),
+            "Hi Ana, 1,234 {$x}!",
+            [
+                (0, 3, parts::LITERAL),
+                (3, 6, parts::STRING),
+                (6, 8, parts::LITERAL),
+                (8, 13, parts::NUMBER),
+                (13, 14, parts::LITERAL),
+                (14, 18, parts::FALLBACK),
+                (18, 19, parts::LITERAL),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            try_formatter("{{$x :unknown}}").err(),
+            Some(MessageFormatError::UnknownFunction)
+        );
+        assert_eq!(
+            try_formatter("{{$x :number style=percent}}").err(),
+            Some(MessageFormatError::InvalidOption)
+        );
+        assert_eq!(
+            try_formatter("{{$x :number minimumFractionDigits=$digits}}").err(),
+            Some(MessageFormatError::InvalidOption)
+        );
+        assert_eq!(
+            try_formatter("match {$x} when * {}").err(),
+            Some(MessageFormatError::MissingSelectorAnnotation)
+        );
+        assert_eq!(
+            try_formatter("match {$x :number} when * {}").err(),
+            Some(MessageFormatError::InvalidSelector)
+        );
+        assert_eq!(
+            try_formatter("let $y = {$x :number} match {$y} when * {}").err(),
+            Some(MessageFormatError::InvalidSelector)
+        );
+        assert!(try_formatter("let $y = {$x :plural} match {$y} when * {}").is_ok());
+    }
+}
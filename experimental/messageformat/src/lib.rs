@@ -0,0 +1,87 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Parsing and formatting of messages in the syntax of MessageFormat 2,
+//! such as localized UI strings with placeholders and plural or select variants.
+//!
+//! This module is published as its own crate ([`icu_messageformat`](https://docs.rs/icu_messageformat/latest/icu_messageformat/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! A message is parsed into the data model in [`ast`], and formatted with a [`MessageFormatter`].
+//! Numbers are formatted with [`icu_decimal`], dates with [`icu_datetime`], and plural variants
+//! are selected with [`icu_plurals`].
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_locid::locale;
+//! use icu_messageformat::MessageFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let message = "let $count = {$photos :plural}
+//!     match {$gender :select} {$count}
+//!     when female one {{$name} added a photo to her album.}
+//!     when female * {{$name} added {$count} photos to her album.}
+//!     when * one {{$name} added a photo to their album.}
+//!     when * * {{$name} added {$count} photos to their album.}";
+//!
+//! let formatter = MessageFormatter::try_new_unstable(
+//!     &icu_testdata::unstable(),
+//!     &locale!("en").into(),
+//!     message.parse().expect("The message is valid"),
+//! )
+//! .expect("Data should load successfully");
+//!
+//! let arguments = [
+//!     ("name", "Maria".into()),
+//!     ("gender", "female".into()),
+//!     ("photos", 1.into()),
+//! ];
+//! assert_writeable_eq!(
+//!     formatter.format(&arguments),
+//!     "Maria added a photo to her album."
+//! );
+//! let arguments = [
+//!     ("name", "Sam".into()),
+//!     ("gender", "other".into()),
+//!     ("photos", 1200.into()),
+//! ];
+//! assert_writeable_eq!(
+//!     formatter.format(&arguments),
+//!     "Sam added 1,200 photos to their album."
+//! );
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+pub mod ast;
+mod error;
+mod format;
+mod parser;
+mod serializer;
+
+pub use error::MessageFormatError;
+pub use format::{parts, FormattedMessage, MessageFormatter, MessageValue};
@@ -0,0 +1,423 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::ast::*;
+use crate::MessageFormatError;
+use alloc::string::String;
+use alloc::vec;
+use core::str::FromStr;
+
+impl FromStr for Message {
+    type Err = MessageFormatError;
+
+    /// Parses a message, and checks that it is a valid data model: declarations are unique,
+    /// and each variant has one key per selector, and there is a variant with only `*` keys.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let message = Parser::new(input).parse_message()?;
+        validate(&message)?;
+        Ok(message)
+    }
+}
+
+fn validate(message: &Message) -> Result<(), MessageFormatError> {
+    for (i, declaration) in message.declarations.iter().enumerate() {
+        if message
+            .declarations
+            .iter()
+            .take(i)
+            .any(|d| d.variable == declaration.variable)
+        {
+            return Err(MessageFormatError::DuplicateDeclaration);
+        }
+    }
+    if let Body::Select(select) = &message.body {
+        if select
+            .variants
+            .iter()
+            .any(|variant| variant.keys.len() != select.selectors.len())
+        {
+            return Err(MessageFormatError::VariantKeyMismatch);
+        }
+        if !select.variants.iter().any(|variant| {
+            variant
+                .keys
+                .iter()
+                .all(|key| matches!(key, VariantKey::Wildcard))
+        }) {
+            return Err(MessageFormatError::MissingFallbackVariant);
+        }
+    }
+    Ok(())
+}
+
+/// Whether `c` may start a name, following the `name-start` production.
+pub(crate) fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || c == '_'
+        || matches!(c, '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}' | '\u{F8}'..='\u{2FF}')
+        || matches!(c, '\u{370}'..='\u{37D}' | '\u{37F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}')
+        || matches!(c, '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}' | '\u{3001}'..='\u{D7FF}')
+        || matches!(c, '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}' | '\u{10000}'..='\u{EFFFF}')
+}
+
+/// Whether `c` may be part of a name, following the `name-char` production.
+pub(crate) fn is_name_char(c: char) -> bool {
+    is_name_start(c)
+        || c.is_ascii_digit()
+        || matches!(c, '-' | '.' | '\u{B7}' | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}')
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, rest: input }
+    }
+
+    fn error(&self) -> MessageFormatError {
+        MessageFormatError::Syntax(self.input.len() - self.rest.len())
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let mut chars = self.rest.chars();
+        let c = chars.next();
+        self.rest = chars.as_str();
+        c
+    }
+
+    fn take_if(&mut self, c: char) -> bool {
+        if let Some(rest) = self.rest.strip_prefix(c) {
+            self.rest = rest;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), MessageFormatError> {
+        if self.take_if(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Skips optional whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let trimmed = self.rest.trim_start_matches(is_whitespace);
+        let skipped = trimmed.len() != self.rest.len();
+        self.rest = trimmed;
+        skipped
+    }
+
+    /// Consumes `keyword` if it is followed by one of `followers`.
+    fn take_keyword(&mut self, keyword: &str, followers: &[char]) -> bool {
+        match self.rest.strip_prefix(keyword) {
+            Some(rest)
+                if rest
+                    .chars()
+                    .next()
+                    .map_or(false, |c| is_whitespace(c) || followers.contains(&c)) =>
+            {
+                self.rest = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_message(mut self) -> Result<Message, MessageFormatError> {
+        let mut declarations = vec![];
+        self.skip_whitespace();
+        while self.take_keyword("let", &[]) {
+            self.skip_whitespace();
+            self.expect('$')?;
+            let variable = self.parse_name()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            self.skip_whitespace();
+            let expression = self.parse_expression()?;
+            declarations.push(Declaration {
+                variable,
+                expression,
+            });
+            self.skip_whitespace();
+        }
+
+        let body = if self.take_keyword("match", &['{']) {
+            let mut selectors = vec![];
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some('{') {
+                    break;
+                }
+                selectors.push(self.parse_expression()?);
+            }
+            if selectors.is_empty() {
+                return Err(self.error());
+            }
+            let mut variants = vec![];
+            while self.take_keyword("when", &[]) {
+                variants.push(self.parse_variant()?);
+                self.skip_whitespace();
+            }
+            if variants.is_empty() {
+                return Err(self.error());
+            }
+            Body::Select(Select {
+                selectors,
+                variants,
+            })
+        } else {
+            let pattern = self.parse_pattern()?;
+            self.skip_whitespace();
+            Body::Pattern(pattern)
+        };
+
+        if self.rest.is_empty() {
+            Ok(Message { declarations, body })
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn parse_variant(&mut self) -> Result<Variant, MessageFormatError> {
+        let mut keys = vec![];
+        while self.skip_whitespace() || keys.is_empty() {
+            let key = match self.peek() {
+                Some('{') if !keys.is_empty() => break,
+                Some('*') => {
+                    self.bump();
+                    VariantKey::Wildcard
+                }
+                Some('|') => VariantKey::Literal(self.parse_literal()?),
+                _ => VariantKey::Literal(self.parse_nmtoken()?),
+            };
+            keys.push(key);
+        }
+        Ok(Variant {
+            keys,
+            pattern: self.parse_pattern()?,
+        })
+    }
+
+    fn parse_pattern(&mut self) -> Result<Pattern, MessageFormatError> {
+        self.expect('{')?;
+        let mut parts = vec![];
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some('{') => {
+                    if !text.is_empty() {
+                        parts.push(PatternPart::Text(core::mem::take(&mut text)));
+                    }
+                    parts.push(PatternPart::Expression(self.parse_expression()?));
+                }
+                Some('\\') => {
+                    self.bump();
+                    match self.bump() {
+                        Some(c @ ('\\' | '{' | '}')) => text.push(c),
+                        _ => return Err(self.error()),
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    text.push(c);
+                }
+                None => return Err(self.error()),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(PatternPart::Text(text));
+        }
+        Ok(Pattern { parts })
+    }
+
+    fn parse_expression(&mut self) -> Result<Expression, MessageFormatError> {
+        self.expect('{')?;
+        self.skip_whitespace();
+        let operand = match self.peek() {
+            Some('$') | Some('|') => Some(self.parse_operand()?),
+            _ => None,
+        };
+        let annotation = if operand.is_none() || self.skip_whitespace() {
+            if self.take_if(':') {
+                let function = self.parse_name()?;
+                let mut options = vec![];
+                while self.skip_whitespace() {
+                    if !self.peek().map_or(false, is_name_start) {
+                        break;
+                    }
+                    let name = self.parse_name()?;
+                    self.skip_whitespace();
+                    self.expect('=')?;
+                    self.skip_whitespace();
+                    let value = match self.peek() {
+                        Some('$') | Some('|') => self.parse_operand()?,
+                        _ => Operand::Literal(self.parse_nmtoken()?),
+                    };
+                    options.push(FunctionOption { name, value });
+                }
+                Some(Annotation { function, options })
+            } else if operand.is_none() {
+                return Err(self.error());
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        self.skip_whitespace();
+        self.expect('}')?;
+        Ok(Expression {
+            operand,
+            annotation,
+        })
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, MessageFormatError> {
+        if self.take_if('$') {
+            Ok(Operand::Variable(self.parse_name()?))
+        } else {
+            Ok(Operand::Literal(self.parse_literal()?))
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<String, MessageFormatError> {
+        self.expect('|')?;
+        let mut literal = String::new();
+        loop {
+            match self.bump() {
+                Some('|') => return Ok(literal),
+                Some('\\') => match self.bump() {
+                    Some(c @ ('\\' | '|')) => literal.push(c),
+                    _ => return Err(self.error()),
+                },
+                Some(c) => literal.push(c),
+                None => return Err(self.error()),
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, MessageFormatError> {
+        if !self.peek().map_or(false, is_name_start) {
+            return Err(self.error());
+        }
+        self.parse_nmtoken()
+    }
+
+    fn parse_nmtoken(&mut self) -> Result<String, MessageFormatError> {
+        let rest = self.rest.trim_start_matches(is_name_char);
+        let len = self.rest.len() - rest.len();
+        match self.rest.get(..len) {
+            Some(token) if !token.is_empty() => {
+                self.rest = rest;
+                Ok(token.into())
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use writeable::Writeable;
+
+    #[test]
+    fn test_round_trip() {
+        for message in [
+            "{Hello, world!}",
+            "{}",
+            "{Hello, {$name}!}",
+            "{Escaped \\{braces\\} and \\\\}",
+            "{{|literal \\| with pipe|}}",
+            "{{$count :number minimumFractionDigits=2 useGrouping=never}}",
+            "{{:now}}",
+            "{{$x :f opt=$y other=|a b|}}",
+            "let $count = {$items :number}\n{{$count} items}",
+            "match {$count :plural}\nwhen 1 {One}\nwhen one {{$count} thing}\nwhen * {{$count} things}",
+            "match {$a :select} {$b :select}\nwhen x |y z| {xy}\nwhen * * {other}",
+        ] {
+            let parsed: Message = message.parse().expect(message);
+            assert_eq!(parsed.write_to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let message: Message = "  let $x={ $y :number }\n\tlet $z = {|z|}\n match{$x :plural}{$z :select}when 1 z{a}\nwhen * *{b} "
+            .parse()
+            .unwrap();
+        assert_eq!(
+            message.write_to_string(),
+            "let $x = {$y :number}\nlet $z = {|z|}\nmatch {$x :plural} {$z :select}\nwhen 1 z {a}\nwhen * * {b}"
+        );
+        // Whitespace in patterns is significant.
+        let message: Message = "{ a {$b} }".parse().unwrap();
+        assert_eq!(
+            message.body,
+            Body::Pattern(Pattern {
+                parts: vec![
+                    PatternPart::Text(" a ".into()),
+                    PatternPart::Expression(Expression {
+                        operand: Some(Operand::Variable("b".into())),
+                        annotation: None,
+                    }),
+                    PatternPart::Text(" ".into()),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        use MessageFormatError::*;
+
+        for (message, error) in [
+            ("", Syntax(0)),
+            ("Hello", Syntax(0)),
+            ("{Hello", Syntax(6)),
+            ("{Hello} world", Syntax(8)),
+            ("{\\n}", Syntax(3)),
+            ("{{}}", Syntax(2)),
+            ("{{$}}", Syntax(3)),
+            ("{{$1}}", Syntax(3)),
+            ("{{|abc}}", Syntax(8)),
+            ("{{$x:number}}", Syntax(4)),
+            ("{{$x :number opt}}", Syntax(16)),
+            ("{{:number opt=}}", Syntax(14)),
+            ("let x = {$y} {}", Syntax(4)),
+            ("let $x {$y} {}", Syntax(7)),
+            ("letter", Syntax(0)),
+            ("match {$x :plural}", Syntax(18)),
+            ("match when * {}", Syntax(6)),
+            ("match {$x :plural} when {}", Syntax(24)),
+            ("match {$x :plural} when * {} {}", Syntax(29)),
+            ("let $x = {$y} let $x = {$z} {}", DuplicateDeclaration),
+            (
+                "match {$x :plural} when one * {} when * {}",
+                VariantKeyMismatch,
+            ),
+            ("match {$x :plural} when one {}", MissingFallbackVariant),
+        ] {
+            assert_eq!(message.parse::<Message>(), Err(error), "{message}");
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::ast::*;
+use crate::parser::is_name_char;
+use core::fmt::{self, Write};
+use writeable::Writeable;
+
+/// Writes the message in the message syntax, with declarations and variants on separate lines.
+impl Writeable for Message {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        for declaration in &self.declarations {
+            sink.write_str("let $")?;
+            sink.write_str(&declaration.variable)?;
+            sink.write_str(" = ")?;
+            write_expression(&declaration.expression, sink)?;
+            sink.write_char('\n')?;
+        }
+        match &self.body {
+            Body::Pattern(pattern) => write_pattern(pattern, sink),
+            Body::Select(select) => {
+                sink.write_str("match")?;
+                for selector in &select.selectors {
+                    sink.write_char(' ')?;
+                    write_expression(selector, sink)?;
+                }
+                for variant in &select.variants {
+                    sink.write_str("\nwhen")?;
+                    for key in &variant.keys {
+                        sink.write_char(' ')?;
+                        match key {
+                            VariantKey::Literal(key) => write_nmtoken_or_literal(key, sink)?,
+                            VariantKey::Wildcard => sink.write_char('*')?,
+                        }
+                    }
+                    sink.write_char(' ')?;
+                    write_pattern(&variant.pattern, sink)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(Message);
+
+fn write_pattern<W: Write + ?Sized>(pattern: &Pattern, sink: &mut W) -> fmt::Result {
+    sink.write_char('{')?;
+    for part in &pattern.parts {
+        match part {
+            PatternPart::Text(text) => {
+                for c in text.chars() {
+                    if matches!(c, '\\' | '{' | '}') {
+                        sink.write_char('\\')?;
+                    }
+                    sink.write_char(c)?;
+                }
+            }
+            PatternPart::Expression(expression) => write_expression(expression, sink)?,
+        }
+    }
+    sink.write_char('}')
+}
+
+/// Writes an expression, such as `{$count :number}`.
+pub(crate) fn write_expression<W: Write + ?Sized>(
+    expression: &Expression,
+    sink: &mut W,
+) -> fmt::Result {
+    sink.write_char('{')?;
+    if let Some(operand) = &expression.operand {
+        write_operand(operand, sink)?;
+        if expression.annotation.is_some() {
+            sink.write_char(' ')?;
+        }
+    }
+    if let Some(annotation) = &expression.annotation {
+        sink.write_char(':')?;
+        sink.write_str(&annotation.function)?;
+        for option in &annotation.options {
+            sink.write_char(' ')?;
+            sink.write_str(&option.name)?;
+            sink.write_char('=')?;
+            match &option.value {
+                Operand::Literal(value) => write_nmtoken_or_literal(value, sink)?,
+                variable => write_operand(variable, sink)?,
+            }
+        }
+    }
+    sink.write_char('}')
+}
+
+fn write_operand<W: Write + ?Sized>(operand: &Operand, sink: &mut W) -> fmt::Result {
+    match operand {
+        Operand::Literal(literal) => write_literal(literal, sink),
+        Operand::Variable(name) => {
+            sink.write_char('$')?;
+            sink.write_str(name)
+        }
+    }
+}
+
+fn write_nmtoken_or_literal<W: Write + ?Sized>(value: &str, sink: &mut W) -> fmt::Result {
+    if !value.is_empty() && value.chars().all(is_name_char) {
+        sink.write_str(value)
+    } else {
+        write_literal(value, sink)
+    }
+}
+
+fn write_literal<W: Write + ?Sized>(literal: &str, sink: &mut W) -> fmt::Result {
+    sink.write_char('|')?;
+    for c in literal.chars() {
+        if matches!(c, '\\' | '|') {
+            sink.write_char('\\')?;
+        }
+        sink.write_char(c)?;
+    }
+    sink.write_char('|')
+}
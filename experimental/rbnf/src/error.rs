@@ -0,0 +1,47 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use displaydoc::Display;
+use icu_decimal::DecimalError;
+use icu_plurals::PluralsError;
+use icu_provider::DataError;
+
+/// A list of error outcomes for various operations in the `icu_rbnf` crate.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RbnfError {
+    /// An error originating from [`PluralRules`](icu_plurals::PluralRules).
+    #[displaydoc("Error loading plural rules: {0}")]
+    PluralRules(PluralsError),
+    /// An error originating from [`DataProvider`](icu_provider::DataProvider).
+    #[displaydoc("Error loading data: {0}")]
+    Data(DataError),
+    /// An error originating from [`FixedDecimalFormatter`](icu_decimal::FixedDecimalFormatter).
+    #[displaydoc("Error loading FixedDecimalFormatter: {0}")]
+    Decimal(DecimalError),
+    /// The locale data has no rule set of the requested type.
+    #[displaydoc("Unknown rule set")]
+    UnknownRuleSet,
+    /// The rules in the locale data are not syntactically valid.
+    #[displaydoc("Invalid rule-based number format rules")]
+    InvalidRules,
+}
+
+impl From<PluralsError> for RbnfError {
+    fn from(e: PluralsError) -> Self {
+        RbnfError::PluralRules(e)
+    }
+}
+
+impl From<DataError> for RbnfError {
+    fn from(e: DataError) -> Self {
+        RbnfError::Data(e)
+    }
+}
+
+impl From<DecimalError> for RbnfError {
+    fn from(e: DecimalError) -> Self {
+        RbnfError::Decimal(e)
+    }
+}
@@ -0,0 +1,801 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::RuleBasedNumberFormatterOptions;
+use crate::provider::RbnfRulesV1Marker;
+use crate::rules::*;
+use crate::RbnfError;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy, RoundingMode};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::FixedDecimalFormatter;
+use icu_plurals::provider::{CardinalV1Marker, OrdinalV1Marker};
+use icu_plurals::{PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+/// The maximum nesting of rule set invocations, which bounds the recursion of cyclic rules.
+const MAX_DEPTH: u8 = 64;
+
+/// The number of fraction digits beyond which a number is truncated.
+const MAX_FRACTION_DIGITS: u8 = 18;
+
+/// The decimal pattern for numbers that are too large for the rules.
+const DEFAULT_PATTERN: &str = "#,##0";
+
+/// A formatter that renders numbers according to the rule-based number format rules of a
+/// locale, such as spelled-out cardinals and ordinals.
+///
+/// Numbers are formatted exactly for integer parts that fit in a [`u64`] and up to 18
+/// fraction digits; larger integer parts are rendered with digits, and further fraction
+/// digits are truncated.
+///
+/// Read more about the options in the [`options`](crate::options) module.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_rbnf::options::{RuleBasedNumberFormatterOptions, RuleSetType};
+/// use icu_rbnf::RuleBasedNumberFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let format = |locale: &icu_locid::Locale, rule_set| {
+///     let mut options: RuleBasedNumberFormatterOptions = Default::default();
+///     options.rule_set = rule_set;
+///     RuleBasedNumberFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale.into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+/// };
+///
+/// let en = format(&locale!("en"), RuleSetType::SpelloutCardinal);
+/// assert_writeable_eq!(
+///     en.format(&FixedDecimal::from(-1234567)),
+///     "minus one million two hundred thirty-four thousand five hundred sixty-seven"
+/// );
+/// assert_writeable_eq!(
+///     en.format(&FixedDecimal::from(305).multiplied_pow10(-2)),
+///     "three point zero five"
+/// );
+///
+/// let en = format(&locale!("en"), RuleSetType::SpelloutOrdinal);
+/// assert_writeable_eq!(en.format(&FixedDecimal::from(1011)), "one thousand eleventh");
+///
+/// let fr = format(&locale!("fr"), RuleSetType::SpelloutCardinal);
+/// assert_writeable_eq!(fr.format(&FixedDecimal::from(81)), "quatre-vingt-un");
+///
+/// let fr = format(&locale!("fr"), RuleSetType::DigitsOrdinal);
+/// assert_writeable_eq!(fr.format(&FixedDecimal::from(1)), "1er");
+///
+/// // Plural categories select the forms of the words.
+/// let ru = format(&locale!("ru"), RuleSetType::SpelloutCardinal);
+/// assert_writeable_eq!(ru.format(&FixedDecimal::from(2000)), "две тысячи");
+/// assert_writeable_eq!(
+///     ru.format(&FixedDecimal::from(225).multiplied_pow10(-2)),
+///     "две целых двадцать пять сотых"
+/// );
+/// ```
+pub struct RuleBasedNumberFormatter {
+    rule_sets: Vec<RuleSet>,
+    /// The index of the rule set to format with.
+    rule_set: usize,
+    /// The formatters for the decimal patterns of the rules.
+    patterns: Vec<FixedDecimalFormatter>,
+    /// The index of the formatter for [`DEFAULT_PATTERN`].
+    default_pattern: usize,
+    cardinal_rules: PluralRules,
+    ordinal_rules: PluralRules,
+}
+
+impl RuleBasedNumberFormatter {
+    /// Creates a new [`RuleBasedNumberFormatter`] from locale data and an options bag.
+    ///
+    /// Returns [`RbnfError::UnknownRuleSet`] if the locale has no rule set for the options.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        options: RuleBasedNumberFormatterOptions,
+    ) -> Result<Self, RbnfError>
+    where
+        D: DataProvider<RbnfRulesV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + ?Sized,
+    {
+        let rules: DataPayload<RbnfRulesV1Marker> = data_provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        let rules = rules.get();
+
+        let names: Vec<&str> = rules.rule_sets.iter_keys().collect();
+        let base = options.rule_set.cldr_name();
+        let rule_set = [
+            options
+                .gender
+                .map(|gender| format!("{}{}", base, gender.cldr_suffix())),
+            Some(base.into()),
+            Some(format!("{}-masculine", base)),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(|name| names.iter().position(|n| *n == name))
+        .ok_or(RbnfError::UnknownRuleSet)?;
+
+        Self::try_new_from_rule_sets(
+            data_provider,
+            locale,
+            &names,
+            rules.rule_sets.iter_values(),
+            rule_set,
+        )
+    }
+
+    /// Creates a [`RuleBasedNumberFormatter`] from the source of its rule sets, which are
+    /// referred to by their `names`, formatting with the rule set at index `rule_set`.
+    fn try_new_from_rule_sets<'a, D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        names: &[&str],
+        sources: impl Iterator<Item = &'a str>,
+        rule_set: usize,
+    ) -> Result<Self, RbnfError>
+    where
+        D: DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<CardinalV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + ?Sized,
+    {
+        let mut patterns = Vec::new();
+        let mut rule_sets = sources
+            .map(|source| parse_rule_set(source, names, &mut patterns))
+            .collect::<Result<Vec<_>, _>>()?;
+        mark_fraction_rule_sets(&mut rule_sets);
+
+        let default_pattern = match patterns.iter().position(|p| p == DEFAULT_PATTERN) {
+            Some(index) => index,
+            None => {
+                patterns.push(DEFAULT_PATTERN.into());
+                patterns.len() - 1
+            }
+        };
+        let patterns = patterns
+            .iter()
+            .map(|pattern| {
+                FixedDecimalFormatter::try_new_unstable(
+                    data_provider,
+                    locale,
+                    pattern_options(pattern),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            rule_sets,
+            rule_set,
+            patterns,
+            default_pattern,
+            cardinal_rules: PluralRules::try_new_unstable(
+                data_provider,
+                locale,
+                PluralRuleType::Cardinal,
+            )?,
+            ordinal_rules: PluralRules::try_new_unstable(
+                data_provider,
+                locale,
+                PluralRuleType::Ordinal,
+            )?,
+        })
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: RuleBasedNumberFormatterOptions,
+        error: RbnfError
+    );
+
+    /// Formats a [`FixedDecimal`] with the rule set of this formatter.
+    pub fn format(&self, value: &FixedDecimal) -> FormattedRuleBasedNumber<'_> {
+        FormattedRuleBasedNumber {
+            value: value.clone(),
+            formatter: self,
+        }
+    }
+
+    fn write_number<W>(&self, set: usize, n: Number, depth: u8, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let depth = depth
+            .checked_add(1)
+            .filter(|depth| *depth <= MAX_DEPTH)
+            .ok_or(fmt::Error)?;
+        let rule_set = self.rule_sets.get(set).ok_or(fmt::Error)?;
+        if rule_set.is_fraction {
+            return self.write_fraction(set, n, depth, sink);
+        }
+        // As in ICU, a number for which there is no negative or fraction rule is formatted
+        // with the rule for its rounded absolute value, which still receives the number itself.
+        if n.negative {
+            if let Some(rule) = &rule_set.negative {
+                return self.write_rule(set, 0, rule, Input::Negative(n.abs()), depth, sink);
+            }
+        }
+        if n.scale != 0 {
+            let rule = if n.integer == 0 {
+                rule_set
+                    .proper_fraction
+                    .as_ref()
+                    .or(rule_set.improper_fraction.as_ref())
+            } else {
+                rule_set.improper_fraction.as_ref()
+            };
+            if let Some(rule) = rule {
+                return self.write_rule(set, 0, rule, Input::Fraction(n), depth, sink);
+            }
+        }
+        let rules = &rule_set.rules;
+        let value = n.rounded();
+        // The last rule whose base value is at most the value, or the first rule.
+        let mut index = rules
+            .iter()
+            .rposition(|rule| rule.base <= value)
+            .unwrap_or(0);
+        if let Some(rule) = rules.get(index) {
+            // As in ICU, a rule whose remainder would be zero while its base value is not a
+            // multiple of its divisor yields to the preceding rule, so that for example a rule
+            // `1100/100` is not used for 1200.
+            if index > 0
+                && rule.has_modulus()
+                && value % rule.divisor == 0
+                && rule.base % rule.divisor != 0
+            {
+                index -= 1;
+            }
+        }
+        match rules.get(index) {
+            Some(rule) => self.write_rule(set, index, rule, Input::Normal(n), depth, sink),
+            None => self.write_pattern(self.default_pattern, n, sink),
+        }
+    }
+
+    /// Formats the fractional part of `n` with a rule set whose base values are denominators.
+    fn write_fraction<W>(&self, set: usize, n: Number, depth: u8, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let rules = self
+            .rule_sets
+            .get(set)
+            .map(|rule_set| rule_set.rules.as_slice())
+            .unwrap_or_default();
+        let scale = 10u128.pow(n.scale.into());
+        // The first denominator with an exact numerator, or else the one with the smallest error
+        // |fraction - numerator / base|, which is |product - numerator * scale| / (scale * base).
+        let mut best: Option<(usize, u64, u128, u64)> = None;
+        for (index, rule) in rules.iter().enumerate() {
+            if rule.base == 0 {
+                continue;
+            }
+            let product = u128::from(n.fraction) * u128::from(rule.base);
+            let numerator = match u64::try_from((product + scale / 2) / scale) {
+                Ok(numerator) => numerator,
+                Err(_) => continue,
+            };
+            // The distance is at most scale / 2 <= 5 * 10^17, so multiplying it by a u64 base
+            // does not overflow a u128.
+            let distance = product.abs_diff(u128::from(numerator) * scale);
+            if best
+                .map(|(_, _, best_distance, best_base)| {
+                    distance * u128::from(best_base) < best_distance * u128::from(rule.base)
+                })
+                .unwrap_or(true)
+            {
+                best = Some((index, numerator, distance, rule.base));
+            }
+            if distance == 0 {
+                break;
+            }
+        }
+        match best.and_then(|(index, numerator, _, _)| Some((index, rules.get(index)?, numerator)))
+        {
+            Some((index, rule, numerator)) => {
+                self.write_rule(set, index, rule, Input::Numerator(numerator), depth, sink)
+            }
+            None => self.write_pattern(self.default_pattern, n, sink),
+        }
+    }
+
+    fn write_rule<W>(
+        &self,
+        set: usize,
+        index: usize,
+        rule: &Rule,
+        input: Input,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let omit_optional = match input {
+            Input::Normal(n) => rule.base % rule.divisor == 0 && n.rounded() % rule.divisor == 0,
+            Input::Fraction(n) => n.integer == 0,
+            Input::Negative(_) | Input::Numerator(_) => false,
+        };
+        self.write_parts(
+            set,
+            index,
+            rule,
+            &rule.parts,
+            input,
+            omit_optional,
+            depth,
+            sink,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_parts<W>(
+        &self,
+        set: usize,
+        index: usize,
+        rule: &Rule,
+        parts: &[Part],
+        input: Input,
+        omit_optional: bool,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        for part in parts {
+            match part {
+                Part::Text(text) => sink.write_str(text)?,
+                Part::Optional(parts) => {
+                    if !omit_optional {
+                        self.write_parts(set, index, rule, parts, input, false, depth, sink)?;
+                    }
+                }
+                Part::Plural(plural) => {
+                    let value = match input {
+                        Input::Normal(n) => n.integer / rule.divisor,
+                        Input::Fraction(n) | Input::Negative(n) => n.integer,
+                        Input::Numerator(n) => n,
+                    };
+                    let plural_rules = if plural.ordinal {
+                        &self.ordinal_rules
+                    } else {
+                        &self.cardinal_rules
+                    };
+                    sink.write_str(plural.get(plural_rules.category_for(value)))?;
+                }
+                Part::Substitution(substitution) => {
+                    self.write_substitution(set, index, rule, substitution, input, depth, sink)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_substitution<W>(
+        &self,
+        set: usize,
+        index: usize,
+        rule: &Rule,
+        substitution: &Substitution,
+        input: Input,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        use SubstitutionKind::*;
+        let value = match (input, substitution.kind) {
+            (Input::Normal(n), Quotient) => Number {
+                integer: n.integer / rule.divisor,
+                fraction: 0,
+                scale: 0,
+                ..n
+            },
+            (Input::Normal(n), Modulus) => Number {
+                integer: n.integer % rule.divisor,
+                ..n
+            },
+            (Input::Normal(n), PrecedingModulus) => {
+                let n = Number {
+                    integer: n.integer % rule.divisor,
+                    ..n
+                };
+                let preceding = index
+                    .checked_sub(1)
+                    .and_then(|index| Some((index, self.rule_sets.get(set)?.rules.get(index)?)));
+                return match preceding {
+                    Some((index, rule)) => {
+                        self.write_rule(set, index, rule, Input::Normal(n), depth, sink)
+                    }
+                    None => self.write_number(set, n, depth, sink),
+                };
+            }
+            (Input::Normal(n) | Input::Negative(n) | Input::Fraction(n), Same) => n,
+            (Input::Negative(n), _) => n,
+            (Input::Fraction(n), Quotient) => Number {
+                fraction: 0,
+                scale: 0,
+                ..n
+            },
+            (Input::Fraction(n), Modulus | PrecedingModulus) => {
+                let fraction = Number { integer: 0, ..n };
+                let by_digits = match substitution.target {
+                    Target::Owner => true,
+                    Target::RuleSet(target) => target == set,
+                    Target::Pattern(_) => false,
+                };
+                if !by_digits {
+                    return self.write_target(set, substitution.target, fraction, depth, sink);
+                }
+                // Each digit is formatted separately, separated by spaces for `>>`.
+                for position in (0..n.scale).rev() {
+                    if position + 1 < n.scale && substitution.kind == Modulus {
+                        sink.write_char(' ')?;
+                    }
+                    let digit = n.fraction / 10u64.pow(position.into()) % 10;
+                    self.write_number(set, Number::integer(digit), depth, sink)?;
+                }
+                return Ok(());
+            }
+            (Input::Numerator(n), Quotient | Same) => Number::integer(n),
+            // Fraction rule sets only use their numerator.
+            (Input::Numerator(_), Modulus | PrecedingModulus) => return Ok(()),
+        };
+        self.write_target(set, substitution.target, value.normalized(), depth, sink)
+    }
+
+    fn write_target<W>(
+        &self,
+        set: usize,
+        target: Target,
+        n: Number,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        match target {
+            Target::Owner => self.write_number(set, n, depth, sink),
+            Target::RuleSet(set) => self.write_number(set, n, depth, sink),
+            Target::Pattern(pattern) => self.write_pattern(pattern, n, sink),
+        }
+    }
+
+    fn write_pattern<W>(&self, pattern: usize, n: Number, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.patterns
+            .get(pattern)
+            .ok_or(fmt::Error)?
+            .format(&n.to_fixed_decimal())
+            .write_to(sink)
+    }
+}
+
+/// The options of the [`FixedDecimalFormatter`] for a decimal pattern such as `#,##0.#`.
+fn pattern_options(pattern: &str) -> FixedDecimalFormatterOptions {
+    let (integer, fraction) = pattern.split_once('.').unwrap_or((pattern, ""));
+    let count = |s: &str, digits: &[char]| s.chars().filter(|c| digits.contains(c)).count() as u8;
+    let mut options: FixedDecimalFormatterOptions = Default::default();
+    options.grouping_strategy = if integer.contains(',') {
+        GroupingStrategy::Auto
+    } else {
+        GroupingStrategy::Never
+    };
+    options.min_integer_digits = Some(count(integer, &['0']));
+    options.min_fraction_digits = Some(count(fraction, &['0']));
+    options.max_fraction_digits = Some(count(fraction, &['0', '#']));
+    options.rounding_mode = RoundingMode::HalfEven;
+    options
+}
+
+/// Marks the rule sets named by the `>>` substitutions of fraction rules in other rule sets
+/// as fraction rule sets, as ICU does.
+fn mark_fraction_rule_sets(rule_sets: &mut [RuleSet]) {
+    fn collect(parts: &[Part], owner: usize, targets: &mut Vec<usize>) {
+        for part in parts {
+            match part {
+                Part::Substitution(Substitution {
+                    kind: SubstitutionKind::Modulus | SubstitutionKind::PrecedingModulus,
+                    target: Target::RuleSet(target),
+                }) if *target != owner => targets.push(*target),
+                Part::Optional(parts) => collect(parts, owner, targets),
+                _ => (),
+            }
+        }
+    }
+    let mut targets = Vec::new();
+    for (owner, rule_set) in rule_sets.iter().enumerate() {
+        for rule in [&rule_set.improper_fraction, &rule_set.proper_fraction]
+            .into_iter()
+            .flatten()
+        {
+            collect(&rule.parts, owner, &mut targets);
+        }
+    }
+    for target in targets {
+        if let Some(rule_set) = rule_sets.get_mut(target) {
+            rule_set.is_fraction = true;
+        }
+    }
+}
+
+/// The value that a rule is applied to.
+#[derive(Debug, Clone, Copy)]
+enum Input {
+    /// A number for a rule with a numeric base value, which is usually a non-negative integer.
+    Normal(Number),
+    /// The absolute value of a negative number, for a `-x` rule.
+    Negative(Number),
+    /// A number with a fractional part, for a `x.x` or `0.x` rule.
+    Fraction(Number),
+    /// The numerator of a fraction, for a rule of a fraction rule set.
+    Numerator(u64),
+}
+
+/// A number whose integer part fits in a [`u64`], with up to [`MAX_FRACTION_DIGITS`]
+/// fraction digits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Number {
+    negative: bool,
+    integer: u64,
+    /// The fraction digits as an integer, without trailing zeros.
+    fraction: u64,
+    /// The number of fraction digits.
+    scale: u8,
+}
+
+impl Number {
+    fn integer(integer: u64) -> Self {
+        Self {
+            negative: false,
+            integer,
+            fraction: 0,
+            scale: 0,
+        }
+    }
+
+    fn try_from_fixed_decimal(value: &FixedDecimal) -> Option<Self> {
+        let magnitudes = value.magnitude_range();
+        let mut integer: u64 = 0;
+        for magnitude in (0..=*magnitudes.end()).rev() {
+            integer = integer
+                .checked_mul(10)?
+                .checked_add(value.digit_at(magnitude).into())?;
+        }
+        let mut fraction: u64 = 0;
+        let mut scale = 0;
+        while scale < MAX_FRACTION_DIGITS && -(i16::from(scale) + 1) >= *magnitudes.start() {
+            scale += 1;
+            fraction = fraction * 10 + u64::from(value.digit_at(-i16::from(scale)));
+        }
+        while scale > 0 && fraction % 10 == 0 {
+            fraction /= 10;
+            scale -= 1;
+        }
+        Some(
+            Self {
+                negative: value.sign() == Sign::Negative,
+                integer,
+                fraction,
+                scale,
+            }
+            .normalized(),
+        )
+    }
+
+    /// The number, without the sign if it is zero.
+    fn normalized(self) -> Self {
+        Self {
+            negative: self.negative && (self.integer != 0 || self.fraction != 0),
+            ..self
+        }
+    }
+
+    fn abs(self) -> Self {
+        Self {
+            negative: false,
+            ..self
+        }
+    }
+
+    /// The integer nearest to the absolute value of the number, rounding half up.
+    fn rounded(self) -> u64 {
+        let half_up = u128::from(self.fraction) * 2 >= 10u128.pow(self.scale.into());
+        self.integer.saturating_add(half_up.into())
+    }
+
+    fn to_fixed_decimal(self) -> FixedDecimal {
+        let scale = 10u128.pow(self.scale.into());
+        let digits = u128::from(self.integer) * scale + u128::from(self.fraction);
+        FixedDecimal::from(digits)
+            .multiplied_pow10(-i16::from(self.scale))
+            .with_sign(if self.negative {
+                Sign::Negative
+            } else {
+                Sign::None
+            })
+    }
+}
+
+/// An intermediate structure returned by [`RuleBasedNumberFormatter`].
+/// Use [`Writeable`][Writeable] to render the formatted number to a string or buffer.
+///
+/// Writing fails if the rules of the locale recurse too deeply.
+pub struct FormattedRuleBasedNumber<'l> {
+    value: FixedDecimal,
+    formatter: &'l RuleBasedNumberFormatter,
+}
+
+impl<'l> Writeable for FormattedRuleBasedNumber<'l> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match Number::try_from_fixed_decimal(&self.value) {
+            Some(n) => self
+                .formatter
+                .write_number(self.formatter.rule_set, n, 0, sink),
+            None => self
+                .formatter
+                .patterns
+                .get(self.formatter.default_pattern)
+                .ok_or(fmt::Error)?
+                .format(&self.value)
+                .write_to(sink),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRuleBasedNumber<'_>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Formats `value` with the first of the given rule sets.
+    fn format(rule_sets: &[(&str, &str)], value: &str) -> String {
+        let names: Vec<&str> = rule_sets.iter().map(|(name, _)| *name).collect();
+        let formatter = RuleBasedNumberFormatter::try_new_from_rule_sets(
+            &icu_testdata::unstable(),
+            &icu_locid::locale!("en").into(),
+            &names,
+            rule_sets.iter().map(|(_, source)| *source),
+            0,
+        )
+        .unwrap();
+        let value: FixedDecimal = value.parse().unwrap();
+        formatter.format(&value).write_to_string().into_owned()
+    }
+
+    const WORDS: (&str, &str) = (
+        "%words",
+        "-x: minus >>; x.x: << point >>; 0: zero; 1: one; 2: two; 3: three; 4: four; \
+         5: five; 6: six; 7: seven; 8: eight; 9: nine; 10: ten; 20: twenty[->>]; \
+         40: forty[->>]; 90: ninety[->>]; 100: << hundred[ >>];",
+    );
+
+    #[test]
+    fn test_modulus() {
+        for (value, expected) in [
+            ("20", "twenty"),
+            ("23", "twenty-three"),
+            ("120", "one hundred twenty"),
+            ("105", "one hundred five"),
+        ] {
+            assert_eq!(format(&[WORDS], value), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_quotient() {
+        for (value, expected) in [
+            ("300", "three hundred"),
+            ("999", "nine hundred ninety-nine"),
+        ] {
+            assert_eq!(format(&[WORDS], value), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_same_value() {
+        let rule_sets = [("%exclaimed", "0: =%words=!;"), WORDS];
+        assert_eq!(format(&rule_sets, "42"), "forty-two!");
+    }
+
+    #[test]
+    fn test_negative() {
+        assert_eq!(format(&[WORDS], "-7"), "minus seven");
+        assert_eq!(format(&[WORDS], "-1.5"), "minus one point five");
+    }
+
+    #[test]
+    fn test_decimal_fraction() {
+        assert_eq!(format(&[WORDS], "2.05"), "two point zero five");
+        assert_eq!(format(&[WORDS], "0.25"), "zero point two five");
+    }
+
+    #[test]
+    fn test_fraction_rule_set() {
+        let rule_sets = [
+            (
+                "%fraction",
+                "0: =%words=; 0.x: >%%parts>; x.x: <%words< and >%%parts>;",
+            ),
+            (
+                "%%parts",
+                "2: <%words< half; 3: <%words< third; 4: <%words< quarter;",
+            ),
+            WORDS,
+        ];
+        for (value, expected) in [
+            ("0.5", "one half"),
+            ("0.75", "three quarter"),
+            // 0.3 is closest to 1/3, and 0.6 to 2/3
+            ("0.3", "one third"),
+            ("0.6", "two third"),
+            // Of the denominators that give the same error, the first one is used
+            ("0.375", "one third"),
+            ("2.25", "two and one quarter"),
+        ] {
+            assert_eq!(format(&rule_sets, value), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_number() {
+        let value: FixedDecimal = "-12.3400".parse().unwrap();
+        let n = Number::try_from_fixed_decimal(&value).unwrap();
+        assert_eq!(
+            n,
+            Number {
+                negative: true,
+                integer: 12,
+                fraction: 34,
+                scale: 2
+            }
+        );
+        assert_eq!(n.to_fixed_decimal().to_string(), "-12.34");
+        assert_eq!(n.rounded(), 12);
+
+        let value: FixedDecimal = "-0.00".parse().unwrap();
+        assert_eq!(
+            Number::try_from_fixed_decimal(&value),
+            Some(Number::integer(0))
+        );
+
+        let value = FixedDecimal::from(u64::MAX).multiplied_pow10(1);
+        assert_eq!(Number::try_from_fixed_decimal(&value), None);
+
+        let value: FixedDecimal = "0.5".parse().unwrap();
+        assert_eq!(Number::try_from_fixed_decimal(&value).unwrap().rounded(), 1);
+    }
+}
@@ -0,0 +1,78 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Experimental\] Rule-based number formatting, such as numbers spelled out in words
+//! ("one hundred twenty-three") and ordinals ("23rd").
+//!
+//! This module is published as its own crate ([`icu_rbnf`](https://docs.rs/icu_rbnf/latest/icu_rbnf/))
+//! and as part of the [`icu`](https://docs.rs/icu/latest/icu/) crate. See the latter for more details on the ICU4X project.
+//!
+//! The formatter interprets the CLDR rule-based number format (RBNF) rule sets of a locale,
+//! which use the syntax of ICU's `RuleBasedNumberFormat`. Rules that depend on the plural
+//! category of a number are resolved with [`icu_plurals`].
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. It can be enabled with the "experimental" Cargo feature
+//! of the icu meta-crate. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed_decimal::FixedDecimal;
+//! use icu_locid::locale;
+//! use icu_rbnf::options::{RuleBasedNumberFormatterOptions, RuleSetType};
+//! use icu_rbnf::RuleBasedNumberFormatter;
+//! use writeable::assert_writeable_eq;
+//!
+//! let format = |locale: &icu_locid::Locale, rule_set| {
+//!     let mut options: RuleBasedNumberFormatterOptions = Default::default();
+//!     options.rule_set = rule_set;
+//!     RuleBasedNumberFormatter::try_new_unstable(
+//!         &icu_testdata::unstable(),
+//!         &locale.into(),
+//!         options,
+//!     )
+//!     .expect("Data should load successfully")
+//! };
+//!
+//! let value = FixedDecimal::from(123);
+//!
+//! let en = format(&locale!("en"), RuleSetType::SpelloutCardinal);
+//! assert_writeable_eq!(en.format(&value), "one hundred twenty-three");
+//!
+//! let fr = format(&locale!("fr"), RuleSetType::SpelloutCardinal);
+//! assert_writeable_eq!(fr.format(&value), "cent vingt-trois");
+//!
+//! let en = format(&locale!("en"), RuleSetType::DigitsOrdinal);
+//! assert_writeable_eq!(en.format(&FixedDecimal::from(23)), "23rd");
+//! ```
+
+// https://github.com/unicode-org/icu4x/blob/main/docs/process/boilerplate.md#library-annotations
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        // TODO(#2266): enable missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+extern crate alloc;
+
+mod error;
+mod format;
+pub mod options;
+pub mod provider;
+mod rules;
+
+pub use error::RbnfError;
+pub use format::{FormattedRuleBasedNumber, RuleBasedNumberFormatter};
@@ -0,0 +1,114 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RuleBasedNumberFormatter`](crate::RuleBasedNumberFormatter).
+
+/// A bag of options defining which rule set of the locale is used by
+/// [`RuleBasedNumberFormatter`](crate::RuleBasedNumberFormatter).
+///
+/// The rule set is looked up by its CLDR name, such as `%spellout-cardinal-feminine`. The
+/// following names are tried in order, and the first one that the locale defines is used:
+///
+/// 1. the name of the [`RuleSetType`] followed by the [`GrammaticalGender`], if one is set;
+/// 2. the name of the [`RuleSetType`] alone;
+/// 3. the name of the [`RuleSetType`] followed by `-masculine`.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// use icu_locid::locale;
+/// use icu_rbnf::options::{
+///     GrammaticalGender, RuleBasedNumberFormatterOptions, RuleSetType,
+/// };
+/// use icu_rbnf::RuleBasedNumberFormatter;
+/// use writeable::assert_writeable_eq;
+///
+/// let format = |gender| {
+///     let mut options: RuleBasedNumberFormatterOptions = Default::default();
+///     options.rule_set = RuleSetType::SpelloutOrdinal;
+///     options.gender = gender;
+///     RuleBasedNumberFormatter::try_new_unstable(
+///         &icu_testdata::unstable(),
+///         &locale!("es").into(),
+///         options,
+///     )
+///     .expect("Data should load successfully")
+/// };
+///
+/// let value = FixedDecimal::from(21);
+/// let masculine = format(None);
+/// assert_writeable_eq!(masculine.format(&value), "vigésimo primero");
+/// let feminine = format(Some(GrammaticalGender::Feminine));
+/// assert_writeable_eq!(feminine.format(&value), "vigésima primera");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct RuleBasedNumberFormatterOptions {
+    /// The kind of rule set to format with.
+    pub rule_set: RuleSetType,
+    /// The grammatical gender of the noun that the number agrees with, if any.
+    pub gender: Option<GrammaticalGender>,
+}
+
+/// The kind of rule set that a [`RuleBasedNumberFormatter`](crate::RuleBasedNumberFormatter)
+/// formats with.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RuleSetType {
+    /// Numbers spelled out as counted in sequence, the CLDR `%spellout-numbering` rule set.
+    SpelloutNumbering,
+
+    /// Cardinal numbers spelled out in words, such as "one hundred twenty-three", the CLDR
+    /// `%spellout-cardinal` rule set.
+    SpelloutCardinal,
+
+    /// Ordinal numbers spelled out in words, such as "twenty-third", the CLDR
+    /// `%spellout-ordinal` rule set.
+    SpelloutOrdinal,
+
+    /// Ordinal numbers written with digits, such as "23rd", the CLDR `%digits-ordinal` rule set.
+    DigitsOrdinal,
+}
+
+impl Default for RuleSetType {
+    fn default() -> Self {
+        Self::SpelloutCardinal
+    }
+}
+
+impl RuleSetType {
+    pub(crate) fn cldr_name(self) -> &'static str {
+        match self {
+            Self::SpelloutNumbering => "%spellout-numbering",
+            Self::SpelloutCardinal => "%spellout-cardinal",
+            Self::SpelloutOrdinal => "%spellout-ordinal",
+            Self::DigitsOrdinal => "%digits-ordinal",
+        }
+    }
+}
+
+/// A grammatical gender that selects a variant of a rule set.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum GrammaticalGender {
+    /// The masculine variant, with the CLDR suffix `-masculine`.
+    Masculine,
+
+    /// The feminine variant, with the CLDR suffix `-feminine`.
+    Feminine,
+
+    /// The neuter variant, with the CLDR suffix `-neuter`.
+    Neuter,
+}
+
+impl GrammaticalGender {
+    pub(crate) fn cldr_suffix(self) -> &'static str {
+        match self {
+            Self::Masculine => "-masculine",
+            Self::Feminine => "-feminine",
+            Self::Neuter => "-neuter",
+        }
+    }
+}
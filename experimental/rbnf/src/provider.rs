@@ -0,0 +1,40 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable.
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::{yoke, zerofrom};
+use zerovec::ZeroMap;
+
+/// The rule-based number format rule sets of a locale.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[icu_provider::data_struct(RbnfRulesV1Marker = "rbnf/rules@1")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_rbnf::provider)
+)]
+#[yoke(prove_covariance_manually)]
+pub struct RbnfRulesV1<'data> {
+    /// A map from CLDR rule set names, such as `%spellout-cardinal` or `%%tieth`, to the rules
+    /// of the rule set.
+    ///
+    /// The rules use the syntax of ICU's `RuleBasedNumberFormat`: each rule is a descriptor
+    /// and a body separated by a colon, and terminated by a semicolon, as in
+    /// `20: twenty[->>];`. Substitutions use the ASCII characters `<` and `>`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_sets: ZeroMap<'data, str, str>,
+}
@@ -0,0 +1,387 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A parser for rule sets in the syntax of ICU's `RuleBasedNumberFormat`.
+
+use crate::RbnfError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_plurals::PluralCategory;
+
+/// A parsed rule set.
+#[derive(Debug, Default)]
+pub(crate) struct RuleSet {
+    /// The `-x` rule, applied to negative numbers.
+    pub negative: Option<Rule>,
+    /// The `x.x` rule, applied to numbers with a fractional part.
+    pub improper_fraction: Option<Rule>,
+    /// The `0.x` rule, applied to numbers between 0 and 1.
+    pub proper_fraction: Option<Rule>,
+    /// The rules with numeric base values, in ascending order.
+    pub rules: Vec<Rule>,
+    /// Whether the rule set formats fractional parts, with base values acting as denominators.
+    ///
+    /// This is the case for the rule sets named by the `>>` substitutions of fraction rules.
+    pub is_fraction: bool,
+}
+
+/// A parsed rule.
+#[derive(Debug, Default)]
+pub(crate) struct Rule {
+    pub base: u64,
+    /// The radix raised to the exponent of the rule.
+    pub divisor: u64,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Part {
+    Text(String),
+    Substitution(Substitution),
+    /// Text in brackets, which is omitted if the number is a multiple of the divisor.
+    Optional(Vec<Part>),
+    Plural(PluralPattern),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Substitution {
+    pub kind: SubstitutionKind,
+    pub target: Target,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum SubstitutionKind {
+    /// `<<`: the quotient, or the integral part of a fraction.
+    Quotient,
+    /// `>>`: the remainder, or the fractional part of a fraction.
+    Modulus,
+    /// `>>>`: the remainder, formatted with the preceding rule; or the fractional part,
+    /// formatted digit by digit without spaces.
+    PrecedingModulus,
+    /// `==`: the number itself.
+    Same,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Target {
+    /// The rule set that contains the rule.
+    Owner,
+    /// A rule set, by index.
+    RuleSet(usize),
+    /// A decimal pattern, by index.
+    Pattern(usize),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct PluralPattern {
+    pub ordinal: bool,
+    pub variants: Vec<(PluralCategory, String)>,
+}
+
+impl PluralPattern {
+    pub(crate) fn get(&self, category: PluralCategory) -> &str {
+        self.variants
+            .iter()
+            .find(|(c, _)| *c == category)
+            .or_else(|| {
+                self.variants
+                    .iter()
+                    .find(|(c, _)| *c == PluralCategory::Other)
+            })
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_default()
+    }
+}
+
+/// Parses the rules of a rule set, resolving rule set names against `names` and collecting
+/// decimal patterns into `patterns`.
+pub(crate) fn parse_rule_set(
+    source: &str,
+    names: &[&str],
+    patterns: &mut Vec<String>,
+) -> Result<RuleSet, RbnfError> {
+    let mut rule_set = RuleSet::default();
+    for rule in source.split(';') {
+        // Whitespace at the end of a rule body is significant.
+        let rule = rule.trim_start();
+        if rule.is_empty() {
+            continue;
+        }
+        let (descriptor, body) = rule.split_once(':').ok_or(RbnfError::InvalidRules)?;
+        let body = body.trim_start();
+        let body = body.strip_prefix('\'').unwrap_or(body);
+        let parts = parse_body(body, names, patterns)?;
+        match descriptor.trim() {
+            "-x" => rule_set.negative = Some(Rule::special(parts)),
+            "x.x" | "x,x" => rule_set.improper_fraction = Some(Rule::special(parts)),
+            "0.x" | "0,x" => rule_set.proper_fraction = Some(Rule::special(parts)),
+            // Infinity and NaN cannot be represented by FixedDecimal, and the master rule
+            // only affects parsing.
+            "Inf" | "NaN" | "x.0" => (),
+            descriptor => rule_set.rules.push(Rule::normal(descriptor, parts)?),
+        }
+    }
+    Ok(rule_set)
+}
+
+impl Rule {
+    fn special(parts: Vec<Part>) -> Self {
+        Self {
+            base: 0,
+            divisor: 1,
+            parts,
+        }
+    }
+
+    fn normal(descriptor: &str, parts: Vec<Part>) -> Result<Self, RbnfError> {
+        let trimmed = descriptor.trim_end_matches('>');
+        let decrements = (descriptor.len() - trimmed.len()) as u32;
+        let (base, radix) = match trimmed.split_once('/') {
+            Some((base, radix)) => (base, radix),
+            None => (trimmed, "10"),
+        };
+        let base: u64 = base.parse().map_err(|_| RbnfError::InvalidRules)?;
+        let radix: u64 = radix.parse().map_err(|_| RbnfError::InvalidRules)?;
+        if radix < 2 {
+            return Err(RbnfError::InvalidRules);
+        }
+        // The exponent is the largest power of the radix that is at most the base value.
+        let mut exponent = 0;
+        let mut power: u64 = 1;
+        while let Some(next) = power.checked_mul(radix) {
+            if next > base {
+                break;
+            }
+            power = next;
+            exponent += 1;
+        }
+        let divisor = radix.pow(exponent - decrements.min(exponent));
+        Ok(Self {
+            base,
+            divisor,
+            parts,
+        })
+    }
+
+    /// Whether the rule has a `>>` or `>>>` substitution.
+    pub(crate) fn has_modulus(&self) -> bool {
+        fn has_modulus(parts: &[Part]) -> bool {
+            parts.iter().any(|part| match part {
+                Part::Substitution(Substitution {
+                    kind: SubstitutionKind::Modulus | SubstitutionKind::PrecedingModulus,
+                    ..
+                }) => true,
+                Part::Optional(parts) => has_modulus(parts),
+                _ => false,
+            })
+        }
+        has_modulus(&self.parts)
+    }
+}
+
+fn parse_body(
+    body: &str,
+    names: &[&str],
+    patterns: &mut Vec<String>,
+) -> Result<Vec<Part>, RbnfError> {
+    // The parts of the rule, and of the optional part being parsed, if any.
+    let mut parts = Vec::new();
+    let mut optional: Option<Vec<Part>> = None;
+    let mut text = String::new();
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        let after_c = rest.get(c.len_utf8()..).unwrap_or_default();
+        let in_optional = optional.is_some();
+        let current = optional.as_mut().unwrap_or(&mut parts);
+        let part = match c {
+            '<' | '>' | '=' => {
+                let (token, after) = after_c.split_once(c).ok_or(RbnfError::InvalidRules)?;
+                rest = after;
+                let kind = match c {
+                    '<' => SubstitutionKind::Quotient,
+                    '=' => SubstitutionKind::Same,
+                    _ => match rest.strip_prefix('>') {
+                        Some(after) if token.is_empty() => {
+                            rest = after;
+                            SubstitutionKind::PrecedingModulus
+                        }
+                        _ => SubstitutionKind::Modulus,
+                    },
+                };
+                let target = if token.is_empty() {
+                    Target::Owner
+                } else if token.starts_with('%') {
+                    Target::RuleSet(
+                        names
+                            .iter()
+                            .position(|name| *name == token)
+                            .ok_or(RbnfError::InvalidRules)?,
+                    )
+                } else {
+                    Target::Pattern(match patterns.iter().position(|p| p == token) {
+                        Some(index) => index,
+                        None => {
+                            patterns.push(token.into());
+                            patterns.len() - 1
+                        }
+                    })
+                };
+                Part::Substitution(Substitution { kind, target })
+            }
+            '$' if after_c.starts_with('(') => {
+                let (pattern, after) = after_c
+                    .get(1..)
+                    .and_then(|p| p.split_once(")$"))
+                    .ok_or(RbnfError::InvalidRules)?;
+                rest = after;
+                Part::Plural(parse_plural(pattern)?)
+            }
+            '[' if !in_optional => {
+                rest = after_c;
+                flush(&mut text, current);
+                optional = Some(Vec::new());
+                continue;
+            }
+            ']' => {
+                rest = after_c;
+                flush(&mut text, current);
+                let optional = optional.take().ok_or(RbnfError::InvalidRules)?;
+                parts.push(Part::Optional(optional));
+                continue;
+            }
+            c => {
+                rest = after_c;
+                text.push(c);
+                continue;
+            }
+        };
+        flush(&mut text, current);
+        current.push(part);
+    }
+    if optional.is_some() {
+        return Err(RbnfError::InvalidRules);
+    }
+    flush(&mut text, &mut parts);
+    Ok(parts)
+}
+
+fn flush(text: &mut String, parts: &mut Vec<Part>) {
+    if !text.is_empty() {
+        parts.push(Part::Text(core::mem::take(text)));
+    }
+}
+
+/// Parses a plural pattern such as `ordinal,one{st}two{nd}few{rd}other{th}`.
+fn parse_plural(pattern: &str) -> Result<PluralPattern, RbnfError> {
+    let (rule_type, mut rest) = pattern.split_once(',').ok_or(RbnfError::InvalidRules)?;
+    let ordinal = match rule_type.trim() {
+        "cardinal" => false,
+        "ordinal" => true,
+        _ => return Err(RbnfError::InvalidRules),
+    };
+    let mut variants = Vec::new();
+    while !rest.trim().is_empty() {
+        let (category, after) = rest.split_once('{').ok_or(RbnfError::InvalidRules)?;
+        let (text, after) = after.split_once('}').ok_or(RbnfError::InvalidRules)?;
+        let category =
+            PluralCategory::get_for_cldr_string(category.trim()).ok_or(RbnfError::InvalidRules)?;
+        variants.push((category, text.into()));
+        rest = after;
+    }
+    Ok(PluralPattern { ordinal, variants })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_rule_set() {
+        let names = ["%spellout-cardinal", "%digits-ordinal", "%%th"];
+        let mut patterns = Vec::new();
+        let rule_set = parse_rule_set(
+            "-x: minus >>; x.x: << point >>; Inf: infinite; 0: zero; 20: twenty[->>]; \
+             100: <%digits-ordinal< hundred>%%th>; 1000>: =#,##0=$(ordinal,one{st}other{th})$; \
+             2000/20: ' and >>>;",
+            &names,
+            &mut patterns,
+        )
+        .unwrap();
+
+        assert!(rule_set.negative.is_some());
+        assert!(rule_set.improper_fraction.is_some());
+        assert!(rule_set.proper_fraction.is_none());
+        assert_eq!(patterns, ["#,##0"]);
+
+        let bases: Vec<_> = rule_set
+            .rules
+            .iter()
+            .map(|rule| (rule.base, rule.divisor))
+            .collect();
+        assert_eq!(
+            bases,
+            [(0, 1), (20, 10), (100, 100), (1000, 100), (2000, 400)]
+        );
+
+        let modulus = |target| {
+            Part::Substitution(Substitution {
+                kind: SubstitutionKind::Modulus,
+                target,
+            })
+        };
+        assert_eq!(
+            rule_set.rules[1].parts,
+            [
+                Part::Text("twenty".into()),
+                Part::Optional(vec![Part::Text("-".into()), modulus(Target::Owner)])
+            ]
+        );
+        assert_eq!(
+            rule_set.rules[2].parts,
+            [
+                Part::Substitution(Substitution {
+                    kind: SubstitutionKind::Quotient,
+                    target: Target::RuleSet(1),
+                }),
+                Part::Text(" hundred".into()),
+                modulus(Target::RuleSet(2)),
+            ]
+        );
+        assert_eq!(
+            rule_set.rules[3].parts,
+            [
+                Part::Substitution(Substitution {
+                    kind: SubstitutionKind::Same,
+                    target: Target::Pattern(0),
+                }),
+                Part::Plural(PluralPattern {
+                    ordinal: true,
+                    variants: vec![
+                        (PluralCategory::One, "st".into()),
+                        (PluralCategory::Other, "th".into())
+                    ],
+                }),
+            ]
+        );
+        assert_eq!(
+            rule_set.rules[4].parts,
+            [
+                Part::Text(" and ".into()),
+                Part::Substitution(Substitution {
+                    kind: SubstitutionKind::PrecedingModulus,
+                    target: Target::Owner,
+                }),
+            ]
+        );
+
+        for invalid in ["0: [zero", "0: <%unknown<;", "x: zero;", "0: $(one{x})$;"] {
+            assert_eq!(
+                parse_rule_set(invalid, &names, &mut patterns).err(),
+                Some(RbnfError::InvalidRules),
+                "{invalid}"
+            );
+        }
+    }
+}
@@ -105,6 +105,17 @@ fn main() -> eyre::Result<()> {
                 )
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("ZONEINFO_ROOT")
+                .long("zoneinfo-root")
+                .value_name("PATH")
+                .help(
+                    "Path to a local directory of compiled time zone database (TZif) files, such as /usr/share/zoneinfo.\n\
+                    Required for the time_zone/offset_transitions@1 key, which fails to generate without it,\n\
+                    and is skipped by '--keys all' if this is not present.",
+                )
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("TRIE_TYPE")
                 .long("trie-type")
@@ -149,7 +160,8 @@ fn main() -> eyre::Result<()> {
                 .help(
                     "Include these resource keys in the output. Accepts multiple arguments.\n\
                     Set to 'all' for all keys, 'experimental-all' to include experimental keys,\n\
-                    or 'none' for no keys.",
+                    or 'none' for no keys. 'all' and 'experimental-all' skip time_zone/offset_transitions@1\n\
+                    unless --zoneinfo-root is present.",
                 ),
         )
         .arg(
@@ -265,25 +277,44 @@ fn main() -> eyre::Result<()> {
         }
     };
 
-    let selected_keys = if matches.is_present("ALL_KEYS") {
-        icu_datagen::all_keys()
+    let (mut selected_keys, all_keys_selected) = if matches.is_present("ALL_KEYS") {
+        (icu_datagen::all_keys(), true)
     } else if let Some(paths) = matches.values_of("KEYS") {
         match paths.collect::<Vec<_>>().as_slice() {
-            ["none"] => vec![],
-            ["all"] => icu_datagen::all_keys(),
-            ["experimental-all"] => icu_datagen::all_keys_with_experimental(),
-            keys => icu_datagen::keys(keys),
+            ["none"] => (vec![], false),
+            ["all"] => (icu_datagen::all_keys(), true),
+            ["experimental-all"] => (icu_datagen::all_keys_with_experimental(), true),
+            keys => (icu_datagen::keys(keys), false),
         }
     } else if let Some(key_file_path) = matches.value_of_os("KEY_FILE") {
-        icu_datagen::keys_from_file(key_file_path)
-            .with_context(|| key_file_path.to_string_lossy().into_owned())?
+        (
+            icu_datagen::keys_from_file(key_file_path)
+                .with_context(|| key_file_path.to_string_lossy().into_owned())?,
+            false,
+        )
     } else if let Some(bin_path) = matches.value_of_os("KEYS_FOR_BIN") {
-        icu_datagen::keys_from_bin(bin_path)
-            .with_context(|| bin_path.to_string_lossy().into_owned())?
+        (
+            icu_datagen::keys_from_bin(bin_path)
+                .with_context(|| bin_path.to_string_lossy().into_owned())?,
+            false,
+        )
     } else {
         unreachable!("required group")
     };
 
+    // The offset transitions can only be generated from TZif files, so selecting all keys
+    // should not fail just because no zoneinfo root was given.
+    if all_keys_selected && !matches.is_present("ZONEINFO_ROOT") {
+        let key = icu_timezone::provider::ZoneOffsetTransitionsV1Marker::KEY;
+        if selected_keys.contains(&key) {
+            log::warn!(
+                "Skipping {} because --zoneinfo-root is not present",
+                key.path().get()
+            );
+            selected_keys.retain(|k| k != &key);
+        }
+    }
+
     if selected_keys.is_empty() {
         log::warn!("No keys selected");
     }
@@ -307,6 +338,10 @@ fn main() -> eyre::Result<()> {
         source_data = source_data.with_icuexport_for_tag(tag)?;
     }
 
+    if let Some(path) = matches.value_of("ZONEINFO_ROOT") {
+        source_data = source_data.with_zoneinfo(PathBuf::from(path))?;
+    }
+
     if matches.value_of("TRIE_TYPE") == Some("fast") {
         source_data = source_data.with_fast_tries();
     }
@@ -10,6 +10,9 @@ pub(crate) const MISSING_CLDR_ERROR: DataError =
 pub(crate) const MISSING_ICUEXPORT_ERROR: DataError =
     DataErrorKind::MissingSourceData.with_str_context("icuexport");
 
+pub(crate) const MISSING_ZONEINFO_ERROR: DataError =
+    DataErrorKind::MissingSourceData.with_str_context("zoneinfo");
+
 /// Identifies errors that are due to missing CLDR data.
 ///
 /// See [`datagen`](crate::datagen).
@@ -26,6 +29,14 @@ pub fn is_missing_icuexport_error(mut e: DataError) -> bool {
     e == MISSING_ICUEXPORT_ERROR
 }
 
+/// Identifies errors that are due to missing time zone database data.
+///
+/// See [`datagen`](crate::datagen).
+pub fn is_missing_zoneinfo_error(mut e: DataError) -> bool {
+    e.key = None;
+    e == MISSING_ZONEINFO_ERROR
+}
+
 pub(crate) fn data_error_from_toml(other: toml::de::Error) -> DataError {
     DataError::custom("Toml deserialize").with_display_context(&other)
 }
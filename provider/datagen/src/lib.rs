@@ -45,6 +45,9 @@
 //! >    --out data.postcard
 //! ```
 
+//! `--keys all` skips the `time_zone/offset_transitions@1` key unless `--zoneinfo-root` points
+//! to a directory of compiled TZif files, such as `/usr/share/zoneinfo`.
+//!
 //! More details can be found by running `--help`.
 
 #![cfg_attr(
@@ -70,7 +73,7 @@ mod source;
 mod testutil;
 mod transform;
 
-pub use error::{is_missing_cldr_error, is_missing_icuexport_error};
+pub use error::{is_missing_cldr_error, is_missing_icuexport_error, is_missing_zoneinfo_error};
 pub use registry::{all_keys, all_keys_with_experimental};
 pub use source::{CldrLocaleSubset, CollationHanDatabase, SourceData};
 
@@ -291,9 +294,9 @@ pub enum Out {
 ///   contained (strictly, i.e. `en` != `en-US`) in the slice will be generated.
 ///   Otherwise, all locales supported by the source data will be generated.
 /// * `keys`: The keys for which to generate data. See [`all_keys`], [`keys`], [`keys_from_file`], [`keys_from_bin`].
-/// * `sources`: The underlying source data. CLDR and/or ICU data can be missing if no
-///   requested key requires them, otherwise an error satisfying [`is_missing_cldr_error`]
-///   or [`is_missing_icuexport_error`] will be returned.
+/// * `sources`: The underlying source data. CLDR, ICU, and/or zoneinfo data can be missing if no
+///   requested key requires them, otherwise an error satisfying [`is_missing_cldr_error`],
+///   [`is_missing_icuexport_error`], or [`is_missing_zoneinfo_error`] will be returned.
 /// * `out`: The output format and location. See the documentation on [`Out`]
 pub fn datagen(
     locales: Option<&[LanguageIdentifier]>,
@@ -11,6 +11,7 @@ use icu_compactdecimal::provider::*;
 use icu_datetime::provider::calendar::*;
 use icu_datetime::provider::time_zones::*;
 use icu_decimal::provider::*;
+use icu_dimension::provider::*;
 use icu_displaynames::provider::*;
 use icu_list::provider::*;
 use icu_locid_transform::provider::*;
@@ -19,6 +20,7 @@ use icu_plurals::provider::*;
 use icu_properties::provider::*;
 use icu_provider::hello_world::HelloWorldV1Marker;
 use icu_provider_adapters::fallback::provider::*;
+use icu_rbnf::provider::*;
 use icu_relativetime::provider::*;
 use icu_segmenter::provider::*;
 use icu_timezone::provider::*;
@@ -85,12 +87,14 @@ macro_rules! registry {
 }
 
 registry!(
+    AccountingSymbolsV1Marker,
     AliasesV1Marker,
     AlnumV1Marker,
     AlphabeticV1Marker,
     AndListV1Marker,
     AsciiHexDigitV1Marker,
     BasicEmojiV1Marker,
+    Bcp47ToIanaMapV1Marker,
     BidiClassV1Marker,
     BidiControlV1Marker,
     BidiMirroredV1Marker,
@@ -154,6 +158,7 @@ registry!(
     GregorianDateSymbolsV1Marker,
     HexDigitV1Marker,
     HyphenV1Marker,
+    IanaToBcp47MapV1Marker,
     IdContinueV1Marker,
     IdeographicV1Marker,
     IdsBinaryOperatorV1Marker,
@@ -186,14 +191,17 @@ registry!(
     NfkdInertV1Marker,
     NoncharacterCodePointV1Marker,
     NonRecursiveDecompositionSupplementV1Marker,
+    NotationSymbolsV1Marker,
     OrdinalV1Marker,
     OrListV1Marker,
     PatternSyntaxV1Marker,
     PatternWhiteSpaceV1Marker,
+    PluralRangesV1Marker,
     PrependedConcatenationMarkV1Marker,
     PrintV1Marker,
     QuotationMarkV1Marker,
     RadicalV1Marker,
+    RangePatternsV1Marker,
     RegionalIndicatorV1Marker,
     ScriptV1Marker,
     ScriptWithExtensionsPropertyV1Marker,
@@ -212,10 +220,12 @@ registry!(
     VariationSelectorV1Marker,
     WeekDataV1Marker,
     WhiteSpaceV1Marker,
+    WindowsZonesMapV1Marker,
     WordBreakV1Marker,
     XdigitV1Marker,
     XidContinueV1Marker,
     XidStartV1Marker,
+    ZoneOffsetTransitionsV1Marker,
     # experimental #
     {
         CaseMappingV1Marker,
@@ -253,7 +263,17 @@ registry!(
         ShortYearRelativeTimeFormatDataV1Marker,
         NarrowYearRelativeTimeFormatDataV1Marker,
         LongCompactDecimalFormatDataV1Marker,
+        ShortCompactCurrencyFormatDataV1Marker,
         ShortCompactDecimalFormatDataV1Marker,
+        CurrencyEssentialsV1Marker,
+        CurrencyFractionsV1Marker,
+        CurrencyNamesV1Marker,
+        UnitsConversionV1Marker,
+        UnitsDisplayNamesV1Marker,
+        UnitPreferencesV1Marker,
+        RbnfRulesV1Marker,
+        CardinalSamplesV1Marker,
+        OrdinalSamplesV1Marker,
     }
 );
 
@@ -26,6 +26,7 @@ pub struct SourceData {
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_paths: Arc<SerdeCache>,
     segmenter_lstm_paths: Arc<SerdeCache>,
+    zoneinfo_path: Option<Arc<PathBuf>>,
     trie_type: IcuTrieType,
     collation_han_database: CollationHanDatabase,
     collations: Vec<String>,
@@ -44,6 +45,7 @@ impl Default for SourceData {
             segmenter_lstm_paths: Arc::new(SerdeCache::new(
                 AbstractFs::new(segmenter_path.join("lstm")).expect("valid dir"),
             )),
+            zoneinfo_path: None,
             trie_type: IcuTrieType::Small,
             collation_han_database: CollationHanDatabase::Implicit,
             collations: vec![],
@@ -68,6 +70,8 @@ impl SourceData {
             .expect("testdata is valid")
             .with_icuexport(icu_testdata::paths::icuexport_toml_root())
             .expect("testdata is valid")
+            .with_zoneinfo(icu_testdata::paths::zoneinfo_root())
+            .expect("testdata is valid")
     }
 
     /// Adds CLDR data to this `DataSource`. The root should point to a local
@@ -105,6 +109,21 @@ impl SourceData {
         })
     }
 
+    /// Adds time zone database data to this `DataSource`. The root should point to a local
+    /// directory of compiled TZif files, such as `/usr/share/zoneinfo`, with files named after
+    /// their IANA time zone identifiers (e.g. `America/Los_Angeles`).
+    pub fn with_zoneinfo(self, root: PathBuf) -> Result<Self, DataError> {
+        if !root.is_dir() {
+            return Err(
+                DataError::custom("zoneinfo root is not a directory").with_path_context(&root)
+            );
+        }
+        Ok(Self {
+            zoneinfo_path: Some(Arc::new(root)),
+            ..self
+        })
+    }
+
     /// Adds CLDR data to this `DataSource`. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -201,6 +220,14 @@ impl SourceData {
             .ok_or(crate::error::MISSING_ICUEXPORT_ERROR)
     }
 
+    /// Path to the compiled time zone database.
+    pub(crate) fn zoneinfo(&self) -> Result<&Path, DataError> {
+        self.zoneinfo_path
+            .as_deref()
+            .map(PathBuf::as_path)
+            .ok_or(crate::error::MISSING_ZONEINFO_ERROR)
+    }
+
     /// Path to segmenter data.
     pub(crate) fn segmenter(&self) -> Result<&SerdeCache, DataError> {
         Ok(&self.segmenter_paths)
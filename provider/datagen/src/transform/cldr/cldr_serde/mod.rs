@@ -9,6 +9,8 @@
 
 pub mod aliases;
 pub mod ca;
+pub mod currencies;
+pub mod currency_data;
 pub mod date_fields;
 pub mod exemplar_chars;
 pub mod japanese;
@@ -18,7 +20,11 @@ pub mod list_patterns;
 pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
+pub mod plural_ranges;
 pub mod plurals;
+pub mod rbnf;
 pub mod region_displaynames;
 pub mod time_zones;
+pub mod units;
+pub mod units_data;
 pub mod week_data;
@@ -23,6 +23,25 @@ pub struct Symbols {
     pub minus_sign: String,
     #[serde(rename = "plusSign")]
     pub plus_sign: String,
+    #[serde(rename = "percentSign")]
+    pub percent_sign: String,
+    #[serde(rename = "perMille")]
+    pub per_mille: String,
+    pub exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub superscripting_exponent: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct PercentFormats {
+    pub standard: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct MiscPatterns {
+    // This list is not comprehensive; add more fields when needed
+    pub approximately: String,
+    pub range: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -38,12 +57,57 @@ pub struct DecimalFormatLength {
     pub decimal_format: DecimalFormat,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacingPattern {
+    #[serde(rename = "insertBetween")]
+    pub insert_between: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencySpacing {
+    #[serde(rename = "beforeCurrency")]
+    pub before_currency: CurrencySpacingPattern,
+    #[serde(rename = "afterCurrency")]
+    pub after_currency: CurrencySpacingPattern,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyFormats {
+    // This list is not comprehensive; add more fields when needed
+    #[serde(rename = "currencySpacing")]
+    pub currency_spacing: CurrencySpacing,
+    pub standard: String,
+    #[serde(rename = "standard-alphaNextToNumber")]
+    pub standard_alpha_next_to_number: Option<String>,
+    pub accounting: String,
+    #[serde(rename = "accounting-alphaNextToNumber")]
+    pub accounting_alpha_next_to_number: Option<String>,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: String,
+    pub short: Option<CurrencyFormatLength>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct CurrencyFormatLength {
+    pub standard: DecimalFormat,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub struct DecimalFormat {
     pub patterns: Vec<CompactDecimalPattern>,
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Debug, Default, Clone)]
 pub struct CompactDecimalPattern {
     pub compact_decimal_type: String,
     pub compact_decimal_count: String,
@@ -93,6 +157,12 @@ pub struct NumberingSystemData {
     pub symbols: HashMap<TinyStr8, Symbols>,
     /// Map from numbering system to decimal formats
     pub formats: HashMap<TinyStr8, DecimalFormats>,
+    /// Map from numbering system to currency formats
+    pub currency_formats: HashMap<TinyStr8, CurrencyFormats>,
+    /// Map from numbering system to percent formats
+    pub percent_formats: HashMap<TinyStr8, PercentFormats>,
+    /// Map from numbering system to miscellaneous patterns
+    pub misc_patterns: HashMap<TinyStr8, MiscPatterns>,
 }
 
 pub struct NumberingSystemDataVisitor;
@@ -128,8 +198,20 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: DecimalFormats = access.next_value()?;
                     result.formats.insert(numsys, value);
                 }
+                "currencyFormats" => {
+                    let value: CurrencyFormats = access.next_value()?;
+                    result.currency_formats.insert(numsys, value);
+                }
+                "percentFormats" => {
+                    let value: PercentFormats = access.next_value()?;
+                    result.percent_formats.insert(numsys, value);
+                }
+                "miscPatterns" => {
+                    let value: MiscPatterns = access.next_value()?;
+                    result.misc_patterns.insert(numsys, value);
+                }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume "scientificFormats", ...
                     // For now, ignore them.
                 }
             }
@@ -147,10 +229,21 @@ impl<'de> Deserialize<'de> for NumberingSystemData {
     }
 }
 
+/// The numbering systems behind the CLDR `-u-nu` aliases of a locale.
+#[derive(PartialEq, Debug, Default, Deserialize)]
+pub struct OtherNumberingSystems {
+    pub native: Option<TinyStr8>,
+    pub traditional: Option<TinyStr8>,
+    pub finance: Option<TinyStr8>,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub struct Numbers {
     #[serde(rename = "defaultNumberingSystem")]
     pub default_numbering_system: TinyStr8,
+    #[serde(rename = "otherNumberingSystems")]
+    #[serde(default)]
+    pub other_numbering_systems: OtherNumberingSystems,
     #[serde(rename = "minimumGroupingDigits")]
     #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
     pub minimum_grouping_digits: u8,
@@ -0,0 +1,26 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON pluralRanges.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/pluralRanges.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// A map from ranges of the form "start+end", such as "one+other", to the category of the range.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LocalePluralRanges(pub BTreeMap<String, String>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    pub plurals: HashMap<LanguageIdentifier, LocalePluralRanges>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
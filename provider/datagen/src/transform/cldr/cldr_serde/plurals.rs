@@ -23,6 +23,8 @@ pub struct LocalePluralRules {
     pub few: Option<String>,
     #[serde(rename = "pluralRule-count-many")]
     pub many: Option<String>,
+    #[serde(rename = "pluralRule-count-other")]
+    pub other: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
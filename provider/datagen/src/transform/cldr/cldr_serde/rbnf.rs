@@ -0,0 +1,25 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON rule-based number format files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-rbnf/rbnf/en.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The rules of a rule set, as pairs of descriptors and rule bodies.
+pub type RuleSet = Vec<(String, String)>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Rbnf {
+    /// The rule sets of the locale by rule set group, such as `SpelloutRules`, and name.
+    pub rbnf: BTreeMap<String, BTreeMap<String, RuleSet>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub rbnf: Rbnf,
+}
@@ -23,6 +23,8 @@ pub struct Bcp47TzidAliasData {
     pub alias: Option<String>,
     #[serde(rename = "_since")]
     pub since: Option<String>,
+    #[serde(rename = "_iana")]
+    pub iana: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -5,3 +5,4 @@
 pub mod bcp47_tzid;
 pub mod meta_zones;
 pub mod time_zone_names;
+pub mod windows_zones;
@@ -0,0 +1,43 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON windowsZones.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/windowsZones.json>
+
+use serde::Deserialize;
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct MapZone {
+    #[serde(rename = "_other")]
+    pub windows_id: String,
+    #[serde(rename = "_type")]
+    pub iana_ids: String,
+    #[serde(rename = "_territory")]
+    pub territory: String,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct MapTimezone {
+    #[serde(rename = "mapZone")]
+    pub map_zone: MapZone,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct WindowsZones {
+    #[serde(rename = "mapTimezones")]
+    pub map_timezones: Vec<MapTimezone>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct Supplemental {
+    #[serde(rename = "windowsZones")]
+    pub windows_zones: WindowsZones,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
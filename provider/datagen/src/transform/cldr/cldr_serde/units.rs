@@ -0,0 +1,56 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON units.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-units-full/main/en/units.json>
+
+use icu_locid::LanguageIdentifier;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// The patterns of a unit, such as `length-meter`, or of a compound unit pattern, such as
+/// `per`.
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Patterns {
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "unitPattern-count-zero")]
+    pub unit_pattern_zero: Option<String>,
+    #[serde(rename = "unitPattern-count-one")]
+    pub unit_pattern_one: Option<String>,
+    #[serde(rename = "unitPattern-count-two")]
+    pub unit_pattern_two: Option<String>,
+    #[serde(rename = "unitPattern-count-few")]
+    pub unit_pattern_few: Option<String>,
+    #[serde(rename = "unitPattern-count-many")]
+    pub unit_pattern_many: Option<String>,
+    #[serde(rename = "unitPattern-count-other")]
+    pub unit_pattern_other: Option<String>,
+    #[serde(rename = "perUnitPattern")]
+    pub per_unit_pattern: Option<String>,
+    #[serde(rename = "compoundUnitPattern")]
+    pub compound_unit_pattern: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Units {
+    pub long: BTreeMap<String, Patterns>,
+    pub short: BTreeMap<String, Patterns>,
+    pub narrow: BTreeMap<String, Patterns>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangUnits {
+    pub units: Units,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct LangData(pub HashMap<LanguageIdentifier, LangUnits>);
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub main: LangData,
+}
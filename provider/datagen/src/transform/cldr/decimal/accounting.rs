@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+impl DataProvider<AccountingSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<AccountingSymbolsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::numbering_system_for(numbers, req.locale);
+
+        let symbols = numbers.numsys_data.symbols.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(&nsname)
+        })?;
+        let currency_formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let accounting_pattern: super::decimal_pattern::DecimalPattern = currency_formats
+            .accounting
+            .parse()
+            .map_err(|e: super::decimal_pattern::Error| {
+                DataError::custom("Could not parse accounting pattern")
+                    .with_display_context(&e)
+                    .with_display_context(&nsname)
+            })?;
+        let affixes = accounting_pattern.localize_sign(&symbols.minus_sign);
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(AccountingSymbolsV1 {
+                minus_sign_affixes: AffixesV1 {
+                    prefix: without_currency(&affixes.prefix),
+                    suffix: without_currency(&affixes.suffix),
+                },
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<AccountingSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+/// Removes the currency symbol placeholder, and the spacing next to it, from an affix of a
+/// currency pattern, such as "(¤\u{a0}" in Dutch.
+fn without_currency(affix: &str) -> Cow<'static, str> {
+    match affix.split_once('¤') {
+        Some((before, after)) => Cow::Owned(format!("{}{}", before.trim_end(), after.trim_start())),
+        None => Cow::Owned(affix.to_owned()),
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let load = |locale: &icu_locid::Locale| -> DataPayload<AccountingSymbolsV1Marker> {
+        provider
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en = load(&locale!("en"));
+    assert_eq!(en.get().minus_sign_affixes.prefix, "(");
+    assert_eq!(en.get().minus_sign_affixes.suffix, ")");
+
+    let fr = load(&locale!("fr"));
+    assert_eq!(fr.get().minus_sign_affixes.prefix, "(");
+    assert_eq!(fr.get().minus_sign_affixes.suffix, ")");
+
+    let es = load(&locale!("es"));
+    assert_eq!(es.get().minus_sign_affixes.prefix, "-");
+    assert_eq!(es.get().minus_sign_affixes.suffix, "");
+}
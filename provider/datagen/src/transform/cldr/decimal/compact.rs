@@ -4,7 +4,6 @@
 
 use crate::transform::cldr::cldr_serde;
 use icu_compactdecimal::provider::*;
-use icu_locid::extensions_unicode_key as key;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
 use std::convert::TryFrom;
@@ -29,13 +28,7 @@ impl DataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvi
             .expect("CLDR file contains the expected language")
             .numbers;
 
-        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
-            Some(v) => *v
-                .as_tinystr_slice()
-                .first()
-                .expect("expecting subtag if key is present"),
-            None => numbers.default_numbering_system,
-        };
+        let nsname = super::numbering_system_for(numbers, req.locale);
 
         let result = CompactDecimalPatternDataV1::try_from(
             &numbers
@@ -82,13 +75,7 @@ impl DataProvider<LongCompactDecimalFormatDataV1Marker> for crate::DatagenProvid
             .expect("CLDR file contains the expected language")
             .numbers;
 
-        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
-            Some(v) => *v
-                .as_tinystr_slice()
-                .first()
-                .expect("expecting subtag if key is present"),
-            None => numbers.default_numbering_system,
-        };
+        let nsname = super::numbering_system_for(numbers, req.locale);
 
         let result = CompactDecimalPatternDataV1::try_from(
             &numbers
@@ -115,6 +102,87 @@ impl DataProvider<LongCompactDecimalFormatDataV1Marker> for crate::DatagenProvid
     }
 }
 
+impl DataProvider<ShortCompactCurrencyFormatDataV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<ShortCompactCurrencyFormatDataV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::numbering_system_for(numbers, req.locale);
+
+        // As in CLDR, numbering systems without short currency formats use those of latn.
+        let short = [nsname, tinystr::tinystr!(8, "latn")]
+            .iter()
+            .find_map(|nsname| {
+                numbers
+                    .numsys_data
+                    .currency_formats
+                    .get(nsname)?
+                    .short
+                    .as_ref()
+            })
+            .ok_or_else(|| {
+                DataError::custom("Could not find short currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        // The alphaNextToNumber patterns have counts such as "one-alt-alphaNextToNumber".
+        let mut standard = cldr_serde::numbers::DecimalFormat::default();
+        let mut alpha_next_to_number = cldr_serde::numbers::DecimalFormat::default();
+        for pattern in &short.standard.patterns {
+            match pattern
+                .compact_decimal_count
+                .strip_suffix("-alt-alphaNextToNumber")
+            {
+                Some(count) => {
+                    alpha_next_to_number
+                        .patterns
+                        .push(cldr_serde::numbers::CompactDecimalPattern {
+                            compact_decimal_count: count.to_string(),
+                            ..pattern.clone()
+                        })
+                }
+                None => standard.patterns.push(pattern.clone()),
+            }
+        }
+
+        let convert = |format: &cldr_serde::numbers::DecimalFormat| {
+            CompactDecimalPatternDataV1::try_from(format).map_err(|s| {
+                DataError::custom("Could not create compact currency patterns")
+                    .with_display_context(&s)
+                    .with_display_context(&nsname)
+            })
+        };
+        let result = CompactCurrencyPatternDataV1 {
+            standard: convert(&standard)?,
+            alpha_next_to_number: if alpha_next_to_number.patterns.is_empty() {
+                None
+            } else {
+                Some(convert(&alpha_next_to_number)?)
+            },
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(result)),
+        })
+    }
+}
+
 impl IterableDataProvider<ShortCompactDecimalFormatDataV1Marker> for crate::DatagenProvider {
     fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
         self.supported_locales()
@@ -127,6 +195,12 @@ impl IterableDataProvider<LongCompactDecimalFormatDataV1Marker> for crate::Datag
     }
 }
 
+impl IterableDataProvider<ShortCompactCurrencyFormatDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -268,4 +342,53 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_compact_currency_short() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let load =
+            |locale: &icu_locid::Locale| -> DataPayload<ShortCompactCurrencyFormatDataV1Marker> {
+                provider
+                    .load(DataRequest {
+                        locale: &locale.into(),
+                        metadata: Default::default(),
+                    })
+                    .unwrap()
+                    .take_payload()
+                    .unwrap()
+            };
+        fn million<'a>(data: &'a CompactDecimalPatternDataV1<'a>) -> Pattern<'a> {
+            Pattern::zero_from(data.patterns.get_2d(&6, &Count::Other).unwrap())
+        }
+
+        let en = load(&locale!("en"));
+        assert_eq!(
+            million(&en.get().standard),
+            Pattern {
+                index: 2,
+                exponent: 6,
+                literal_text: Cow::Borrowed("¤M")
+            }
+        );
+        assert_eq!(
+            million(en.get().alpha_next_to_number.as_ref().unwrap()),
+            Pattern {
+                index: 4,
+                exponent: 6,
+                literal_text: Cow::Borrowed("¤\u{a0}M")
+            }
+        );
+
+        let fr = load(&locale!("fr"));
+        assert_eq!(
+            million(&fr.get().standard),
+            Pattern {
+                index: 0,
+                exponent: 6,
+                literal_text: Cow::Borrowed("\u{a0}M\u{a0}¤")
+            }
+        );
+        assert_eq!(fr.get().alpha_next_to_number, None);
+    }
 }
@@ -104,9 +104,11 @@ fn parse(pattern: &str) -> Result<Option<ParsedPattern>, Cow<'static, str>> {
             } else {
                 // We are in unquoted text, so we need to check for the
                 // symbols defined in https://www.unicode.org/reports/tr35/tr35-numbers.html#Number_Pattern_Character_Definitions.
+                // The currency sign ¤ of compact currency patterns is kept in
+                // the literal text, where it is replaced at formatting time.
                 if chunk
                     .chars()
-                    .any(|c| ('1'..'9').contains(&c) || "@#.-,E+%‰,¤*'".contains(c))
+                    .any(|c| ('1'..'9').contains(&c) || "@#.-,E+%‰,*'".contains(c))
                 {
                     return Err(
                         format!("Unsupported symbol in compact decimal pattern {pattern}").into(),
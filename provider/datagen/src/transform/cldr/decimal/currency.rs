@@ -0,0 +1,354 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::decimal_pattern::{DecimalPattern, DecimalSubPattern};
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::AffixesV1;
+use icu_dimension::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use tinystr::TinyAsciiStr;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+impl crate::DatagenProvider {
+    fn get_numbers_and_currencies(
+        &self,
+        locale: &DataLocale,
+    ) -> Result<
+        (
+            &cldr_serde::numbers::Numbers,
+            &cldr_serde::currencies::Currencies,
+            TinyAsciiStr<8>,
+        ),
+        DataError,
+    > {
+        let langid = locale.get_langid();
+
+        let numbers = &self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse::<cldr_serde::numbers::Resource>(&langid, "numbers.json")?
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let currencies = &self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse::<cldr_serde::currencies::Resource>(&langid, "currencies.json")?
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::numbering_system_for(numbers, locale);
+
+        Ok((numbers, currencies, nsname))
+    }
+}
+
+impl DataProvider<CurrencyEssentialsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CurrencyEssentialsV1Marker>, DataError> {
+        let (numbers, currencies, nsname) = self.get_numbers_and_currencies(req.locale)?;
+
+        let minus_sign = &numbers
+            .numsys_data
+            .symbols
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find symbols for numbering system")
+                    .with_display_context(&nsname)
+            })?
+            .minus_sign;
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let spacing = &formats.currency_spacing;
+
+        let parse = |pattern: &str| -> Result<DecimalPattern, DataError> {
+            pattern.parse().map_err(|e: super::decimal_pattern::Error| {
+                DataError::custom("Could not parse currency pattern")
+                    .with_display_context(&e)
+                    .with_display_context(pattern)
+            })
+        };
+        let standard = parse(&formats.standard)?;
+        let accounting = parse(&formats.accounting)?;
+        let standard_alpha_next_to_number = match &formats.standard_alpha_next_to_number {
+            Some(pattern) => parse(pattern)?,
+            None => parse(&formats.standard)?.with_currency_spacing(spacing),
+        };
+        let accounting_alpha_next_to_number = match &formats.accounting_alpha_next_to_number {
+            Some(pattern) => parse(pattern)?,
+            None => parse(&formats.accounting)?.with_currency_spacing(spacing),
+        };
+
+        let mut symbols = BTreeMap::new();
+        let mut narrow_symbols = BTreeMap::new();
+        for (code, currency) in currencies.currencies.iter() {
+            let symbol = currency.symbol.as_deref().unwrap_or(code.as_str());
+            if symbol != code.as_str() {
+                symbols.insert(*code, symbol);
+            }
+            if let Some(narrow_symbol) = currency.narrow_symbol.as_deref() {
+                if narrow_symbol != symbol {
+                    narrow_symbols.insert(*code, narrow_symbol);
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyEssentialsV1 {
+                standard: standard.localize_currency_pattern(minus_sign),
+                standard_alpha_next_to_number: standard_alpha_next_to_number
+                    .localize_currency_pattern(minus_sign),
+                accounting: accounting.localize_currency_pattern(minus_sign),
+                accounting_alpha_next_to_number: accounting_alpha_next_to_number
+                    .localize_currency_pattern(minus_sign),
+                symbols: symbols.into_iter().collect::<ZeroMap<_, _>>(),
+                narrow_symbols: narrow_symbols.into_iter().collect::<ZeroMap<_, _>>(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyEssentialsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+impl DataProvider<CurrencyNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyNamesV1Marker>, DataError> {
+        let (numbers, currencies, nsname) = self.get_numbers_and_currencies(req.locale)?;
+
+        let formats = numbers
+            .numsys_data
+            .currency_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find currency formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        let unit_patterns = [
+            (Count::Zero, formats.unit_pattern_zero.as_deref()),
+            (Count::One, formats.unit_pattern_one.as_deref()),
+            (Count::Two, formats.unit_pattern_two.as_deref()),
+            (Count::Few, formats.unit_pattern_few.as_deref()),
+            (Count::Many, formats.unit_pattern_many.as_deref()),
+            (Count::Other, Some(formats.unit_pattern_other.as_str())),
+        ]
+        .into_iter()
+        .filter_map(|(count, pattern)| Some((count, pattern?)))
+        .collect::<ZeroMap<_, _>>();
+
+        let mut names = ZeroMap2d::new();
+        for (code, currency) in currencies.currencies.iter() {
+            for (count, name) in [
+                (Count::Zero, currency.display_name_zero.as_deref()),
+                (Count::One, currency.display_name_one.as_deref()),
+                (Count::Two, currency.display_name_two.as_deref()),
+                (Count::Few, currency.display_name_few.as_deref()),
+                (Count::Many, currency.display_name_many.as_deref()),
+                (
+                    Count::Other,
+                    currency
+                        .display_name_other
+                        .as_deref()
+                        .or(currency.display_name.as_deref()),
+                ),
+            ] {
+                if let Some(name) = name {
+                    names.insert(code, &count, name);
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyNamesV1 {
+                unit_patterns,
+                names,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+impl DataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<CurrencyFractionsV1Marker>, DataError> {
+        let resource: &cldr_serde::currency_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        let to_fraction_info = |fraction: &cldr_serde::currency_data::Fraction| FractionInfo {
+            digits: fraction.digits,
+            rounding: fraction.rounding,
+            cash_digits: fraction.cash_digits.unwrap_or(fraction.digits),
+            cash_rounding: fraction.cash_rounding.unwrap_or(fraction.rounding),
+        };
+
+        let mut default = None;
+        let mut fractions = BTreeMap::new();
+        for (code, fraction) in resource.supplemental.currency_data.fractions.iter() {
+            if code.as_str() == "DEFAULT" {
+                default = Some(to_fraction_info(fraction));
+            } else {
+                let code = code.as_str().parse::<TinyAsciiStr<3>>().map_err(|_| {
+                    DataError::custom("Invalid currency code").with_display_context(code)
+                })?;
+                fractions.insert(code, to_fraction_info(fraction));
+            }
+        }
+        let default = default.ok_or_else(|| DataError::custom("Missing DEFAULT fraction data"))?;
+        fractions.retain(|_, fraction_info| *fraction_info != default);
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CurrencyFractionsV1 {
+                fractions: fractions.into_iter().collect(),
+                default,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<CurrencyFractionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DecimalSubPattern {
+    /// Inserts the CLDR currency spacing between the currency placeholder and the number if
+    /// they are adjacent.
+    fn with_currency_spacing(mut self, spacing: &cldr_serde::numbers::CurrencySpacing) -> Self {
+        if self.prefix.ends_with(CURRENCY_PLACEHOLDER) {
+            self.prefix
+                .push_str(&spacing.before_currency.insert_between);
+        }
+        if self.suffix.starts_with(CURRENCY_PLACEHOLDER) {
+            self.suffix
+                .insert_str(0, &spacing.after_currency.insert_between);
+        }
+        self
+    }
+}
+
+impl DecimalPattern {
+    /// Inserts the CLDR currency spacing between the currency placeholder and the number if
+    /// they are adjacent.
+    fn with_currency_spacing(self, spacing: &cldr_serde::numbers::CurrencySpacing) -> Self {
+        Self {
+            positive: self.positive.with_currency_spacing(spacing),
+            negative: self
+                .negative
+                .map(|subpattern| subpattern.with_currency_spacing(spacing)),
+        }
+    }
+
+    fn localize_currency_pattern(&self, minus_sign: &str) -> CurrencyPatternV1<'static> {
+        // UTS 35: the absence of a negative pattern means the positive pattern with a
+        // prefixed minus sign
+        let (negative_prefix, negative_suffix) = match &self.negative {
+            Some(subpattern) => (subpattern.prefix.clone(), subpattern.suffix.clone()),
+            None => (
+                format!("-{}", self.positive.prefix),
+                self.positive.suffix.clone(),
+            ),
+        };
+        CurrencyPatternV1 {
+            positive: AffixesV1 {
+                prefix: Cow::Owned(self.positive.prefix.clone()),
+                suffix: Cow::Owned(self.positive.suffix.clone()),
+            },
+            negative: AffixesV1 {
+                prefix: Cow::Owned(negative_prefix.replace('-', minus_sign)),
+                suffix: Cow::Owned(negative_suffix.replace('-', minus_sign)),
+            },
+        }
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let en: DataPayload<CurrencyEssentialsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(en.get().standard.positive.prefix, "¤");
+    assert_eq!(en.get().standard.negative.prefix, "-¤");
+    assert_eq!(
+        en.get().standard_alpha_next_to_number.positive.prefix,
+        "¤\u{a0}"
+    );
+    assert_eq!(en.get().accounting.negative.prefix, "(¤");
+    assert_eq!(en.get().accounting.negative.suffix, ")");
+    assert_eq!(
+        en.get().symbols.get(&tinystr::tinystr!(3, "USD")),
+        Some("$")
+    );
+    assert_eq!(en.get().symbols.get(&tinystr::tinystr!(3, "CHF")), None);
+
+    // Japanese has no standard-alphaNextToNumber pattern, so currency spacing is inserted.
+    let ja: DataPayload<CurrencyEssentialsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ja").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        ja.get().standard_alpha_next_to_number.positive.prefix,
+        "¤\u{a0}"
+    );
+
+    let fractions: DataPayload<CurrencyFractionsV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(fractions.get().default.digits, 2);
+    assert_eq!(
+        fractions
+            .get()
+            .fractions
+            .get_copied(&tinystr::tinystr!(3, "CHF"))
+            .map(|fraction_info| fraction_info.cash_rounding),
+        Some(5)
+    );
+}
@@ -55,14 +55,25 @@ impl FromStr for DecimalSubPattern {
         let body = &subpattern[i..j];
         let suffix = &subpattern[j..];
 
-        // For now, we expect one of a handful of pattern bodies.
-        // TODO(#567): Generalize this to support all of UTS 35.
-        let (a, b, c, d) = match body {
-            "#,##0.###" => (3, 3, 0, 3),
-            "#,##,##0.###" => (3, 2, 0, 3),
-            "0.######" => (0, 0, 0, 6),
-            _ => return Err(Error::UnknownPatternBody(body.to_string())),
+        let (integer, fraction) = body.split_once('.').unwrap_or((body, ""));
+        // The integer part is '#'s followed by '0's, possibly with grouping separators,
+        // and the fraction part is '0's followed by '#'s.
+        let is_valid = !integer.trim_start_matches(['#', ',']).contains('#')
+            && !fraction.trim_start_matches('0').contains(['0', ','])
+            && !fraction.ends_with(',');
+        if !is_valid {
+            return Err(Error::UnknownPatternBody(body.to_string()));
+        }
+        let mut groups = integer.rsplit(',').map(|group| group.len() as u8);
+        let primary_grouping = groups.next().filter(|_| integer.contains(','));
+        let secondary_grouping = groups.next().filter(|_| integer.matches(',').count() > 1);
+        let (a, b) = match (primary_grouping, secondary_grouping) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None) => (a, a),
+            _ => (0, 0),
         };
+        let c = fraction.matches('0').count() as u8;
+        let d = fraction.len() as u8;
         Ok(Self {
             prefix: prefix.into(),
             suffix: suffix.into(),
@@ -199,6 +210,27 @@ fn test_basic() {
                 }),
             }),
         },
+        TestCase {
+            pattern: "¤#,##,##0.00;(¤#,##,##0.00)",
+            expected: Ok(DecimalPattern {
+                positive: DecimalSubPattern {
+                    prefix: "¤".into(),
+                    suffix: "".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 2,
+                    min_fraction_digits: 2,
+                    max_fraction_digits: 2,
+                },
+                negative: Some(DecimalSubPattern {
+                    prefix: "(¤".into(),
+                    suffix: ")".into(),
+                    primary_grouping: 3,
+                    secondary_grouping: 2,
+                    min_fraction_digits: 2,
+                    max_fraction_digits: 2,
+                }),
+            }),
+        },
         TestCase {
             pattern: "xyz",
             expected: Err(Error::NoBodyInSubpattern),
@@ -9,11 +9,50 @@ use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 use tinystr::TinyAsciiStr;
 
+mod accounting;
 mod compact;
 mod compact_decimal_pattern;
+mod currency;
 mod decimal_pattern;
+mod notation;
+mod range;
 mod symbols;
 
+/// The CLDR `-u-nu` aliases that name one of the numbering systems of a locale.
+const NUMBERING_SYSTEM_ALIASES: [&str; 3] = ["native", "traditional", "finance"];
+
+/// Returns the alias target of `alias` in `numbers`, if the locale defines it.
+fn resolve_alias(numbers: &cldr_serde::numbers::Numbers, alias: &str) -> Option<TinyAsciiStr<8>> {
+    let other = &numbers.other_numbering_systems;
+    match alias {
+        "native" => other.native,
+        "traditional" => other.traditional,
+        "finance" => other.finance,
+        _ => None,
+    }
+}
+
+/// Returns the numbering system selected by the `-u-nu` keyword of `locale`, or the default
+/// numbering system of the locale if the keyword is absent.
+///
+/// The CLDR aliases "native", "traditional" and "finance" are resolved to the numbering
+/// system they stand for in the locale.
+fn numbering_system_for(
+    numbers: &cldr_serde::numbers::Numbers,
+    locale: &DataLocale,
+) -> TinyAsciiStr<8> {
+    match locale.get_unicode_ext(&key!("nu")) {
+        Some(v) => {
+            let nsname = *v
+                .as_tinystr_slice()
+                .first()
+                .expect("expecting subtag if key is present");
+            resolve_alias(numbers, &nsname).unwrap_or(nsname)
+        }
+        None => numbers.default_numbering_system,
+    }
+}
+
 impl crate::DatagenProvider {
     /// Returns the digits for the given numbering system name.
     fn get_digits_for_numbering_system(
@@ -68,12 +107,25 @@ impl crate::DatagenProvider {
             .expect("CLDR file contains the expected language")
             .numbers;
 
+        // An alias is only listed if it leads away from the default numbering system; otherwise
+        // runtime fallback, which drops the `-u-nu` keyword, already finds the right data.
+        let aliases = NUMBERING_SYSTEM_ALIASES.iter().filter_map(|alias| {
+            let nsname = resolve_alias(numbers, alias)?;
+            if nsname == numbers.default_numbering_system
+                || !numbers.numsys_data.symbols.contains_key(&nsname)
+            {
+                return None;
+            }
+            TinyAsciiStr::from_str(alias).ok()
+        });
+
         Ok(numbers
             .numsys_data
             .symbols
             .keys()
             .filter(|nsname| **nsname != numbers.default_numbering_system)
             .copied()
+            .chain(aliases)
             .collect())
     }
 
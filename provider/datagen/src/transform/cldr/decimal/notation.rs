@@ -0,0 +1,103 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+impl DataProvider<NotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<NotationSymbolsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::numbering_system_for(numbers, req.locale);
+
+        let symbols = numbers.numsys_data.symbols.get(&nsname).ok_or_else(|| {
+            DataError::custom("Could not find symbols for numbering system")
+                .with_display_context(&nsname)
+        })?;
+        let percent_formats = numbers
+            .numsys_data
+            .percent_formats
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find percent formats for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+        let percent_pattern: super::decimal_pattern::DecimalPattern = percent_formats
+            .standard
+            .parse()
+            .map_err(|e: super::decimal_pattern::Error| {
+                DataError::custom("Could not parse percent pattern")
+                    .with_display_context(&e)
+                    .with_display_context(&nsname)
+            })?;
+        // The permille pattern is the percent pattern with the permille sign.
+        let localize = |sign: &str| AffixesV1 {
+            prefix: Cow::Owned(percent_pattern.positive.prefix.replace('%', sign)),
+            suffix: Cow::Owned(percent_pattern.positive.suffix.replace('%', sign)),
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(NotationSymbolsV1 {
+                percent_affixes: localize(&symbols.percent_sign),
+                permille_affixes: localize(&symbols.per_mille),
+                exponent_separator: Cow::Owned(symbols.exponential.clone()),
+                superscripting_exponent: Cow::Owned(symbols.superscripting_exponent.clone()),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<NotationSymbolsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let load = |locale: &icu_locid::Locale| -> DataPayload<NotationSymbolsV1Marker> {
+        provider
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let fr = load(&locale!("fr"));
+    assert_eq!(fr.get().percent_affixes.prefix, "");
+    assert_eq!(fr.get().percent_affixes.suffix, "\u{a0}%");
+    assert_eq!(fr.get().permille_affixes.suffix, "\u{a0}‰");
+
+    let tr = load(&locale!("tr"));
+    assert_eq!(tr.get().percent_affixes.prefix, "%");
+    assert_eq!(tr.get().percent_affixes.suffix, "");
+
+    let ar = load(&locale!("ar-EG"));
+    assert_eq!(ar.get().exponent_separator, "اس");
+    assert_eq!(ar.get().superscripting_exponent, "×");
+}
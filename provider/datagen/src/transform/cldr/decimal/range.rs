@@ -0,0 +1,105 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_decimal::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+
+impl DataProvider<RangePatternsV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RangePatternsV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .source
+            .cldr()?
+            .numbers()
+            .read_and_parse(&langid, "numbers.json")?;
+
+        let numbers = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .numbers;
+
+        let nsname = super::numbering_system_for(numbers, req.locale);
+
+        let misc_patterns = numbers
+            .numsys_data
+            .misc_patterns
+            .get(&nsname)
+            .ok_or_else(|| {
+                DataError::custom("Could not find misc patterns for numbering system")
+                    .with_display_context(&nsname)
+            })?;
+
+        let invalid = |pattern: &str| {
+            DataError::custom("Could not parse misc pattern")
+                .with_display_context(pattern)
+                .with_display_context(&nsname)
+        };
+        let (range_prefix, range_separator, range_suffix) = misc_patterns
+            .range
+            .split_once("{0}")
+            .and_then(|(prefix, rest)| {
+                let (separator, suffix) = rest.split_once("{1}")?;
+                Some((prefix, separator, suffix))
+            })
+            .ok_or_else(|| invalid(&misc_patterns.range))?;
+        let (approximately_prefix, approximately_suffix) = misc_patterns
+            .approximately
+            .split_once("{0}")
+            .ok_or_else(|| invalid(&misc_patterns.approximately))?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(RangePatternsV1 {
+                range_affixes: AffixesV1 {
+                    prefix: Cow::Owned(range_prefix.to_owned()),
+                    suffix: Cow::Owned(range_suffix.to_owned()),
+                },
+                range_separator: Cow::Owned(range_separator.to_owned()),
+                approximately_affixes: AffixesV1 {
+                    prefix: Cow::Owned(approximately_prefix.to_owned()),
+                    suffix: Cow::Owned(approximately_suffix.to_owned()),
+                },
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<RangePatternsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        self.supported_locales()
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let load = |locale: &icu_locid::Locale| -> DataPayload<RangePatternsV1Marker> {
+        provider
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let en = load(&locale!("en"));
+    assert_eq!(en.get().range_affixes.prefix, "");
+    assert_eq!(en.get().range_separator, "–");
+    assert_eq!(en.get().range_affixes.suffix, "");
+    assert_eq!(en.get().approximately_affixes.prefix, "~");
+
+    let ja = load(&locale!("ja"));
+    assert_eq!(ja.get().range_separator, "～");
+}
@@ -4,7 +4,6 @@
 
 use crate::transform::cldr::cldr_serde;
 use icu_decimal::provider::*;
-use icu_locid::extensions_unicode_key as key;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
 use std::borrow::Cow;
@@ -28,13 +27,7 @@ impl DataProvider<DecimalSymbolsV1Marker> for crate::DatagenProvider {
             .expect("CLDR file contains the expected language")
             .numbers;
 
-        let nsname = match req.locale.get_unicode_ext(&key!("nu")) {
-            Some(v) => *v
-                .as_tinystr_slice()
-                .first()
-                .expect("expecting subtag if key is present"),
-            None => numbers.default_numbering_system,
-        };
+        let nsname = super::numbering_system_for(numbers, req.locale);
 
         let mut result =
             DecimalSymbolsV1::try_from(NumbersWithNumsys(numbers, nsname)).map_err(|s| {
@@ -113,3 +106,27 @@ fn test_basic() {
     assert_eq!(ar_decimal.get().decimal_separator, "٫");
     assert_eq!(ar_decimal.get().digits[0], '٠');
 }
+
+#[test]
+fn test_numbering_system_aliases() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let th_native: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("th-u-nu-native").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(th_native.get().digits[0], '๐');
+
+    let locales =
+        IterableDataProvider::<DecimalSymbolsV1Marker>::supported_locales(&provider).unwrap();
+    assert!(locales.contains(&locale!("th-u-nu-native").into()));
+    // The native numbering system of Arabic is its default one.
+    assert!(!locales.contains(&locale!("ar-u-nu-native").into()));
+}
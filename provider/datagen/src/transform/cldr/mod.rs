@@ -14,6 +14,8 @@ pub mod fallback;
 pub mod list;
 pub mod locale_canonicalizer;
 pub mod plurals;
+pub mod rbnf;
 pub mod relativetime;
 pub mod source;
 pub mod time_zones;
+pub mod units;
@@ -3,14 +3,19 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::transform::cldr::cldr_serde;
+use icu_locid::LanguageIdentifier;
 use icu_plurals::provider::*;
 use icu_plurals::rules::runtime::ast::Rule;
+use icu_plurals::PluralCategory;
 use icu_provider::datagen::IterableDataProvider;
 use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use zerovec::ZeroMap;
 
 impl crate::DatagenProvider {
     fn get_rules_for(&self, key: DataKey) -> Result<&cldr_serde::plurals::Rules, DataError> {
-        if key == CardinalV1Marker::KEY {
+        if key == CardinalV1Marker::KEY || key == CardinalSamplesV1Marker::KEY {
             self.source
                 .cldr()?
                 .core()
@@ -18,7 +23,7 @@ impl crate::DatagenProvider {
                 .supplemental
                 .plurals_type_cardinal
                 .as_ref()
-        } else if key == OrdinalV1Marker::KEY {
+        } else if key == OrdinalV1Marker::KEY || key == OrdinalSamplesV1Marker::KEY {
             self.source
                 .cldr()?
                 .core()
@@ -34,12 +39,12 @@ impl crate::DatagenProvider {
 }
 
 macro_rules! implement {
-    ($marker:ident) => {
+    ($marker:ident, $data_struct:ident) => {
         impl DataProvider<$marker> for crate::DatagenProvider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
                 Ok(DataResponse {
                     metadata: Default::default(),
-                    payload: Some(DataPayload::from_owned(PluralRulesV1::from(
+                    payload: Some(DataPayload::from_owned($data_struct::from(
                         self.get_rules_for(<$marker>::KEY)?
                             .0
                             .get(&req.locale.get_langid())
@@ -64,8 +69,10 @@ macro_rules! implement {
     };
 }
 
-implement!(CardinalV1Marker);
-implement!(OrdinalV1Marker);
+implement!(CardinalV1Marker, PluralRulesV1);
+implement!(OrdinalV1Marker, PluralRulesV1);
+implement!(CardinalSamplesV1Marker, PluralSamplesV1);
+implement!(OrdinalSamplesV1Marker, PluralSamplesV1);
 
 impl From<&cldr_serde::plurals::LocalePluralRules> for PluralRulesV1<'static> {
     fn from(other: &cldr_serde::plurals::LocalePluralRules) -> Self {
@@ -84,6 +91,102 @@ impl From<&cldr_serde::plurals::LocalePluralRules> for PluralRulesV1<'static> {
     }
 }
 
+impl From<&cldr_serde::plurals::LocalePluralRules> for PluralSamplesV1<'static> {
+    fn from(other: &cldr_serde::plurals::LocalePluralRules) -> Self {
+        /// Keeps the samples of plural rule strings, such as `"@integer 0, 1 @decimal 0.0~1.5"`.
+        fn convert(s: &str) -> Option<Cow<'static, str>> {
+            let samples = s.find('@').map(|i| s[i..].trim_end())?;
+            // Check that the samples are valid, since they are parsed at runtime.
+            icu_plurals::rules::reference::parse(samples.as_bytes())
+                .ok()
+                .and_then(|rule| rule.samples)
+                .expect("Sample parsing failed.");
+            Some(Cow::Owned(samples.to_owned()))
+        }
+        Self {
+            zero: other.zero.as_deref().and_then(convert),
+            one: other.one.as_deref().and_then(convert),
+            two: other.two.as_deref().and_then(convert),
+            few: other.few.as_deref().and_then(convert),
+            many: other.many.as_deref().and_then(convert),
+            other: other.other.as_deref().and_then(convert),
+        }
+    }
+}
+
+impl crate::DatagenProvider {
+    fn get_plural_ranges(
+        &self,
+    ) -> Result<
+        &HashMap<LanguageIdentifier, cldr_serde::plural_ranges::LocalePluralRanges>,
+        DataError,
+    > {
+        Ok(&self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse::<cldr_serde::plural_ranges::Resource>(
+                "supplemental/pluralRanges.json",
+            )?
+            .supplemental
+            .plurals)
+    }
+}
+
+impl DataProvider<PluralRangesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PluralRangesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+        let ranges = match self.get_plural_ranges()?.get(&langid) {
+            Some(ranges) => PluralRangesV1::try_from(ranges)?,
+            // Locales without data, such as the root locale, use the category of the end.
+            None if langid == LanguageIdentifier::UND => PluralRangesV1::default(),
+            None => return Err(DataErrorKind::MissingLocale.into_error()),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(ranges)),
+        })
+    }
+}
+
+impl IterableDataProvider<PluralRangesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .get_plural_ranges()?
+            .keys()
+            .cloned()
+            .chain(core::iter::once(LanguageIdentifier::UND))
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl TryFrom<&cldr_serde::plural_ranges::LocalePluralRanges> for PluralRangesV1<'static> {
+    type Error = DataError;
+    fn try_from(other: &cldr_serde::plural_ranges::LocalePluralRanges) -> Result<Self, DataError> {
+        fn convert(category: &str) -> Result<RawPluralCategory, DataError> {
+            PluralCategory::get_for_cldr_string(category)
+                .map(RawPluralCategory::from)
+                .ok_or_else(|| {
+                    DataError::custom("Unknown plural category").with_display_context(category)
+                })
+        }
+        let mut ranges = ZeroMap::new();
+        for (range, result) in &other.0 {
+            let (start, end) = range.split_once('+').ok_or_else(|| {
+                DataError::custom("Plural range is not of the form start+end")
+                    .with_display_context(range)
+            })?;
+            let (start, end, result) = (convert(start)?, convert(end)?, convert(result)?);
+            // The category of the end is the fallback, so it does not need to be stored.
+            if result != end {
+                ranges.insert(&UnvalidatedPluralRange::from_range(start, end), &result);
+            }
+        }
+        Ok(Self { ranges })
+    }
+}
+
 #[test]
 fn test_basic() {
     use icu_locid::langid;
@@ -115,3 +218,67 @@ fn test_basic() {
         cs_rules.get().many
     );
 }
+
+#[test]
+fn test_samples() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let fr_samples: DataPayload<CardinalSamplesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("fr").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        fr_samples.get().one.as_deref(),
+        Some("@integer 0, 1 @decimal 0.0~1.5")
+    );
+    assert_eq!(None, fr_samples.get().two);
+    assert!(fr_samples
+        .get()
+        .other
+        .as_deref()
+        .unwrap()
+        .starts_with("@integer 2~17, 100, 1000"));
+}
+
+#[test]
+fn test_ranges() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let load = |langid: &LanguageIdentifier| -> DataPayload<PluralRangesV1Marker> {
+        provider
+            .load(DataRequest {
+                locale: &langid.into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap()
+    };
+
+    let range = |start, end| UnvalidatedPluralRange::from_range(start, end);
+
+    let fr = load(&langid!("fr"));
+    assert_eq!(
+        fr.get()
+            .ranges
+            .get_copied(&range(RawPluralCategory::One, RawPluralCategory::One)),
+        None
+    );
+    let ar = load(&langid!("ar"));
+    assert_eq!(
+        ar.get()
+            .ranges
+            .get_copied(&range(RawPluralCategory::Zero, RawPluralCategory::One)),
+        Some(RawPluralCategory::Zero)
+    );
+    assert!(load(&langid!("und")).get().ranges.is_empty());
+}
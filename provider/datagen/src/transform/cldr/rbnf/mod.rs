@@ -0,0 +1,109 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_rbnf::provider::*;
+use std::str::FromStr;
+use zerovec::ZeroMap;
+
+/// The rule set groups used for formatting.
+const RULE_SET_GROUPS: &[&str] = &["OrdinalRules", "SpelloutRules"];
+
+impl DataProvider<RbnfRulesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RbnfRulesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+        // The root locale is stored as `root` rather than `und`.
+        let file_name = if langid == LanguageIdentifier::UND {
+            "root.json".to_string()
+        } else {
+            format!("{}.json", langid)
+        };
+        let resource: &cldr_serde::rbnf::Resource =
+            self.source.cldr()?.rbnf().read_and_parse(&file_name)?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(RbnfRulesV1::from(resource))),
+        })
+    }
+}
+
+impl IterableDataProvider<RbnfRulesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .rbnf()
+            .list_files()?
+            .filter_map(|file_name| match file_name.strip_suffix(".json")? {
+                "root" => Some(LanguageIdentifier::UND),
+                name => LanguageIdentifier::from_str(name).ok(),
+            })
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl From<&cldr_serde::rbnf::Resource> for RbnfRulesV1<'static> {
+    fn from(other: &cldr_serde::rbnf::Resource) -> Self {
+        let rule_sets = other
+            .rbnf
+            .rbnf
+            .iter()
+            .filter(|(group, _)| RULE_SET_GROUPS.contains(&group.as_str()))
+            .flat_map(|(_, rule_sets)| rule_sets)
+            // The lenient-parse rules are collation rules for parsing.
+            .filter(|(name, _)| name.as_str() != "%%lenient-parse")
+            .map(|(name, rules)| {
+                let rules = rules
+                    .iter()
+                    .map(|(descriptor, body)| {
+                        // CLDR JSON uses arrows for the ICU substitution characters.
+                        format!(
+                            "{}: {}",
+                            descriptor,
+                            body.replace('←', "<").replace('→', ">")
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                (name.as_str(), rules)
+            })
+            .collect::<Vec<_>>();
+        let mut map = ZeroMap::new();
+        for (name, rules) in &rule_sets {
+            map.insert(*name, rules.as_str());
+        }
+        Self { rule_sets: map }
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let data: DataPayload<RbnfRulesV1Marker> = provider
+        .load(DataRequest {
+            locale: &langid!("en").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        data.get().rule_sets.get("%digits-ordinal"),
+        Some("-x: −>>; 0: =#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$;")
+    );
+    assert!(data.get().rule_sets.get("%%lenient-parse").is_none());
+
+    let locales = IterableDataProvider::<RbnfRulesV1Marker>::supported_locales(&provider).unwrap();
+    assert!(locales.contains(&langid!("und").into()));
+    assert!(locales.contains(&langid!("sr-Latn").into()));
+}
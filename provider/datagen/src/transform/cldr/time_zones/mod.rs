@@ -15,6 +15,8 @@ use icu_timezone::provider::*;
 use std::collections::HashMap;
 
 mod convert;
+mod names;
+mod windows;
 
 #[derive(Debug, Copy, Clone)]
 struct CldrTimeZonesData<'a> {
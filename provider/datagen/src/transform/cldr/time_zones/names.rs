@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::BTreeMap;
+
+impl DataProvider<IanaToBcp47MapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<IanaToBcp47MapV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.source
+                .cldr()?
+                .bcp47()
+                .read_and_parse("timezone.json")?;
+
+        let mut map = BTreeMap::new();
+        for (bcp47_tzid, bcp47_tzid_data) in resource.keyword.u.time_zones.values.iter() {
+            if let Some(alias) = &bcp47_tzid_data.alias {
+                for iana_id in alias.split(' ') {
+                    map.insert(iana_id.to_ascii_lowercase(), *bcp47_tzid);
+                }
+            }
+        }
+
+        let data_struct = IanaToBcp47MapV1 {
+            map: map.iter().map(|(k, v)| (k.as_str(), v)).collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<IanaToBcp47MapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<Bcp47ToIanaMapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<Bcp47ToIanaMapV1Marker>, DataError> {
+        let resource: &cldr_serde::time_zones::bcp47_tzid::Resource =
+            self.source
+                .cldr()?
+                .bcp47()
+                .read_and_parse("timezone.json")?;
+
+        // The canonical IANA ID is the one in the `_iana` attribute if present, and otherwise
+        // the first one in the `_alias` attribute.
+        let map: BTreeMap<_, _> = resource
+            .keyword
+            .u
+            .time_zones
+            .values
+            .iter()
+            .filter_map(|(bcp47_tzid, bcp47_tzid_data)| {
+                bcp47_tzid_data
+                    .iana
+                    .as_deref()
+                    .or_else(|| bcp47_tzid_data.alias.as_ref()?.split(' ').next())
+                    .map(|iana_id| (*bcp47_tzid, iana_id))
+            })
+            .collect();
+
+        let data_struct = Bcp47ToIanaMapV1 {
+            map: map.iter().map(|(k, v)| (k, *v)).collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<Bcp47ToIanaMapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn basic_iana_bcp47_mapping() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let iana_to_bcp47: DataPayload<IanaToBcp47MapV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            iana_to_bcp47.get().map.get_copied("america/los_angeles"),
+            Some(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        );
+        assert_eq!(
+            iana_to_bcp47.get().map.get_copied("us/pacific"),
+            Some(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        );
+        assert_eq!(
+            iana_to_bcp47.get().map.get_copied("asia/kolkata"),
+            Some(TimeZoneBcp47Id(tinystr!(8, "inccu")))
+        );
+
+        let bcp47_to_iana: DataPayload<Bcp47ToIanaMapV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            bcp47_to_iana
+                .get()
+                .map
+                .get(&TimeZoneBcp47Id(tinystr!(8, "uslax"))),
+            Some("America/Los_Angeles")
+        );
+        // The `_iana` attribute takes precedence over the legacy first alias
+        assert_eq!(
+            bcp47_to_iana
+                .get()
+                .map
+                .get(&TimeZoneBcp47Id(tinystr!(8, "inccu"))),
+            Some("Asia/Kolkata")
+        );
+        assert_eq!(
+            bcp47_to_iana
+                .get()
+                .map
+                .get(&TimeZoneBcp47Id(tinystr!(8, "vnsgn"))),
+            Some("Asia/Ho_Chi_Minh")
+        );
+        // Deprecated IDs without an IANA alias are not included
+        assert_eq!(
+            bcp47_to_iana
+                .get()
+                .map
+                .get(&TimeZoneBcp47Id(tinystr!(8, "usnavajo"))),
+            None
+        );
+    }
+}
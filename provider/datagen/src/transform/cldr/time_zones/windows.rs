@@ -0,0 +1,191 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::{BTreeMap, HashMap};
+use tinystr::TinyAsciiStr;
+use zerovec::ZeroMap2d;
+
+impl DataProvider<WindowsZonesMapV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<WindowsZonesMapV1Marker>, DataError> {
+        let bcp47_tzid_resource: &cldr_serde::time_zones::bcp47_tzid::Resource = self
+            .source
+            .cldr()?
+            .bcp47()
+            .read_and_parse("timezone.json")?;
+        let windows_zones_resource: &cldr_serde::time_zones::windows_zones::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/windowsZones.json")?;
+
+        let data_struct = windows_zones_map(bcp47_tzid_resource, windows_zones_resource)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+fn windows_zones_map(
+    bcp47_tzid_resource: &cldr_serde::time_zones::bcp47_tzid::Resource,
+    windows_zones_resource: &cldr_serde::time_zones::windows_zones::Resource,
+) -> Result<WindowsZonesMapV1<'static>, DataError> {
+    let iana_to_bcp47: HashMap<&str, TimeZoneBcp47Id> = bcp47_tzid_resource
+        .keyword
+        .u
+        .time_zones
+        .values
+        .iter()
+        .filter_map(|(bcp47_tzid, bcp47_tzid_data)| {
+            Some((bcp47_tzid_data.alias.as_deref()?, *bcp47_tzid))
+        })
+        .flat_map(|(alias, bcp47_tzid)| alias.split(' ').map(move |iana_id| (iana_id, bcp47_tzid)))
+        .collect();
+    let get_bcp47 = |iana_id: &str| {
+        iana_to_bcp47.get(iana_id).copied().ok_or_else(|| {
+            DataError::custom("Unknown IANA ID in windowsZones.json").with_display_context(iana_id)
+        })
+    };
+
+    let map_zones = windows_zones_resource
+        .supplemental
+        .windows_zones
+        .map_timezones
+        .iter()
+        .map(|map_timezone| &map_timezone.map_zone);
+    let mut windows_to_bcp47 = ZeroMap2d::new();
+    let mut bcp47_to_windows = BTreeMap::new();
+    // A BCP-47 ID maps back to the first Windows ID that lists it, looking at the mappings
+    // for the world ("001") before those for single territories.
+    for map_zone in map_zones
+        .clone()
+        .filter(|map_zone| map_zone.territory == "001")
+        .chain(map_zones.filter(|map_zone| map_zone.territory != "001"))
+    {
+        let territory = TinyAsciiStr::<3>::from_str(&map_zone.territory).map_err(|_| {
+            DataError::custom("Invalid territory in windowsZones.json")
+                .with_display_context(&map_zone.territory)
+        })?;
+        // The first IANA ID is the preferred one for the territory.
+        let mut iana_ids = map_zone.iana_ids.split_whitespace();
+        if let Some(iana_id) = iana_ids.next() {
+            let bcp47_tzid = get_bcp47(iana_id)?;
+            windows_to_bcp47.insert(map_zone.windows_id.as_str(), &territory, &bcp47_tzid);
+            bcp47_to_windows
+                .entry(bcp47_tzid)
+                .or_insert(map_zone.windows_id.as_str());
+        }
+        for iana_id in iana_ids {
+            bcp47_to_windows
+                .entry(get_bcp47(iana_id)?)
+                .or_insert(map_zone.windows_id.as_str());
+        }
+    }
+
+    Ok(WindowsZonesMapV1 {
+        windows_to_bcp47,
+        bcp47_to_windows: bcp47_to_windows.iter().map(|(k, v)| (k, *v)).collect(),
+    })
+}
+
+impl IterableDataProvider<WindowsZonesMapV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn basic_windows_zones_mapping() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let windows_zones: DataPayload<WindowsZonesMapV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            windows_zones
+                .get()
+                .windows_to_bcp47
+                .get_copied_2d("Pacific Standard Time", &tinystr!(3, "001")),
+            Some(TimeZoneBcp47Id(tinystr!(8, "uslax")))
+        );
+        assert_eq!(
+            windows_zones
+                .get()
+                .windows_to_bcp47
+                .get_copied_2d("Pacific Standard Time", &tinystr!(3, "CA")),
+            Some(TimeZoneBcp47Id(tinystr!(8, "cavan")))
+        );
+        assert_eq!(
+            windows_zones
+                .get()
+                .bcp47_to_windows
+                .get(&TimeZoneBcp47Id(tinystr!(8, "usden"))),
+            Some("Mountain Standard Time")
+        );
+        // Time zones listed after the preferred one for a territory are also mapped back
+        assert_eq!(
+            windows_zones
+                .get()
+                .bcp47_to_windows
+                .get(&TimeZoneBcp47Id(tinystr!(8, "usdet"))),
+            Some("Eastern Standard Time")
+        );
+    }
+
+    #[test]
+    fn repeated_windows_zones_mapping() {
+        let provider = crate::DatagenProvider::for_test();
+        let bcp47_tzid_resource: &cldr_serde::time_zones::bcp47_tzid::Resource = provider
+            .source
+            .cldr()
+            .unwrap()
+            .bcp47()
+            .read_and_parse("timezone.json")
+            .unwrap();
+        // Asia/Kolkata is listed under two Windows IDs, and for the world only under the
+        // second one.
+        let windows_zones_resource: cldr_serde::time_zones::windows_zones::Resource =
+            serde_json::from_str(
+                r#"{ "supplemental": { "windowsZones": { "mapTimezones": [
+                    { "mapZone": { "_other": "Sri Lanka Standard Time", "_type": "Asia/Colombo Asia/Kolkata", "_territory": "LK" } },
+                    { "mapZone": { "_other": "India Standard Time", "_type": "Asia/Calcutta", "_territory": "001" } },
+                    { "mapZone": { "_other": "Sri Lanka Standard Time", "_type": "Asia/Colombo", "_territory": "001" } },
+                    { "mapZone": { "_other": "Other Standard Time", "_type": "Asia/Kolkata", "_territory": "IN" } }
+                ] } } }"#,
+            )
+            .unwrap();
+
+        let windows_zones =
+            windows_zones_map(bcp47_tzid_resource, &windows_zones_resource).unwrap();
+        assert_eq!(
+            windows_zones
+                .bcp47_to_windows
+                .get(&TimeZoneBcp47Id(tinystr!(8, "inccu"))),
+            Some("India Standard Time")
+        );
+        assert_eq!(
+            windows_zones
+                .bcp47_to_windows
+                .get(&TimeZoneBcp47Id(tinystr!(8, "lkcmb"))),
+            Some("Sri Lanka Standard Time")
+        );
+        assert_eq!(
+            windows_zones
+                .windows_to_bcp47
+                .get_copied_2d("Other Standard Time", &tinystr!(3, "IN")),
+            Some(TimeZoneBcp47Id(tinystr!(8, "inccu")))
+        );
+    }
+}
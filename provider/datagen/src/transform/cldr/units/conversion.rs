@@ -0,0 +1,236 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use cldr_serde::units_data::Constant;
+use icu_dimension::provider::*;
+use icu_dimension::units::Ratio;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use zerovec::ule::encode_varule_to_box;
+use zerovec::{VarZeroVec, ZeroMap};
+
+/// Evaluates a CLDR conversion expression, such as "ft2_to_m2 * 43560" or "gal_to_m3/16".
+///
+/// An expression is a product of terms, optionally divided by another product of terms. Terms
+/// are decimal numbers or the names of constants, which are expressions themselves.
+fn evaluate(expression: &str, constants: &BTreeMap<String, Constant>) -> Result<Ratio, DataError> {
+    let product = |product: &str| {
+        product.split('*').try_fold(Ratio::from(1), |result, term| {
+            let term = term.trim();
+            let value = match constants.get(term) {
+                Some(constant) => evaluate(&constant.value, constants)?,
+                None => term.parse().map_err(|_| {
+                    DataError::custom("Invalid conversion expression")
+                        .with_display_context(expression)
+                })?,
+            };
+            Ok::<_, DataError>(result * &value)
+        })
+    };
+    let (numerator, denominator) = expression.split_once('/').unwrap_or((expression, "1"));
+    let denominator = product(denominator)?.recip().ok_or_else(|| {
+        DataError::custom("Division by zero in conversion expression")
+            .with_display_context(expression)
+    })?;
+    Ok(product(numerator)? * &denominator)
+}
+
+impl DataProvider<UnitsConversionV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<UnitsConversionV1Marker>, DataError> {
+        let units_data: &cldr_serde::units_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+        let units_data = &units_data.supplemental;
+
+        let conversions = units_data
+            .convert_units
+            .iter()
+            .map(|(unit, conversion)| {
+                let evaluate_or = |expression: &Option<String>, default| {
+                    expression
+                        .as_deref()
+                        .map_or(Ok(Ratio::from(default)), |expression| {
+                            evaluate(expression, &units_data.unit_constants)
+                        })
+                };
+                Ok((
+                    unit.as_str(),
+                    ConversionInfo {
+                        base_unit: Cow::Borrowed(conversion.base_unit.as_str()),
+                        factor: Cow::Owned(evaluate_or(&conversion.factor, 1)?.to_string()),
+                        offset: Cow::Owned(evaluate_or(&conversion.offset, 0)?.to_string()),
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitsConversionV1 {
+                conversions: conversions
+                    .iter()
+                    .map(|(unit, conversion)| (*unit, encode_varule_to_box(conversion)))
+                    .collect::<ZeroMap<str, ConversionInfoULE>>(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitsConversionV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+impl DataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn load(&self, _: DataRequest) -> Result<DataResponse<UnitPreferencesV1Marker>, DataError> {
+        let units_data: &cldr_serde::units_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+        let units_data = &units_data.supplemental;
+
+        let mut lists = Vec::new();
+        for (quantity, usages) in &units_data.unit_preference_data {
+            for (usage, regions) in usages {
+                for (region, preferences) in regions {
+                    let geqs = preferences
+                        .iter()
+                        .map(|preference| {
+                            preference.geq.as_deref().map_or(Ok(Ratio::from(1)), |geq| {
+                                geq.parse().map_err(|_| {
+                                    DataError::custom("Invalid unit preference threshold")
+                                        .with_display_context(geq)
+                                })
+                            })
+                        })
+                        .map(|geq| geq.map(|geq| geq.to_string()))
+                        .collect::<Result<Vec<_>, DataError>>()?;
+                    lists.push((
+                        format!("{quantity}/{usage}"),
+                        *region,
+                        UnitPreferenceList {
+                            units: preferences
+                                .iter()
+                                .map(|preference| preference.unit.as_str())
+                                .collect::<Vec<_>>()
+                                .as_slice()
+                                .into(),
+                            geqs: VarZeroVec::from(&geqs),
+                            skeletons: preferences
+                                .iter()
+                                .map(|preference| preference.skeleton.as_deref().unwrap_or(""))
+                                .collect::<Vec<_>>()
+                                .as_slice()
+                                .into(),
+                        },
+                    ));
+                }
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitPreferencesV1 {
+                quantities: units_data
+                    .unit_quantities
+                    .iter()
+                    .map(|(base_unit, quantity)| (base_unit.as_str(), quantity.quantity.as_str()))
+                    .collect(),
+                preferences: lists
+                    .iter()
+                    .map(|(key, region, list)| (key.as_str(), *region, encode_varule_to_box(list)))
+                    .collect(),
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitPreferencesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+#[test]
+fn test_evaluate() {
+    let constants = [
+        ("ft_to_m", "0.3048"),
+        ("ft2_to_m2", "ft_to_m*ft_to_m"),
+        ("ft3_to_m3", "ft_to_m*ft_to_m*ft_to_m"),
+        ("in3_to_m3", "ft3_to_m3/12*12*12"),
+        ("gal_to_m3", "231*in3_to_m3"),
+    ]
+    .into_iter()
+    .map(|(name, value)| {
+        (
+            name.to_string(),
+            Constant {
+                value: value.to_string(),
+            },
+        )
+    })
+    .collect();
+
+    for (expression, expected) in [
+        ("ft2_to_m2 * 43560", "316160658/78125"),
+        ("gal_to_m3/16", "473176473/2000000000000"),
+        ("1/360*60", "1/21600"),
+        ("2298.35/9", "45967/180"),
+        ("1.602177E-19", "1602177/10000000000000000000000000"),
+    ] {
+        assert_eq!(
+            evaluate(expression, &constants).unwrap().to_string(),
+            expected,
+            "{expression}"
+        );
+    }
+    assert!(evaluate("ft_to_m/0", &constants).is_err());
+    assert!(evaluate("unknown_constant", &constants).is_err());
+}
+
+#[test]
+fn test_basic() {
+    use icu_provider::zerofrom::ZeroFrom;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let conversion: DataPayload<UnitsConversionV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    let foot = conversion.get().conversions.get("foot").unwrap();
+    let foot = ConversionInfo::zero_from(foot);
+    assert_eq!(foot.base_unit, "meter");
+    assert_eq!(foot.factor, "381/1250");
+    let fahrenheit = conversion.get().conversions.get("fahrenheit").unwrap();
+    let fahrenheit = ConversionInfo::zero_from(fahrenheit);
+    assert_eq!(
+        (&*fahrenheit.factor, &*fahrenheit.offset),
+        ("5/9", "45967/180")
+    );
+
+    let preferences: DataPayload<UnitPreferencesV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(preferences.get().quantities.get("meter"), Some("length"));
+    let road = preferences
+        .get()
+        .preferences
+        .get_2d("length/road", &tinystr::tinystr!(3, "US"))
+        .unwrap();
+    let road = UnitPreferenceList::zero_from(road);
+    assert_eq!(road.units.get(0), Some("mile"));
+    assert_eq!(road.geqs.get(0), Some("1/2"));
+    assert_eq!(road.skeletons.get(1), Some("precision-increment/50"));
+}
@@ -0,0 +1,122 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_dimension::provider::*;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+mod conversion;
+
+/// Converts the CLDR units of one width, keyed by `<category>-<unit>` and compound pattern
+/// names, to the data struct keyed by unit identifier.
+fn convert(
+    units: &BTreeMap<String, cldr_serde::units::Patterns>,
+) -> Result<UnitPatternsV1<'static>, DataError> {
+    let mut patterns = ZeroMap2d::new();
+    let mut per_unit_patterns = BTreeMap::new();
+    let mut per = None;
+    for (key, value) in units {
+        if key == "per" {
+            per = value.compound_unit_pattern.as_deref();
+            continue;
+        }
+        // Keys of units start with the unit category; other keys are compound unit
+        // patterns, such as "times", or are not unit patterns, such as "coordinateUnit".
+        let unit = match key.split_once('-') {
+            Some((_category, unit)) => unit,
+            None => continue,
+        };
+        for (count, pattern) in [
+            (Count::Zero, &value.unit_pattern_zero),
+            (Count::One, &value.unit_pattern_one),
+            (Count::Two, &value.unit_pattern_two),
+            (Count::Few, &value.unit_pattern_few),
+            (Count::Many, &value.unit_pattern_many),
+            (Count::Other, &value.unit_pattern_other),
+        ] {
+            if let Some(pattern) = pattern {
+                patterns.insert(unit, &count, pattern.as_str());
+            }
+        }
+        if let Some(per_unit_pattern) = &value.per_unit_pattern {
+            per_unit_patterns.insert(unit, per_unit_pattern.as_str());
+        }
+    }
+    Ok(UnitPatternsV1 {
+        patterns,
+        per_unit_patterns: per_unit_patterns.into_iter().collect::<ZeroMap<_, _>>(),
+        per: Cow::Owned(
+            per.ok_or_else(|| DataError::custom("Missing compound unit pattern: per"))?
+                .to_string(),
+        ),
+    })
+}
+
+impl DataProvider<UnitsDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsDisplayNamesV1Marker>, DataError> {
+        let langid = req.locale.get_langid();
+
+        let resource: &cldr_serde::units::Resource = self
+            .source
+            .cldr()?
+            .units()
+            .read_and_parse(&langid, "units.json")?;
+
+        let units = &resource
+            .main
+            .0
+            .get(&langid)
+            .expect("CLDR file contains the expected language")
+            .units;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(UnitsDisplayNamesV1 {
+                long: convert(&units.long)?,
+                short: convert(&units.short)?,
+                narrow: convert(&units.narrow)?,
+            })),
+        })
+    }
+}
+
+impl IterableDataProvider<UnitsDisplayNamesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(self
+            .source
+            .cldr()?
+            .units()
+            .list_langs()?
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::locale;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let ru: DataPayload<UnitsDisplayNamesV1Marker> = provider
+        .load(DataRequest {
+            locale: &locale!("ru").into(),
+            metadata: Default::default(),
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    let long = &ru.get().long;
+    assert_eq!(
+        long.patterns.get_2d("meter", &Count::Many),
+        Some("{0} метров")
+    );
+    assert_eq!(long.per_unit_patterns.get("meter"), Some("{0} на метр"));
+    assert_eq!(ru.get().short.per, "{0}/{1}");
+}
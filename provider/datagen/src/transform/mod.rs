@@ -5,6 +5,7 @@
 pub mod cldr;
 pub mod icuexport;
 pub mod segmenter;
+pub mod zoneinfo;
 
 use icu_provider::datagen::*;
 use icu_provider::hello_world::*;
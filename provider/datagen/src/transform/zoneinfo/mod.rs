@@ -0,0 +1,201 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations backed by a compiled time zone
+//! database, i.e. a directory of TZif files such as `/usr/share/zoneinfo`.
+
+use crate::transform::cldr::cldr_serde;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use icu_timezone::provider::*;
+use std::collections::BTreeMap;
+use tzif::data::posix::{PosixTzString, TransitionDate, TransitionDay};
+use tzif::data::tzif::LocalTimeTypeRecord;
+use zerovec::ZeroMap2d;
+
+impl DataProvider<ZoneOffsetTransitionsV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        _: DataRequest,
+    ) -> Result<DataResponse<ZoneOffsetTransitionsV1Marker>, DataError> {
+        let zoneinfo = self.source.zoneinfo()?;
+        let bcp47_tzid_resource: &cldr_serde::time_zones::bcp47_tzid::Resource = self
+            .source
+            .cldr()?
+            .bcp47()
+            .read_and_parse("timezone.json")?;
+
+        let mut transitions = ZeroMap2d::new();
+        let mut rules = BTreeMap::new();
+        for (bcp47_tzid, bcp47_tzid_data) in bcp47_tzid_resource.keyword.u.time_zones.values.iter()
+        {
+            if bcp47_tzid_data.deprecated == Some(true) {
+                continue;
+            }
+            // The zoneinfo directory might not contain every zone, or might only contain it
+            // under one of its aliases.
+            let path = match bcp47_tzid_data
+                .iana
+                .as_deref()
+                .into_iter()
+                .chain(
+                    bcp47_tzid_data
+                        .alias
+                        .iter()
+                        .flat_map(|alias| alias.split(' ')),
+                )
+                .map(|iana_id| zoneinfo.join(iana_id))
+                .find(|path| path.is_file())
+            {
+                Some(path) => path,
+                None => continue,
+            };
+            let tzif = tzif::parse_tzif_file(&path).map_err(|e| {
+                DataError::custom("Invalid TZif file")
+                    .with_display_context(&e)
+                    .with_path_context(&path)
+            })?;
+
+            // The version 2+ data block has 64-bit transition times.
+            let block = tzif.data_block2.as_ref().unwrap_or(&tzif.data_block1);
+            let zone_offset = |record: &LocalTimeTypeRecord| ZoneOffset {
+                offset_seconds: record.utoff.0 as i32,
+                is_dst: record.is_dst,
+            };
+            let get_record = |index: usize| {
+                block.local_time_type_records.get(index).ok_or_else(|| {
+                    DataError::custom("Invalid local time type in TZif file")
+                        .with_path_context(&path)
+                })
+            };
+
+            // Times before the first transition use the first local time type.
+            let mut previous = zone_offset(get_record(0)?);
+            transitions.insert(bcp47_tzid, &i64::MIN, &previous);
+            for (time, &index) in block
+                .transition_times
+                .iter()
+                .zip(block.transition_types.iter())
+            {
+                let offset = zone_offset(get_record(index)?);
+                // Transitions that only change the abbreviation are not observable.
+                if offset != previous {
+                    transitions.insert(bcp47_tzid, &time.0, &offset);
+                    previous = offset;
+                }
+            }
+
+            if let Some(PosixTzString {
+                std_info,
+                dst_info: Some(dst_info),
+            }) = &tzif.footer
+            {
+                // POSIX offsets are the amount to add to local time to get UTC.
+                rules.insert(
+                    *bcp47_tzid,
+                    ZoneDstRule {
+                        std_offset_seconds: -std_info.offset.0 as i32,
+                        dst_offset_seconds: -dst_info.variant_info.offset.0 as i32,
+                        start: transition_rule(&dst_info.start_date),
+                        end: transition_rule(&dst_info.end_date),
+                    },
+                );
+            }
+        }
+
+        let data_struct = ZoneOffsetTransitionsV1 {
+            transitions,
+            rules: rules.iter().collect(),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(data_struct)),
+        })
+    }
+}
+
+impl IterableDataProvider<ZoneOffsetTransitionsV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        Ok(vec![Default::default()])
+    }
+}
+
+fn transition_rule(date: &TransitionDate) -> ZoneTransitionRule {
+    let (kind, month, week, day) = match date.day {
+        TransitionDay::Mwd(month, week, day) => (
+            ZoneTransitionRuleKind::MonthWeekDay,
+            month as u8,
+            week as u8,
+            day,
+        ),
+        TransitionDay::NoLeap(day) => (ZoneTransitionRuleKind::JulianDay, 0, 0, day),
+        TransitionDay::WithLeap(day) => (ZoneTransitionRuleKind::DayOfYear, 0, 0, day),
+    };
+    ZoneTransitionRule {
+        kind,
+        month,
+        week,
+        day,
+        time_seconds: date.time.0 as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn basic_offset_transitions() {
+        let provider = crate::DatagenProvider::for_test();
+
+        let offset_transitions: DataPayload<ZoneOffsetTransitionsV1Marker> = provider
+            .load(Default::default())
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        let data = offset_transitions.get();
+
+        let uslax = TimeZoneBcp47Id(tinystr!(8, "uslax"));
+        // 2022-03-13T10:00:00Z, the start of DST in 2022
+        assert_eq!(
+            data.transitions.get_copied_2d(&uslax, &1647165600),
+            Some(ZoneOffset {
+                offset_seconds: -7 * 3600,
+                is_dst: true
+            })
+        );
+        // PST8PDT,M3.2.0,M11.1.0
+        assert_eq!(
+            data.rules.get_copied(&uslax),
+            Some(ZoneDstRule {
+                std_offset_seconds: -8 * 3600,
+                dst_offset_seconds: -7 * 3600,
+                start: ZoneTransitionRule {
+                    kind: ZoneTransitionRuleKind::MonthWeekDay,
+                    month: 3,
+                    week: 2,
+                    day: 0,
+                    time_seconds: 2 * 3600,
+                },
+                end: ZoneTransitionRule {
+                    kind: ZoneTransitionRuleKind::MonthWeekDay,
+                    month: 11,
+                    week: 1,
+                    day: 0,
+                    time_seconds: 2 * 3600,
+                },
+            })
+        );
+
+        // Zones without DST have no rule
+        let jptyo = TimeZoneBcp47Id(tinystr!(8, "jptyo"));
+        assert!(data.transitions.get0(&jptyo).is_some());
+        assert_eq!(data.rules.get_copied(&jptyo), None);
+
+        // Zones missing from the zoneinfo directory have no data
+        let aqcas = TimeZoneBcp47Id(tinystr!(8, "aqcas"));
+        assert!(data.transitions.get0(&aqcas).is_none());
+    }
+}
@@ -1,3 +1,4 @@
 // @generated
 pub mod long_v1;
+pub mod short_currency_v1;
 pub mod short_v1;
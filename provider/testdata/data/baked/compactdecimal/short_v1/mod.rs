@@ -3,7 +3,7 @@
 #![allow(clippy::octal_escapes)]
 type DataStruct = < :: icu_compactdecimal :: provider :: ShortCompactDecimalFormatDataV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
 pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct> {
-    static KEYS: [&str; 24usize] = [
+    static KEYS: [&str; 25usize] = [
         "ar",
         "ar-EG",
         "ar-EG-u-nu-latn",
@@ -25,13 +25,14 @@ pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct>
         "sr-Cyrl",
         "sr-Latn",
         "th",
+        "th-u-nu-native",
         "th-u-nu-thai",
         "tr",
         "und",
     ];
-    static DATA: [&DataStruct; 24usize] = [
+    static DATA: [&DataStruct; 25usize] = [
         &AR, &AR, &AR, &AR, &BN, &BN, &CCP, &CCP, &EN, &EN, &EN, &ES, &ES_AR, &EN, &FR, &JA, &RU,
-        &SR, &SR, &SR_LATN, &EN, &EN, &TR, &CCP,
+        &SR, &SR, &SR_LATN, &EN, &EN, &EN, &TR, &CCP,
     ];
     KEYS.binary_search_by(|k| locale.strict_cmp(k.as_bytes()).reverse())
         .ok()
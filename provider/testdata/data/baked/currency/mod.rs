@@ -0,0 +1,4 @@
+// @generated
+pub mod essentials_v1;
+pub mod fractions_v1;
+pub mod names_v1;
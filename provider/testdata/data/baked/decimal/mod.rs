@@ -1,2 +1,5 @@
 // @generated
+pub mod accounting_v1;
+pub mod notation_v1;
+pub mod range_v1;
 pub mod symbols_v1;
@@ -0,0 +1,69 @@
+// @generated
+#![cfg(feature = "icu_decimal")]
+#![allow(clippy::octal_escapes)]
+type DataStruct =
+    <::icu_decimal::provider::NotationSymbolsV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct> {
+    static KEYS: [&str; 25usize] = [
+        "ar",
+        "ar-EG",
+        "ar-EG-u-nu-latn",
+        "ar-u-nu-latn",
+        "bn",
+        "bn-u-nu-latn",
+        "ccp",
+        "ccp-u-nu-latn",
+        "en",
+        "en-001",
+        "en-ZA",
+        "es",
+        "es-AR",
+        "fil",
+        "fr",
+        "ja",
+        "ru",
+        "sr",
+        "sr-Cyrl",
+        "sr-Latn",
+        "th",
+        "th-u-nu-native",
+        "th-u-nu-thai",
+        "tr",
+        "und",
+    ];
+    static DATA: [&DataStruct; 25usize] = [
+        &AR,
+        &AR,
+        &AR_EG_U_NU_LATN,
+        &AR_EG_U_NU_LATN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &ES,
+        &ES,
+        &BN,
+        &ES,
+        &BN,
+        &ES,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &BN,
+        &TR,
+        &BN,
+    ];
+    KEYS.binary_search_by(|k| locale.strict_cmp(k.as_bytes()).reverse())
+        .ok()
+        .map(|i| unsafe { *DATA.get_unchecked(i) })
+}
+static AR_EG_U_NU_LATN: DataStruct = include!("ar-EG-u-nu-latn.rs.data");
+static AR: DataStruct = include!("ar.rs.data");
+static BN: DataStruct = include!("bn.rs.data");
+static ES: DataStruct = include!("es.rs.data");
+static TR: DataStruct = include!("tr.rs.data");
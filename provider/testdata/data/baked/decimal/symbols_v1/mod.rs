@@ -4,7 +4,7 @@
 type DataStruct =
     <::icu_decimal::provider::DecimalSymbolsV1Marker as ::icu_provider::DataMarker>::Yokeable;
 pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct> {
-    static KEYS: [&str; 24usize] = [
+    static KEYS: [&str; 25usize] = [
         "ar",
         "ar-EG",
         "ar-EG-u-nu-latn",
@@ -26,11 +26,12 @@ pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct>
         "sr-Cyrl",
         "sr-Latn",
         "th",
+        "th-u-nu-native",
         "th-u-nu-thai",
         "tr",
         "und",
     ];
-    static DATA: [&DataStruct; 24usize] = [
+    static DATA: [&DataStruct; 25usize] = [
         &AR,
         &AR,
         &AR_EG_U_NU_LATN,
@@ -52,7 +53,8 @@ pub fn lookup(locale: &icu_provider::DataLocale) -> Option<&'static DataStruct>
         &ES_AR,
         &ES_AR,
         &EN,
-        &TH_U_NU_THAI,
+        &TH_U_NU_NATIVE,
+        &TH_U_NU_NATIVE,
         &ES_AR,
         &EN,
     ];
@@ -70,4 +72,4 @@ static EN: DataStruct = include!("en.rs.data");
 static ES_AR: DataStruct = include!("es-AR.rs.data");
 static ES: DataStruct = include!("es.rs.data");
 static FR: DataStruct = include!("fr.rs.data");
-static TH_U_NU_THAI: DataStruct = include!("th-u-nu-thai.rs.data");
+static TH_U_NU_NATIVE: DataStruct = include!("th-u-nu-native.rs.data");
@@ -3,6 +3,7 @@ mod calendar;
 mod collator;
 mod compactdecimal;
 mod core;
+mod currency;
 mod datetime;
 mod decimal;
 mod displaynames;
@@ -12,9 +13,11 @@ mod locid_transform;
 mod normalizer;
 mod plurals;
 mod props;
+mod rbnf;
 mod relativetime;
 mod segmenter;
 mod time_zone;
+mod units;
 use ::icu_provider::prelude::*;
 /// Implement [`DataProvider<M>`] on the given struct using the data
 /// hardcoded in this module. This allows the struct to be used with
@@ -180,6 +183,24 @@ macro_rules! impl_data_provider {
             }
         }
         #[cfg(feature = "icu_compactdecimal")]
+        impl DataProvider<::icu_compactdecimal::provider::ShortCompactCurrencyFormatDataV1Marker> for $provider {
+            fn load(
+                &self,
+                req: DataRequest,
+            ) -> Result<DataResponse<::icu_compactdecimal::provider::ShortCompactCurrencyFormatDataV1Marker>, DataError> {
+                compactdecimal::short_currency_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| {
+                        DataErrorKind::MissingLocale.with_req(::icu_compactdecimal::provider::ShortCompactCurrencyFormatDataV1Marker::KEY, req)
+                    })
+            }
+        }
+        #[cfg(feature = "icu_compactdecimal")]
         impl DataProvider<::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker> for $provider {
             fn load(
                 &self,
@@ -527,6 +548,19 @@ macro_rules! impl_data_provider {
             }
         }
         #[cfg(feature = "icu_decimal")]
+        impl DataProvider<::icu_decimal::provider::AccountingSymbolsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::AccountingSymbolsV1Marker>, DataError> {
+                decimal::accounting_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::AccountingSymbolsV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_decimal")]
         impl DataProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::DecimalSymbolsV1Marker>, DataError> {
                 decimal::symbols_v1::lookup(&req.locale)
@@ -539,6 +573,110 @@ macro_rules! impl_data_provider {
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::DecimalSymbolsV1Marker::KEY, req))
             }
         }
+        #[cfg(feature = "icu_decimal")]
+        impl DataProvider<::icu_decimal::provider::NotationSymbolsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::NotationSymbolsV1Marker>, DataError> {
+                decimal::notation_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::NotationSymbolsV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_decimal")]
+        impl DataProvider<::icu_decimal::provider::RangePatternsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::RangePatternsV1Marker>, DataError> {
+                decimal::range_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_decimal::provider::RangePatternsV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::CurrencyEssentialsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::CurrencyEssentialsV1Marker>, DataError> {
+                currency::essentials_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::CurrencyEssentialsV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::CurrencyFractionsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::CurrencyFractionsV1Marker>, DataError> {
+                currency::fractions_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::CurrencyFractionsV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::CurrencyNamesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::CurrencyNamesV1Marker>, DataError> {
+                currency::names_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::CurrencyNamesV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::UnitPreferencesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::UnitPreferencesV1Marker>, DataError> {
+                units::preferences_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::UnitPreferencesV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::UnitsConversionV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::UnitsConversionV1Marker>, DataError> {
+                units::conversion_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::UnitsConversionV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_dimension")]
+        impl DataProvider<::icu_dimension::provider::UnitsDisplayNamesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_dimension::provider::UnitsDisplayNamesV1Marker>, DataError> {
+                units::displaynames_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_dimension::provider::UnitsDisplayNamesV1Marker::KEY, req))
+            }
+        }
         #[cfg(feature = "icu_displaynames")]
         impl DataProvider<::icu_displaynames::provider::LanguageDisplayNamesV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_displaynames::provider::LanguageDisplayNamesV1Marker>, DataError> {
@@ -737,6 +875,19 @@ macro_rules! impl_data_provider {
             }
         }
         #[cfg(feature = "icu_plurals")]
+        impl DataProvider<::icu_plurals::provider::CardinalSamplesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::CardinalSamplesV1Marker>, DataError> {
+                plurals::cardinal_samples_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_plurals::provider::CardinalSamplesV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_plurals")]
         impl DataProvider<::icu_plurals::provider::CardinalV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::CardinalV1Marker>, DataError> {
                 plurals::cardinal_v1::lookup(&req.locale)
@@ -750,6 +901,19 @@ macro_rules! impl_data_provider {
             }
         }
         #[cfg(feature = "icu_plurals")]
+        impl DataProvider<::icu_plurals::provider::OrdinalSamplesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::OrdinalSamplesV1Marker>, DataError> {
+                plurals::ordinal_samples_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_plurals::provider::OrdinalSamplesV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_plurals")]
         impl DataProvider<::icu_plurals::provider::OrdinalV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::OrdinalV1Marker>, DataError> {
                 plurals::ordinal_v1::lookup(&req.locale)
@@ -762,6 +926,19 @@ macro_rules! impl_data_provider {
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_plurals::provider::OrdinalV1Marker::KEY, req))
             }
         }
+        #[cfg(feature = "icu_plurals")]
+        impl DataProvider<::icu_plurals::provider::PluralRangesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_plurals::provider::PluralRangesV1Marker>, DataError> {
+                plurals::ranges_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_plurals::provider::PluralRangesV1Marker::KEY, req))
+            }
+        }
         #[cfg(feature = "icu_properties")]
         impl DataProvider<::icu_properties::provider::AlnumV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_properties::provider::AlnumV1Marker>, DataError> {
@@ -1884,6 +2061,19 @@ macro_rules! impl_data_provider {
                     })
             }
         }
+        #[cfg(feature = "icu_rbnf")]
+        impl DataProvider<::icu_rbnf::provider::RbnfRulesV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_rbnf::provider::RbnfRulesV1Marker>, DataError> {
+                rbnf::rules_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_rbnf::provider::RbnfRulesV1Marker::KEY, req))
+            }
+        }
         #[cfg(feature = "icu_relativetime")]
         impl DataProvider<::icu_relativetime::provider::LongDayRelativeTimeFormatDataV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_relativetime::provider::LongDayRelativeTimeFormatDataV1Marker>, DataError> {
@@ -2392,6 +2582,32 @@ macro_rules! impl_data_provider {
             }
         }
         #[cfg(feature = "icu_timezone")]
+        impl DataProvider<::icu_timezone::provider::Bcp47ToIanaMapV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::Bcp47ToIanaMapV1Marker>, DataError> {
+                time_zone::bcp47_to_iana_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_timezone::provider::Bcp47ToIanaMapV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_timezone")]
+        impl DataProvider<::icu_timezone::provider::IanaToBcp47MapV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::IanaToBcp47MapV1Marker>, DataError> {
+                time_zone::iana_to_bcp47_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_timezone::provider::IanaToBcp47MapV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_timezone")]
         impl DataProvider<::icu_timezone::provider::MetazonePeriodV1Marker> for $provider {
             fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::MetazonePeriodV1Marker>, DataError> {
                 time_zone::metazone_period_v1::lookup(&req.locale)
@@ -2404,6 +2620,32 @@ macro_rules! impl_data_provider {
                     .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_timezone::provider::MetazonePeriodV1Marker::KEY, req))
             }
         }
+        #[cfg(feature = "icu_timezone")]
+        impl DataProvider<::icu_timezone::provider::WindowsZonesMapV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::WindowsZonesMapV1Marker>, DataError> {
+                time_zone::windows_zones_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_timezone::provider::WindowsZonesMapV1Marker::KEY, req))
+            }
+        }
+        #[cfg(feature = "icu_timezone")]
+        impl DataProvider<::icu_timezone::provider::ZoneOffsetTransitionsV1Marker> for $provider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_timezone::provider::ZoneOffsetTransitionsV1Marker>, DataError> {
+                time_zone::offset_transitions_v1::lookup(&req.locale)
+                    .map(zerofrom::ZeroFrom::zero_from)
+                    .map(DataPayload::from_owned)
+                    .map(|payload| DataResponse {
+                        metadata: Default::default(),
+                        payload: Some(payload),
+                    })
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_timezone::provider::ZoneOffsetTransitionsV1Marker::KEY, req))
+            }
+        }
     };
 }
 /// Implement [`AnyProvider`] on the given struct using the data
@@ -2449,6 +2691,9 @@ macro_rules! impl_any_provider {
                 const LONGCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_compactdecimal::provider::LongCompactDecimalFormatDataV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_compactdecimal")]
+                const SHORTCOMPACTCURRENCYFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
+                    ::icu_compactdecimal::provider::ShortCompactCurrencyFormatDataV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_compactdecimal")]
                 const SHORTCOMPACTDECIMALFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_compactdecimal::provider::ShortCompactDecimalFormatDataV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_datetime")]
@@ -2519,7 +2764,25 @@ macro_rules! impl_any_provider {
                 const TIMEZONEFORMATSV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_decimal")]
+                const ACCOUNTINGSYMBOLSV1MARKER: ::icu_provider::DataKeyHash = ::icu_decimal::provider::AccountingSymbolsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_decimal")]
                 const DECIMALSYMBOLSV1MARKER: ::icu_provider::DataKeyHash = ::icu_decimal::provider::DecimalSymbolsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_decimal")]
+                const NOTATIONSYMBOLSV1MARKER: ::icu_provider::DataKeyHash = ::icu_decimal::provider::NotationSymbolsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_decimal")]
+                const RANGEPATTERNSV1MARKER: ::icu_provider::DataKeyHash = ::icu_decimal::provider::RangePatternsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const CURRENCYESSENTIALSV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::CurrencyEssentialsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const CURRENCYFRACTIONSV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::CurrencyFractionsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const CURRENCYNAMESV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::CurrencyNamesV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const UNITPREFERENCESV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::UnitPreferencesV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const UNITSCONVERSIONV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::UnitsConversionV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_dimension")]
+                const UNITSDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash = ::icu_dimension::provider::UnitsDisplayNamesV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_displaynames")]
                 const LANGUAGEDISPLAYNAMESV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_displaynames::provider::LanguageDisplayNamesV1Marker::KEY.hashed();
@@ -2558,9 +2821,15 @@ macro_rules! impl_any_provider {
                 const UTS46DECOMPOSITIONSUPPLEMENTV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_normalizer::provider::Uts46DecompositionSupplementV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_plurals")]
+                const CARDINALSAMPLESV1MARKER: ::icu_provider::DataKeyHash = ::icu_plurals::provider::CardinalSamplesV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_plurals")]
                 const CARDINALV1MARKER: ::icu_provider::DataKeyHash = ::icu_plurals::provider::CardinalV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_plurals")]
+                const ORDINALSAMPLESV1MARKER: ::icu_provider::DataKeyHash = ::icu_plurals::provider::OrdinalSamplesV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_plurals")]
                 const ORDINALV1MARKER: ::icu_provider::DataKeyHash = ::icu_plurals::provider::OrdinalV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_plurals")]
+                const PLURALRANGESV1MARKER: ::icu_provider::DataKeyHash = ::icu_plurals::provider::PluralRangesV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_properties")]
                 const ALNUMV1MARKER: ::icu_provider::DataKeyHash = ::icu_properties::provider::AlnumV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_properties")]
@@ -2751,6 +3020,8 @@ macro_rules! impl_any_provider {
                     ::icu_provider_adapters::fallback::provider::LocaleFallbackLikelySubtagsV1Marker::KEY.hashed();
                 const LOCALEFALLBACKPARENTSV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_provider_adapters::fallback::provider::LocaleFallbackParentsV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_rbnf")]
+                const RBNFRULESV1MARKER: ::icu_provider::DataKeyHash = ::icu_rbnf::provider::RbnfRulesV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_relativetime")]
                 const LONGDAYRELATIVETIMEFORMATDATAV1MARKER: ::icu_provider::DataKeyHash =
                     ::icu_relativetime::provider::LongDayRelativeTimeFormatDataV1Marker::KEY.hashed();
@@ -2838,7 +3109,16 @@ macro_rules! impl_any_provider {
                 #[cfg(feature = "icu_segmenter")]
                 const WORDBREAKDATAV1MARKER: ::icu_provider::DataKeyHash = ::icu_segmenter::provider::WordBreakDataV1Marker::KEY.hashed();
                 #[cfg(feature = "icu_timezone")]
+                const BCP47TOIANAMAPV1MARKER: ::icu_provider::DataKeyHash = ::icu_timezone::provider::Bcp47ToIanaMapV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_timezone")]
+                const IANATOBCP47MAPV1MARKER: ::icu_provider::DataKeyHash = ::icu_timezone::provider::IanaToBcp47MapV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_timezone")]
                 const METAZONEPERIODV1MARKER: ::icu_provider::DataKeyHash = ::icu_timezone::provider::MetazonePeriodV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_timezone")]
+                const WINDOWSZONESMAPV1MARKER: ::icu_provider::DataKeyHash = ::icu_timezone::provider::WindowsZonesMapV1Marker::KEY.hashed();
+                #[cfg(feature = "icu_timezone")]
+                const ZONEOFFSETTRANSITIONSV1MARKER: ::icu_provider::DataKeyHash =
+                    ::icu_timezone::provider::ZoneOffsetTransitionsV1Marker::KEY.hashed();
                 match key.hashed() {
                     #[cfg(feature = "icu_calendar")]
                     JAPANESEERASV1MARKER => calendar::japanese_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
@@ -2863,6 +3143,8 @@ macro_rules! impl_any_provider {
                     #[cfg(feature = "icu_compactdecimal")]
                     LONGCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::long_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_compactdecimal")]
+                    SHORTCOMPACTCURRENCYFORMATDATAV1MARKER => compactdecimal::short_currency_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_compactdecimal")]
                     SHORTCOMPACTDECIMALFORMATDATAV1MARKER => compactdecimal::short_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_datetime")]
                     BUDDHISTDATELENGTHSV1MARKER => datetime::buddhist::datelengths_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
@@ -2914,7 +3196,25 @@ macro_rules! impl_any_provider {
                     #[cfg(feature = "icu_datetime")]
                     TIMEZONEFORMATSV1MARKER => time_zone::formats_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_decimal")]
+                    ACCOUNTINGSYMBOLSV1MARKER => decimal::accounting_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_decimal")]
                     DECIMALSYMBOLSV1MARKER => decimal::symbols_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_decimal")]
+                    NOTATIONSYMBOLSV1MARKER => decimal::notation_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_decimal")]
+                    RANGEPATTERNSV1MARKER => decimal::range_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    CURRENCYESSENTIALSV1MARKER => currency::essentials_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    CURRENCYFRACTIONSV1MARKER => currency::fractions_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    CURRENCYNAMESV1MARKER => currency::names_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    UNITPREFERENCESV1MARKER => units::preferences_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    UNITSCONVERSIONV1MARKER => units::conversion_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_dimension")]
+                    UNITSDISPLAYNAMESV1MARKER => units::displaynames_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_displaynames")]
                     LANGUAGEDISPLAYNAMESV1MARKER => displaynames::languages_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_displaynames")]
@@ -2944,9 +3244,15 @@ macro_rules! impl_any_provider {
                     #[cfg(feature = "icu_normalizer")]
                     UTS46DECOMPOSITIONSUPPLEMENTV1MARKER => normalizer::uts46d_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_plurals")]
+                    CARDINALSAMPLESV1MARKER => plurals::cardinal_samples_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_plurals")]
                     CARDINALV1MARKER => plurals::cardinal_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_plurals")]
+                    ORDINALSAMPLESV1MARKER => plurals::ordinal_samples_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_plurals")]
                     ORDINALV1MARKER => plurals::ordinal_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_plurals")]
+                    PLURALRANGESV1MARKER => plurals::ranges_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_properties")]
                     ALNUMV1MARKER => props::alnum_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_properties")]
@@ -3115,6 +3421,8 @@ macro_rules! impl_any_provider {
                     COLLATIONFALLBACKSUPPLEMENTV1MARKER => fallback::supplement::co_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     LOCALEFALLBACKLIKELYSUBTAGSV1MARKER => fallback::likelysubtags_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     LOCALEFALLBACKPARENTSV1MARKER => fallback::parents_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_rbnf")]
+                    RBNFRULESV1MARKER => rbnf::rules_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_relativetime")]
                     LONGDAYRELATIVETIMEFORMATDATAV1MARKER => relativetime::long::day_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_relativetime")]
@@ -3184,7 +3492,15 @@ macro_rules! impl_any_provider {
                     #[cfg(feature = "icu_segmenter")]
                     WORDBREAKDATAV1MARKER => segmenter::word_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     #[cfg(feature = "icu_timezone")]
+                    BCP47TOIANAMAPV1MARKER => time_zone::bcp47_to_iana_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_timezone")]
+                    IANATOBCP47MAPV1MARKER => time_zone::iana_to_bcp47_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_timezone")]
                     METAZONEPERIODV1MARKER => time_zone::metazone_period_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_timezone")]
+                    WINDOWSZONESMAPV1MARKER => time_zone::windows_zones_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
+                    #[cfg(feature = "icu_timezone")]
+                    ZONEOFFSETTRANSITIONSV1MARKER => time_zone::offset_transitions_v1::lookup(&req.locale).map(AnyPayload::from_static_ref),
                     _ => return Err(DataErrorKind::MissingDataKey.with_req(key, req)),
                 }
                 .map(|payload| AnyResponse {
@@ -1,3 +1,6 @@
 // @generated
+pub mod cardinal_samples_v1;
 pub mod cardinal_v1;
+pub mod ordinal_samples_v1;
 pub mod ordinal_v1;
+pub mod ranges_v1;
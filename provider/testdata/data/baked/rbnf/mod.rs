@@ -0,0 +1,2 @@
+// @generated
+pub mod rules_v1;
@@ -1,8 +1,12 @@
 // @generated
+pub mod bcp47_to_iana_v1;
 pub mod exemplar_cities_v1;
 pub mod formats_v1;
 pub mod generic_long_v1;
 pub mod generic_short_v1;
+pub mod iana_to_bcp47_v1;
 pub mod metazone_period_v1;
+pub mod offset_transitions_v1;
 pub mod specific_long_v1;
 pub mod specific_short_v1;
+pub mod windows_zones_v1;
@@ -0,0 +1,4 @@
+// @generated
+pub mod conversion_v1;
+pub mod displaynames_v1;
+pub mod preferences_v1;
@@ -25,3 +25,8 @@ pub fn icuexport_toml_root() -> PathBuf {
 pub fn coll_toml_root() -> PathBuf {
     data_root().join("coll")
 }
+
+/// Returns the absolute path to the compiled time zone database (TZif) root directory.
+pub fn zoneinfo_root() -> PathBuf {
+    data_root().join("zoneinfo")
+}
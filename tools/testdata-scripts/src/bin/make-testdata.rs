@@ -17,6 +17,8 @@ fn main() {
         .with_cldr(paths::cldr_json_root(), CldrLocaleSubset::Full)
         .unwrap()
         .with_icuexport(paths::icuexport_toml_root())
+        .unwrap()
+        .with_zoneinfo(paths::zoneinfo_root())
         .unwrap();
 
     let json_out = Out::Fs {
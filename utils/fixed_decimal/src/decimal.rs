@@ -8,7 +8,7 @@ use core::cmp;
 use core::cmp::Ordering;
 use core::convert::TryFrom;
 use core::fmt;
-use core::ops::RangeInclusive;
+use core::ops::{Add, AddAssign, Mul, MulAssign, RangeInclusive, Sub, SubAssign};
 
 use core::str::FromStr;
 
@@ -159,6 +159,66 @@ pub enum SignDisplay {
     Negative,
 }
 
+impl Default for SignDisplay {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+/// A rounding mode, selecting one of the rounding functions of [`FixedDecimal`].
+///
+/// The "half" modes round to the nearest number, and use the given direction only to break
+/// ties. See [`FixedDecimal::round_with_mode()`].
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingMode {
+    /// Round towards positive infinity, as in [`FixedDecimal::ceil()`].
+    Ceil,
+    /// Round away from zero, as in [`FixedDecimal::expand()`].
+    Expand,
+    /// Round towards negative infinity, as in [`FixedDecimal::floor()`].
+    Floor,
+    /// Round towards zero, as in [`FixedDecimal::trunc()`].
+    Trunc,
+    /// Round ties towards positive infinity, as in [`FixedDecimal::half_ceil()`].
+    HalfCeil,
+    /// Round ties away from zero, as in [`FixedDecimal::half_expand()`].
+    HalfExpand,
+    /// Round ties towards negative infinity, as in [`FixedDecimal::half_floor()`].
+    HalfFloor,
+    /// Round ties towards zero, as in [`FixedDecimal::half_trunc()`].
+    HalfTrunc,
+    /// Round ties towards an even digit, as in [`FixedDecimal::half_even()`].
+    HalfEven,
+}
+
+impl Default for RoundingMode {
+    fn default() -> Self {
+        Self::HalfExpand
+    }
+}
+
+/// The multiples of a power of 10 to round to. See
+/// [`FixedDecimal::round_with_mode_and_increment()`].
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RoundingIncrement {
+    /// Round to multiples of 1 at the rounding position, such as 0.01.
+    MultiplesOf1,
+    /// Round to multiples of 2 at the rounding position, such as 0.02.
+    MultiplesOf2,
+    /// Round to multiples of 5 at the rounding position, such as 0.05.
+    MultiplesOf5,
+    /// Round to multiples of 25 at the rounding position, such as 0.25.
+    MultiplesOf25,
+}
+
+impl Default for RoundingIncrement {
+    fn default() -> Self {
+        Self::MultiplesOf1
+    }
+}
+
 impl Default for FixedDecimal {
     /// Returns a `FixedDecimal` representing zero.
     fn default() -> Self {
@@ -1180,6 +1240,12 @@ impl FixedDecimal {
         }
 
         if position <= before_truncate_magnitude {
+            // Trailing zeros are not stored, so pad the digits down to `position` in order
+            // to increment the digit at `position` rather than the last nonzero digit.
+            self.digits.resize(
+                crate::ops::i16_abs_sub(self.magnitude, position) as usize + 1,
+                0,
+            );
             let result = self.increment_abs_by_one();
             if result.is_err() {
                 // Do nothing for now.
@@ -1579,6 +1645,168 @@ impl FixedDecimal {
         self
     }
 
+    /// Rounds the number at a particular position with the given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("-1.25").unwrap();
+    /// dec.round_with_mode(-1, RoundingMode::HalfEven);
+    /// assert_eq!("-1.2", dec.to_string());
+    /// let mut dec = FixedDecimal::from_str("-1.25").unwrap();
+    /// dec.round_with_mode(-1, RoundingMode::HalfCeil);
+    /// assert_eq!("-1.2", dec.to_string());
+    /// let mut dec = FixedDecimal::from_str("-1.25").unwrap();
+    /// dec.round_with_mode(-1, RoundingMode::Floor);
+    /// assert_eq!("-1.3", dec.to_string());
+    /// ```
+    pub fn round_with_mode(&mut self, position: i16, mode: RoundingMode) {
+        match mode {
+            RoundingMode::Ceil => self.ceil(position),
+            RoundingMode::Expand => self.expand(position),
+            RoundingMode::Floor => self.floor(position),
+            RoundingMode::Trunc => self.trunc(position),
+            RoundingMode::HalfCeil => self.half_ceil(position),
+            RoundingMode::HalfExpand => self.half_expand(position),
+            RoundingMode::HalfFloor => self.half_floor(position),
+            RoundingMode::HalfTrunc => self.half_trunc(position),
+            RoundingMode::HalfEven => self.half_even(position),
+        }
+    }
+
+    /// Rounds the number at a particular position with the given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("1.25").unwrap();
+    /// assert_eq!("1.3", dec.rounded_with_mode(-1, RoundingMode::HalfExpand).to_string());
+    /// ```
+    pub fn rounded_with_mode(mut self, position: i16, mode: RoundingMode) -> Self {
+        self.round_with_mode(position, mode);
+        self
+    }
+
+    /// Rounds the number to a multiple of an increment at a particular position, such as 0.05
+    /// for [`RoundingIncrement::MultiplesOf5`] at position -2, with the given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingIncrement, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let mut dec = FixedDecimal::from_str("7.425").unwrap();
+    /// dec.round_with_mode_and_increment(
+    ///     -2,
+    ///     RoundingMode::HalfExpand,
+    ///     RoundingIncrement::MultiplesOf5,
+    /// );
+    /// assert_eq!("7.45", dec.to_string());
+    ///
+    /// let mut dec = FixedDecimal::from_str("-7.3").unwrap();
+    /// dec.round_with_mode_and_increment(
+    ///     -1,
+    ///     RoundingMode::Trunc,
+    ///     RoundingIncrement::MultiplesOf25,
+    /// );
+    /// assert_eq!("-5.0", dec.to_string());
+    /// ```
+    pub fn round_with_mode_and_increment(
+        &mut self,
+        position: i16,
+        mode: RoundingMode,
+        increment: RoundingIncrement,
+    ) {
+        // A number is a multiple of `increment * 10^position` if and only if its product with
+        // `multiplier` is a multiple of `10^(position + shift)`.
+        let (multiplier, shift, increment) = match increment {
+            RoundingIncrement::MultiplesOf1 => return self.round_with_mode(position, mode),
+            RoundingIncrement::MultiplesOf2 => (5, 1, 2),
+            RoundingIncrement::MultiplesOf5 => (2, 1, 5),
+            RoundingIncrement::MultiplesOf25 => (4, 2, 25),
+        };
+        let scaled_position = match position.checked_add(shift) {
+            Some(scaled_position) => scaled_position,
+            None => {
+                // Every representable number rounds to zero.
+                return self.round_with_mode(i16::MAX, mode);
+            }
+        };
+        let upper_magnitude = self.upper_magnitude;
+        self.multiply_abs_by_small(multiplier);
+        self.round_with_mode(scaled_position, mode);
+        self.multiply_abs_by_small(increment);
+        self.multiply_pow10(-shift);
+        self.trunc(position);
+        self.upper_magnitude = cmp::max(upper_magnitude, self.upper_magnitude);
+
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Rounds the number to a multiple of an increment at a particular position with the
+    /// given [`RoundingMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{FixedDecimal, RoundingIncrement, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let dec = FixedDecimal::from_str("1.29").unwrap();
+    /// // 1.28 and 1.30 are equally near; 1.28 is an even multiple of 0.02.
+    /// assert_eq!(
+    ///     "1.28",
+    ///     dec.rounded_with_mode_and_increment(
+    ///         -2,
+    ///         RoundingMode::HalfEven,
+    ///         RoundingIncrement::MultiplesOf2
+    ///     )
+    ///     .to_string()
+    /// );
+    /// ```
+    pub fn rounded_with_mode_and_increment(
+        mut self,
+        position: i16,
+        mode: RoundingMode,
+        increment: RoundingIncrement,
+    ) -> Self {
+        self.round_with_mode_and_increment(position, mode, increment);
+        self
+    }
+
+    /// Multiplies the absolute value of the number by a small integer.
+    ///
+    /// NOTE: if the operation causes overflow, the number will be set to zero.
+    fn multiply_abs_by_small(&mut self, factor: u8) {
+        let mut carry = 0u16;
+        for digit in self.digits.iter_mut().rev() {
+            let product = u16::from(*digit) * u16::from(factor) + carry;
+            *digit = (product % 10) as u8;
+            carry = product / 10;
+        }
+        while carry > 0 {
+            match self.magnitude.checked_add(1) {
+                Some(magnitude) => self.magnitude = magnitude,
+                None => {
+                    self.clear();
+                    return;
+                }
+            }
+            self.digits.insert(0, (carry % 10) as u8);
+            carry /= 10;
+        }
+        self.upper_magnitude = cmp::max(self.upper_magnitude, self.magnitude);
+        self.remove_trailing_zeros_from_digits_list();
+    }
+
     /// Concatenate another `FixedDecimal` into the end of this `FixedDecimal`.
     ///
     /// All nonzero digits in `other` must have lower magnitude than nonzero digits in `self`.
@@ -1702,6 +1930,392 @@ impl FixedDecimal {
     }
 }
 
+/// Arithmetic on the digits of [`FixedDecimal`]s.
+///
+/// Addition, subtraction and multiplication are exact, and are available through the
+/// operators `+`, `-` and `*`; see [`FixedDecimal::divided_with_mode()`] for division.
+///
+/// A sum or difference shows the fraction digits of both operands, and a product as many
+/// fraction digits as both factors together. Its sign is [`Sign::Negative`] or [`Sign::None`].
+///
+/// NOTE: if an operation causes overflow, the number will be set to zero.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::FixedDecimal;
+/// # use std::str::FromStr;
+///
+/// let price = FixedDecimal::from_str("19.99").unwrap();
+/// let discount = FixedDecimal::from_str("5.5").unwrap();
+///
+/// let total = &price * &FixedDecimal::from(3) - discount;
+/// assert_eq!("54.47", total.to_string());
+///
+/// let mut balance = FixedDecimal::from_str("-0.50").unwrap();
+/// balance += &FixedDecimal::from(2);
+/// assert_eq!("1.50", balance.to_string());
+/// ```
+impl FixedDecimal {
+    /// Compares the absolute values of `self` and `other`.
+    fn cmp_abs(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .magnitude
+                .cmp(&other.magnitude)
+                .then_with(|| self.digits.iter().cmp(other.digits.iter())),
+        }
+    }
+
+    /// Replaces the digits of `self` with `digits`, the most significant first, the last of
+    /// which has magnitude `end`. Leading and trailing zeros are removed.
+    ///
+    /// Returns `false`, leaving `self` unchanged, if the digits do not fit in a `FixedDecimal`.
+    fn set_digits(&mut self, mut digits: SmallVec<[u8; 8]>, mut end: i32) -> bool {
+        let trailing_zeros = digits.iter().rev().take_while(|&&digit| digit == 0).count();
+        digits.truncate(digits.len() - trailing_zeros);
+        end += trailing_zeros as i32;
+        let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        digits.drain(..leading_zeros);
+        if digits.is_empty() {
+            self.digits = digits;
+            self.magnitude = 0;
+            return true;
+        }
+        let magnitude = end + digits.len() as i32 - 1;
+        if end < i32::from(i16::MIN) || magnitude > i32::from(i16::MAX) {
+            return false;
+        }
+        self.digits = digits;
+        self.magnitude = magnitude as i16;
+        true
+    }
+
+    /// Adds `other` to `self`, or subtracts it if `negate` is true.
+    ///
+    /// NOTE: if the operation causes overflow, the number will be set to zero.
+    fn add_or_sub(&mut self, other: &Self, negate: bool) {
+        let is_negative = self.sign == Sign::Negative;
+        let other_is_negative = (other.sign == Sign::Negative) != negate;
+        let lower_magnitude = cmp::min(self.lower_magnitude, other.lower_magnitude);
+
+        let result_is_negative = if other.is_zero() {
+            is_negative
+        } else if self.is_zero() {
+            self.digits = other.digits.clone();
+            self.magnitude = other.magnitude;
+            other_is_negative
+        } else {
+            let end = cmp::min(self.nonzero_magnitude_end(), other.nonzero_magnitude_end());
+            let start = cmp::max(self.magnitude, other.magnitude);
+            // The digits of the result, the least significant first.
+            let mut digits: SmallVec<[u8; 8]> = SmallVec::new();
+            let result_is_negative = if is_negative == other_is_negative {
+                let mut carry = 0;
+                for magnitude in end..=start {
+                    let sum = self.digit_at(magnitude) + other.digit_at(magnitude) + carry;
+                    digits.push(sum % 10);
+                    carry = sum / 10;
+                }
+                digits.push(carry);
+                is_negative
+            } else {
+                // Subtract the smaller absolute value from the larger one.
+                let (larger, smaller, result_is_negative) = match self.cmp_abs(other) {
+                    Ordering::Less => (other, &*self, other_is_negative),
+                    _ => (&*self, other, is_negative),
+                };
+                let mut borrow = 0;
+                for magnitude in end..=start {
+                    let subtrahend = smaller.digit_at(magnitude) + borrow;
+                    let minuend = larger.digit_at(magnitude);
+                    if minuend >= subtrahend {
+                        digits.push(minuend - subtrahend);
+                        borrow = 0;
+                    } else {
+                        digits.push(minuend + 10 - subtrahend);
+                        borrow = 1;
+                    }
+                }
+                result_is_negative
+            };
+            digits.reverse();
+            if !self.set_digits(digits, i32::from(end)) {
+                self.clear();
+                return;
+            }
+            result_is_negative
+        };
+
+        self.upper_magnitude = cmp::max(self.magnitude, 0);
+        self.lower_magnitude = lower_magnitude;
+        self.sign = if result_is_negative && !self.is_zero() {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Multiplies `self` by `other`.
+    ///
+    /// NOTE: if the operation causes overflow, the number will be set to zero.
+    fn multiply(&mut self, other: &Self) {
+        let is_negative = (self.sign == Sign::Negative) != (other.sign == Sign::Negative);
+        // The product has as many fraction digits as both factors together.
+        let lower_magnitude = cmp::max(
+            i32::from(self.lower_magnitude) + i32::from(other.lower_magnitude),
+            i32::from(i16::MIN),
+        ) as i16;
+        let end =
+            i32::from(self.nonzero_magnitude_end()) + i32::from(other.nonzero_magnitude_end());
+
+        // The digits of the product, the least significant first.
+        let mut digits: SmallVec<[u8; 8]> =
+            SmallVec::from_elem(0, self.digits.len() + other.digits.len());
+        for (i, digit) in self.digits.iter().rev().enumerate() {
+            let mut carry = 0u16;
+            let mut product_digits = digits.iter_mut().skip(i);
+            for other_digit in other.digits.iter().rev() {
+                if let Some(product_digit) = product_digits.next() {
+                    let product = u16::from(*product_digit)
+                        + u16::from(*digit) * u16::from(*other_digit)
+                        + carry;
+                    *product_digit = (product % 10) as u8;
+                    carry = product / 10;
+                }
+            }
+            for product_digit in product_digits {
+                if carry == 0 {
+                    break;
+                }
+                let sum = u16::from(*product_digit) + carry;
+                *product_digit = (sum % 10) as u8;
+                carry = sum / 10;
+            }
+        }
+        digits.reverse();
+        if !self.set_digits(digits, end) {
+            self.clear();
+            return;
+        }
+
+        self.upper_magnitude = cmp::max(self.magnitude, 0);
+        self.lower_magnitude = cmp::min(lower_magnitude, 0);
+        self.sign = if is_negative && !self.is_zero() {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Divides the number by `divisor`, rounding the quotient at a particular position with the
+    /// given [`RoundingMode`].
+    ///
+    /// The quotient is shown with all its digits down to the rounding position, as by
+    /// [`FixedDecimal::round_with_mode()`].
+    ///
+    /// Returns an error if `divisor` is zero, or if the quotient exceeds the limits of a
+    /// `FixedDecimal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Error, FixedDecimal, RoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let price = FixedDecimal::from_str("10.00").unwrap();
+    /// let three = FixedDecimal::from(3);
+    /// assert_eq!(
+    ///     "3.33",
+    ///     price
+    ///         .clone()
+    ///         .divided_with_mode(&three, -2, RoundingMode::HalfEven)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "3.34",
+    ///     price
+    ///         .clone()
+    ///         .divided_with_mode(&three, -2, RoundingMode::Ceil)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    ///
+    /// let quarter = FixedDecimal::from(1)
+    ///     .divided_with_mode(&FixedDecimal::from(4), -3, RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!("0.250", quarter.to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(Error::DivisionByZero),
+    ///     price.divided_with_mode(&FixedDecimal::from(0), -2, RoundingMode::HalfEven)
+    /// );
+    /// ```
+    pub fn divided_with_mode(
+        mut self,
+        divisor: &FixedDecimal,
+        position: i16,
+        mode: RoundingMode,
+    ) -> Result<Self, Error> {
+        if divisor.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        // The quotient is computed down to a guard digit below the rounding position, followed
+        // by a nonzero digit if the division is inexact, so that it rounds as the exact value.
+        let guard = i32::from(position) - 1;
+        if guard - 1 < i32::from(i16::MIN) {
+            return Err(Error::Limit);
+        }
+        let is_negative = (self.sign == Sign::Negative) != (divisor.sign == Sign::Negative);
+
+        if !self.is_zero() {
+            // With self = a × 10^e and divisor = b × 10^f for integers a and b, the quotient
+            // digits down to the guard digit are those of a × 10^shift / b.
+            let shift = i32::from(self.nonzero_magnitude_end())
+                - i32::from(divisor.nonzero_magnitude_end())
+                - guard;
+            let dividend_zeros = cmp::max(shift, 0) as usize;
+            let mut divisor_digits = divisor.digits.clone();
+            divisor_digits.resize(divisor_digits.len() + cmp::max(-shift, 0) as usize, 0);
+
+            let mut quotient: SmallVec<[u8; 8]> = SmallVec::new();
+            // The remainder, with the most significant digit first and no leading zeros.
+            let mut remainder: SmallVec<[u8; 8]> = SmallVec::new();
+            let dividend_digits = self
+                .digits
+                .iter()
+                .copied()
+                .chain(core::iter::repeat(0).take(dividend_zeros));
+            for digit in dividend_digits {
+                if !remainder.is_empty() || digit != 0 {
+                    remainder.push(digit);
+                }
+                let mut quotient_digit = 0;
+                while cmp_digits(&remainder, &divisor_digits) != Ordering::Less {
+                    sub_digits(&mut remainder, &divisor_digits);
+                    quotient_digit += 1;
+                }
+                quotient.push(quotient_digit);
+            }
+            let mut end = guard;
+            if !remainder.is_empty() {
+                quotient.push(1);
+                end -= 1;
+            }
+            if !self.set_digits(quotient, end) {
+                return Err(Error::Limit);
+            }
+        }
+
+        self.upper_magnitude = cmp::max(self.magnitude, 0);
+        self.lower_magnitude = cmp::min(self.nonzero_magnitude_end(), 0);
+        self.sign = if is_negative && !self.is_zero() {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+        self.round_with_mode(position, mode);
+        Ok(self)
+    }
+}
+
+/// Compares two unsigned integers given by their digits, the most significant first, without
+/// leading zeros.
+fn cmp_digits(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Subtracts `b` from `a`, both given by their digits, the most significant first, without
+/// leading zeros. `a` must not be less than `b`.
+fn sub_digits(a: &mut SmallVec<[u8; 8]>, b: &[u8]) {
+    let mut borrow = 0;
+    let mut b_digits = b.iter().rev();
+    for digit in a.iter_mut().rev() {
+        let subtrahend = b_digits.next().copied().unwrap_or(0) + borrow;
+        if *digit >= subtrahend {
+            *digit -= subtrahend;
+            borrow = 0;
+        } else {
+            *digit = *digit + 10 - subtrahend;
+            borrow = 1;
+        }
+    }
+    let leading_zeros = a.iter().take_while(|&&digit| digit == 0).count();
+    a.drain(..leading_zeros);
+}
+
+macro_rules! impl_arithmetic_op {
+    ($op_trait:ident, $op:ident, $op_assign_trait:ident, $op_assign:ident, $lhs:ident, $rhs:ident, $body:expr) => {
+        impl $op_assign_trait<&FixedDecimal> for FixedDecimal {
+            fn $op_assign(&mut self, $rhs: &FixedDecimal) {
+                let $lhs = self;
+                $body
+            }
+        }
+
+        impl $op_assign_trait for FixedDecimal {
+            fn $op_assign(&mut self, rhs: FixedDecimal) {
+                self.$op_assign(&rhs);
+            }
+        }
+
+        impl $op_trait<&FixedDecimal> for FixedDecimal {
+            type Output = FixedDecimal;
+            fn $op(mut self, rhs: &FixedDecimal) -> FixedDecimal {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl $op_trait for FixedDecimal {
+            type Output = FixedDecimal;
+            fn $op(mut self, rhs: FixedDecimal) -> FixedDecimal {
+                self.$op_assign(&rhs);
+                self
+            }
+        }
+
+        impl $op_trait<&FixedDecimal> for &FixedDecimal {
+            type Output = FixedDecimal;
+            fn $op(self, rhs: &FixedDecimal) -> FixedDecimal {
+                let mut result = self.clone();
+                result.$op_assign(rhs);
+                result
+            }
+        }
+    };
+}
+
+impl_arithmetic_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    lhs,
+    rhs,
+    lhs.add_or_sub(rhs, false)
+);
+impl_arithmetic_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    lhs,
+    rhs,
+    lhs.add_or_sub(rhs, true)
+);
+impl_arithmetic_op!(Mul, mul, MulAssign, mul_assign, lhs, rhs, lhs.multiply(rhs));
+
 /// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -1808,9 +2422,9 @@ impl TryFrom<&[u8]> for FixedDecimal {
                 if i == 0 || i == no_sign_str.len() - 1 {
                     return Err(Error::Syntax);
                 }
-            } else if *c == b'-' {
-                // Allow a single minus sign after the exponent
-                if has_exponent && exponent_index == i - 1 {
+            } else if *c == b'-' || *c == b'+' {
+                // Allow a single sign after the exponent, followed by its digits
+                if has_exponent && exponent_index == i - 1 && i != no_sign_str.len() - 1 {
                     continue;
                 } else {
                     return Err(Error::Syntax);
@@ -1826,6 +2440,24 @@ impl TryFrom<&[u8]> for FixedDecimal {
         #[allow(clippy::indexing_slicing)] // exponent_index comes from enumerate
         let no_exponent_str = &no_sign_str[..exponent_index];
 
+        // The exponent, saturated at a value that is out of range for any input
+        let mut exponent = 0i32;
+        if has_exponent {
+            let mut is_negative = false;
+            #[allow(clippy::indexing_slicing)]
+            // exponent_index comes from enumerate, and is not the last index.
+            for digit in &no_sign_str[exponent_index + 1..] {
+                match *digit {
+                    b'-' => is_negative = true,
+                    b'+' => (),
+                    _ => exponent = cmp::min(exponent * 10 + i32::from(digit - b'0'), 0x10000),
+                }
+            }
+            if is_negative {
+                exponent = -exponent;
+            }
+        }
+
         // If there was no dot, truncate the dot index
         if dot_index > exponent_index {
             dot_index = exponent_index;
@@ -1880,6 +2512,7 @@ impl TryFrom<&[u8]> for FixedDecimal {
         let leftmost_digit = if let Some(leftmost_digit) = leftmost_digit {
             leftmost_digit
         } else {
+            dec.apply_exponent(exponent)?;
             return Ok(dec);
         };
 
@@ -1917,34 +2550,45 @@ impl TryFrom<&[u8]> for FixedDecimal {
         debug_assert_eq!(v_len, digits_str_len);
         dec.digits = v;
 
-        // Extract the exponent part
-        if has_exponent {
-            let mut pow = 0;
-            let mut pos_neg = 1;
-            #[allow(clippy::indexing_slicing)]
-            // exponent_index is exist, then exponent_index + 1 will equal at most no_sign_str.len().
-            for digit in &no_sign_str[exponent_index + 1..] {
-                if *digit == b'-' {
-                    pos_neg = -1;
-                    continue;
-                }
-                pow *= 10;
-                pow += (digit - b'0') as i16;
-            }
+        dec.apply_exponent(exponent)?;
 
-            dec.multiply_pow10(pos_neg * pow);
+        Ok(dec)
+    }
+}
 
-            // Clean up magnitude after multiplication
-            if dec.magnitude > 0 {
-                dec.upper_magnitude = dec.magnitude;
-            }
-            let neg_mag = dec.magnitude - dec.digits.len() as i16 + 1;
-            if neg_mag < 0 {
-                dec.lower_magnitude = neg_mag;
+impl FixedDecimal {
+    /// Multiplies a parsed number by 10 to the power of `exponent`, as written in scientific
+    /// notation. Leading zeros are dropped if the exponent is nonzero, and so are trailing
+    /// zeros, unless they are integer digits.
+    ///
+    /// Unlike [`FixedDecimal::multiply_pow10()`], this returns an error instead of clearing
+    /// the number if the result does not fit in a `FixedDecimal`.
+    fn apply_exponent(&mut self, exponent: i32) -> Result<(), Error> {
+        if exponent == 0 {
+            return Ok(());
+        }
+        let lower_magnitude = i32::from(self.lower_magnitude) + exponent;
+        let magnitude = i32::from(self.magnitude) + exponent;
+        if lower_magnitude < i32::from(i16::MIN)
+            || (!self.is_zero() && magnitude > i32::from(i16::MAX))
+        {
+            return Err(Error::Limit);
+        }
+        self.upper_magnitude = 0;
+        self.lower_magnitude = cmp::min(lower_magnitude, 0) as i16;
+        if !self.is_zero() {
+            self.magnitude = magnitude as i16;
+            self.upper_magnitude = cmp::max(self.magnitude, 0);
+            // Trailing zeros are only kept as integer digits.
+            let nonzero_magnitude_end = self.nonzero_magnitude_end();
+            if nonzero_magnitude_end < 0 {
+                self.lower_magnitude = nonzero_magnitude_end;
             }
         }
 
-        Ok(dec)
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+        Ok(())
     }
 }
 
@@ -2028,14 +2672,47 @@ impl FixedDecimal {
     /// assert_writeable_eq!(negative_zero, "-0");
     /// ```
     pub fn try_from_f64(float: f64, precision: DoublePrecision) -> Result<Self, Error> {
-        let mut decimal = Self::new_from_f64_raw(float)?;
-        let n_digits = decimal.digits.len();
+        Self::new_from_f64_raw(float)?.with_float_precision(precision)
+    }
+
+    /// Construct a [`FixedDecimal`] from an f32.
+    ///
+    /// This is the same as [`FixedDecimal::try_from_f64()`], except that with
+    /// [`DoublePrecision::Floating`], the result has the shortest representation that recovers
+    /// the original f32 value, rather than the f64 value it converts to.
+    ///
+    /// This function can be made available with the `"ryu"` Cargo feature.
+    ///
+    /// ```rust
+    /// use fixed_decimal::{DoublePrecision, FixedDecimal};
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let decimal = FixedDecimal::try_from_f32(0.1, DoublePrecision::Floating)
+    ///     .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "0.1");
+    ///
+    /// // The f64 closest to the f32 closest to 0.1 is not the f64 closest to 0.1.
+    /// let decimal = FixedDecimal::try_from_f64(0.1f32.into(), DoublePrecision::Floating)
+    ///     .expect("Finite quantity");
+    /// assert_writeable_eq!(decimal, "0.10000000149011612");
+    ///
+    /// let decimal = FixedDecimal::try_from_f32(-21.37, DoublePrecision::Magnitude(-3))
+    ///     .expect("Finite quantity with limited precision");
+    /// assert_writeable_eq!(decimal, "-21.370");
+    /// ```
+    pub fn try_from_f32(float: f32, precision: DoublePrecision) -> Result<Self, Error> {
+        Self::new_from_f32_raw(float)?.with_float_precision(precision)
+    }
+
+    /// Applies `precision` to a number parsed from the output of ryū.
+    fn with_float_precision(mut self, precision: DoublePrecision) -> Result<Self, Error> {
+        let n_digits = self.digits.len();
         // magnitude of the lowest digit in self.digits
-        let lowest_magnitude = decimal.magnitude - n_digits as i16 + 1;
+        let lowest_magnitude = self.magnitude - n_digits as i16 + 1;
         // ryū will usually tack on a `.0` to integers which gets included when parsing.
         // Explicitly remove it before doing anything else
-        if lowest_magnitude >= 0 && decimal.lower_magnitude < 0 {
-            decimal.lower_magnitude = 0;
+        if lowest_magnitude >= 0 && self.lower_magnitude < 0 {
+            self.lower_magnitude = 0;
         }
         match precision {
             DoublePrecision::Floating => (),
@@ -2045,26 +2722,26 @@ impl FixedDecimal {
                 }
             }
             DoublePrecision::Magnitude(mag) => {
-                decimal.half_even(mag);
+                self.half_even(mag);
             }
             DoublePrecision::SignificantDigits(sig) => {
                 if sig == 0 {
                     return Err(Error::Limit);
                 }
 
-                let position = decimal.magnitude - (sig as i16) + 1;
-                let old_magnitude = decimal.magnitude;
-                decimal.half_even(position);
+                let position = self.magnitude - (sig as i16) + 1;
+                let old_magnitude = self.magnitude;
+                self.half_even(position);
 
                 // This means the significant digits has been increased by 1.
-                if decimal.magnitude > old_magnitude {
-                    decimal.lower_magnitude = cmp::min(0, position + 1);
+                if self.magnitude > old_magnitude {
+                    self.lower_magnitude = cmp::min(0, position + 1);
                 }
             }
         }
         #[cfg(debug_assertions)]
-        decimal.check_invariants();
-        Ok(decimal)
+        self.check_invariants();
+        Ok(self)
     }
 
     /// Internal function for parsing directly from floats using ryū
@@ -2077,6 +2754,17 @@ impl FixedDecimal {
         let formatted = buf.format_finite(float);
         Self::from_str(formatted)
     }
+
+    /// Internal function for parsing directly from floats using ryū
+    fn new_from_f32_raw(float: f32) -> Result<Self, Error> {
+        if !float.is_finite() {
+            return Err(Error::Limit);
+        }
+        // note: this does not heap allocate
+        let mut buf = ryu::Buffer::new();
+        let formatted = buf.format_finite(float);
+        Self::from_str(formatted)
+    }
 }
 
 #[cfg(feature = "ryu")]
@@ -2258,6 +2946,73 @@ fn test_float() {
     }
 }
 
+#[cfg(feature = "ryu")]
+#[test]
+fn test_float_f32() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: f32,
+        pub precision: DoublePrecision,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: 0.1,
+            precision: DoublePrecision::Floating,
+            expected: "0.1",
+        },
+        TestCase {
+            input: 16777216.0,
+            precision: DoublePrecision::Floating,
+            expected: "16777216",
+        },
+        TestCase {
+            input: 3.4028235e38,
+            precision: DoublePrecision::Floating,
+            expected: "340282350000000000000000000000000000000",
+        },
+        TestCase {
+            input: 1.0e-45,
+            precision: DoublePrecision::Floating,
+            expected: "0.000000000000000000000000000000000000000000001",
+        },
+        TestCase {
+            input: -23.4567,
+            precision: DoublePrecision::Magnitude(-2),
+            expected: "-23.46",
+        },
+        TestCase {
+            input: 100.0,
+            precision: DoublePrecision::Integer,
+            expected: "100",
+        },
+        TestCase {
+            input: 0.00123,
+            precision: DoublePrecision::SignificantDigits(4),
+            expected: "0.001230",
+        },
+        TestCase {
+            input: -0.0,
+            precision: DoublePrecision::Floating,
+            expected: "-0",
+        },
+    ];
+
+    for case in &cases {
+        let dec = FixedDecimal::try_from_f32(case.input, case.precision).unwrap();
+        writeable::assert_writeable_eq!(dec, case.expected, "{:?}", case);
+    }
+
+    assert_eq!(
+        FixedDecimal::try_from_f32(f32::NAN, DoublePrecision::Floating),
+        Err(Error::Limit)
+    );
+    assert_eq!(
+        FixedDecimal::try_from_f32(0.5, DoublePrecision::Integer),
+        Err(Error::Limit)
+    );
+}
+
 #[test]
 fn test_basic() {
     #[derive(Debug)]
@@ -2541,11 +3296,64 @@ fn test_from_str_scientific() {
             input_str: "-9000E-10",
             output: "-0.0000009",
         },
+        TestCase {
+            input_str: "1.5e+3",
+            output: "1500",
+        },
+        TestCase {
+            input_str: "1.50e1",
+            output: "15.0",
+        },
+        TestCase {
+            input_str: "1.50e-1",
+            output: "0.15",
+        },
+        TestCase {
+            input_str: ".5e1",
+            output: "5",
+        },
+        TestCase {
+            input_str: "0.00e1",
+            output: "0.0",
+        },
+        TestCase {
+            input_str: "-0e5",
+            output: "-0",
+        },
     ];
     for cas in &cases {
         let input_str_roundtrip = FixedDecimal::from_str(cas.input_str).unwrap().to_string();
         assert_eq!(cas.output, input_str_roundtrip);
     }
+
+    assert_eq!(
+        FixedDecimal::from_str("1e-32768").map(|dec| dec.nonzero_magnitude_end()),
+        Ok(i16::MIN)
+    );
+    assert_eq!(
+        FixedDecimal::from_str("99e32766").map(|dec| dec.magnitude_range()),
+        Ok(0..=i16::MAX)
+    );
+    for input_str in [
+        "1e32768",
+        "10e32767",
+        "1e-32769",
+        "0.0e-32768",
+        "-2e99999999999",
+    ] {
+        assert_eq!(
+            FixedDecimal::from_str(input_str),
+            Err(Error::Limit),
+            "{input_str}"
+        );
+    }
+    for input_str in ["1e", "1e-", "1e+", "1e+-1", "1e1.5", "1e1e1", "e1"] {
+        assert_eq!(
+            FixedDecimal::from_str(input_str),
+            Err(Error::Syntax),
+            "{input_str}"
+        );
+    }
 }
 
 #[test]
@@ -3338,6 +4146,76 @@ fn test_rounding() {
     let mut dec = FixedDecimal::from_str("-0.009").unwrap();
     dec.half_expand(-1);
     assert_eq!("-0.0", dec.to_string());
+
+    // Test rounding up to a digit that is zero after truncation
+    let mut dec = FixedDecimal::from_str("12.075").unwrap();
+    dec.expand(-1);
+    assert_eq!("12.1", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("12.006").unwrap();
+    dec.half_even(-2);
+    assert_eq!("12.01", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("-100.01").unwrap();
+    dec.ceil(-1);
+    assert_eq!("-100.0", dec.to_string());
+
+    let mut dec = FixedDecimal::from_str("100.01").unwrap();
+    dec.ceil(-1);
+    assert_eq!("100.1", dec.to_string());
+}
+
+#[test]
+fn test_rounding_increment() {
+    use RoundingIncrement::*;
+    use RoundingMode::*;
+
+    #[derive(Debug)]
+    struct TestCase {
+        input: &'static str,
+        position: i16,
+        mode: RoundingMode,
+        increment: RoundingIncrement,
+        expected: &'static str,
+    }
+    let cases = [
+        ("1.23", -1, HalfExpand, MultiplesOf1, "1.2"),
+        ("1.23", -2, HalfExpand, MultiplesOf5, "1.25"),
+        ("1.225", -2, HalfExpand, MultiplesOf5, "1.25"),
+        ("1.2249", -2, HalfExpand, MultiplesOf5, "1.20"),
+        ("1.275", -2, HalfEven, MultiplesOf5, "1.30"),
+        ("1.325", -2, HalfEven, MultiplesOf5, "1.30"),
+        ("-1.21", -2, Ceil, MultiplesOf5, "-1.20"),
+        ("-1.21", -2, Floor, MultiplesOf5, "-1.25"),
+        ("1.21", -2, Expand, MultiplesOf2, "1.22"),
+        ("1.23", -2, Trunc, MultiplesOf2, "1.22"),
+        ("3", 0, HalfTrunc, MultiplesOf2, "2"),
+        ("3", 0, HalfExpand, MultiplesOf2, "4"),
+        ("9.9", 0, HalfExpand, MultiplesOf2, "10"),
+        ("12.4", 0, HalfFloor, MultiplesOf25, "00"),
+        ("12.5", 0, HalfExpand, MultiplesOf25, "25"),
+        ("12.5", 0, HalfFloor, MultiplesOf25, "00"),
+        ("-37.5", 0, HalfFloor, MultiplesOf25, "-50"),
+        ("-37.5", 0, HalfCeil, MultiplesOf25, "-25"),
+        ("1.25", -1, HalfExpand, MultiplesOf25, "2.5"),
+        ("1.24", -1, HalfExpand, MultiplesOf25, "0.0"),
+        ("0.87", -2, HalfExpand, MultiplesOf25, "0.75"),
+        ("999.99", -1, Expand, MultiplesOf5, "1000.0"),
+        ("0012.3", -1, HalfExpand, MultiplesOf5, "0012.5"),
+        ("0", -2, Expand, MultiplesOf5, "0.00"),
+    ]
+    .map(|(input, position, mode, increment, expected)| TestCase {
+        input,
+        position,
+        mode,
+        increment,
+        expected,
+    });
+    for cas in cases {
+        let dec: FixedDecimal = cas.input.parse().unwrap();
+        let rounded = dec.rounded_with_mode_and_increment(cas.position, cas.mode, cas.increment);
+        assert_eq!(cas.expected, rounded.to_string(), "{cas:?}");
+    }
 }
 
 #[test]
@@ -3453,3 +4331,122 @@ fn test_concatenate() {
         }
     }
 }
+
+#[test]
+fn test_add_sub() {
+    let cases = [
+        // (lhs, rhs, lhs + rhs, lhs - rhs)
+        ("1", "2", "3", "-1"),
+        ("0.5", "0.5", "1.0", "0.0"),
+        ("999.99", "0.01", "1000.00", "999.98"),
+        ("-1.25", "1.5", "0.25", "-2.75"),
+        ("-1.25", "-1.5", "-2.75", "0.25"),
+        ("1000", "0.001", "1000.001", "999.999"),
+        ("0", "-12.3", "-12.3", "12.3"),
+        ("012.3", "0", "12.3", "12.3"),
+        ("1", "1", "2", "0"),
+        (
+            "123456789012345678901234567890",
+            "1",
+            "123456789012345678901234567891",
+            "123456789012345678901234567889",
+        ),
+    ];
+    for (lhs, rhs, sum, difference) in cases {
+        let lhs = FixedDecimal::from_str(lhs).unwrap();
+        let rhs = FixedDecimal::from_str(rhs).unwrap();
+        assert_eq!((&lhs + &rhs).to_string(), sum, "{lhs} + {rhs}");
+        assert_eq!((&lhs - &rhs).to_string(), difference, "{lhs} - {rhs}");
+        let mut accumulator = lhs.clone();
+        accumulator += &rhs;
+        accumulator -= rhs.clone();
+        assert_eq!(
+            accumulator.to_string(),
+            (lhs.clone() + rhs.clone() - rhs).to_string()
+        );
+    }
+
+    // Overflow sets the number to zero.
+    let max = FixedDecimal::from(9).multiplied_pow10(i16::MAX);
+    assert!((&max + &max).is_zero());
+}
+
+#[test]
+fn test_mul() {
+    let cases = [
+        // (lhs, rhs, lhs * rhs)
+        ("2", "3", "6"),
+        ("1.5", "2", "3.0"),
+        ("-1.25", "0.4", "-0.500"),
+        ("-3", "-7", "21"),
+        ("0.00", "123", "0.00"),
+        ("-0.5", "0", "0.0"),
+        ("99", "99", "9801"),
+        (
+            "12345678901234567890",
+            "98765432109876543210",
+            "1219326311370217952237463801111263526900",
+        ),
+        ("1000", "0.001", "1.000"),
+    ];
+    for (lhs, rhs, product) in cases {
+        let lhs = FixedDecimal::from_str(lhs).unwrap();
+        let rhs = FixedDecimal::from_str(rhs).unwrap();
+        assert_eq!((&lhs * &rhs).to_string(), product, "{lhs} * {rhs}");
+        assert_eq!(
+            (rhs.clone() * lhs.clone()).to_string(),
+            product,
+            "{rhs} * {lhs}"
+        );
+    }
+
+    let max = FixedDecimal::from(1).multiplied_pow10(i16::MAX);
+    assert!((&max * &FixedDecimal::from(10)).is_zero());
+}
+
+#[test]
+fn test_divided_with_mode() {
+    let cases = [
+        // (lhs, rhs, position, mode, quotient)
+        ("10", "3", -2, RoundingMode::HalfEven, "3.33"),
+        ("10", "3", -2, RoundingMode::Ceil, "3.34"),
+        ("-10", "3", -2, RoundingMode::Floor, "-3.34"),
+        ("-10", "3", -2, RoundingMode::Trunc, "-3.33"),
+        ("1", "8", -2, RoundingMode::HalfEven, "0.12"),
+        ("3", "8", -2, RoundingMode::HalfEven, "0.38"),
+        ("1", "8", -2, RoundingMode::HalfExpand, "0.13"),
+        ("1.0000001", "8", -2, RoundingMode::HalfEven, "0.13"),
+        ("1", "4", -3, RoundingMode::HalfEven, "0.250"),
+        ("123.45", "0.05", 0, RoundingMode::HalfEven, "2469"),
+        ("0.001", "1000", -7, RoundingMode::HalfEven, "0.0000010"),
+        ("1234", "2", 2, RoundingMode::HalfEven, "600"),
+        ("0", "7", -2, RoundingMode::HalfEven, "0.00"),
+        ("1", "-3", -1, RoundingMode::HalfEven, "-0.3"),
+        ("2", "3", 0, RoundingMode::Trunc, "0"),
+    ];
+    for (lhs, rhs, position, mode, quotient) in cases {
+        let lhs = FixedDecimal::from_str(lhs).unwrap();
+        let rhs = FixedDecimal::from_str(rhs).unwrap();
+        assert_eq!(
+            lhs.clone()
+                .divided_with_mode(&rhs, position, mode)
+                .unwrap()
+                .to_string(),
+            quotient,
+            "{lhs} / {rhs} at {position} {mode:?}"
+        );
+    }
+
+    assert_eq!(
+        FixedDecimal::from(1).divided_with_mode(&FixedDecimal::from(0), 0, RoundingMode::HalfEven),
+        Err(Error::DivisionByZero)
+    );
+    assert_eq!(
+        FixedDecimal::from(1).divided_with_mode(
+            &FixedDecimal::from(3),
+            i16::MIN,
+            RoundingMode::HalfEven
+        ),
+        Err(Error::Limit)
+    );
+}
@@ -63,6 +63,8 @@ pub use decimal::DoublePrecision;
 
 pub use compact::CompactDecimal;
 pub use decimal::FixedDecimal;
+pub use decimal::RoundingIncrement;
+pub use decimal::RoundingMode;
 pub use decimal::Sign;
 pub use decimal::SignDisplay;
 use displaydoc::Display;
@@ -100,6 +102,11 @@ pub enum Error {
     /// 123 (or 123.0) must be used.
     #[displaydoc("Failed to parse the input string")]
     Syntax,
+    /// The divisor of a division is zero.
+    ///
+    /// See [`FixedDecimal::divided_with_mode()`].
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
 }
 
 #[cfg(feature = "std")]
@@ -28,6 +28,36 @@ impl ScientificDecimal {
             exponent,
         }
     }
+
+    /// Returns a reference to the significand of `self`.
+    /// ```
+    /// # use fixed_decimal::FixedDecimal;
+    /// # use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    /// #
+    /// assert_eq!(
+    ///     ScientificDecimal::from_str("+1.20e-6").unwrap().significand(),
+    ///     &FixedDecimal::from_str("+1.20").unwrap()
+    /// );
+    /// ```
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns a reference to the exponent of `self`.
+    /// ```
+    /// # use fixed_decimal::FixedInteger;
+    /// # use fixed_decimal::ScientificDecimal;
+    /// # use std::str::FromStr;
+    /// #
+    /// assert_eq!(
+    ///     ScientificDecimal::from_str("+1.20e-6").unwrap().exponent(),
+    ///     &FixedInteger::from(-6)
+    /// );
+    /// ```
+    pub fn exponent(&self) -> &FixedInteger {
+        &self.exponent
+    }
 }
 
 /// Render the [`ScientificDecimal`] as a string of ASCII digits with a possible decimal point,
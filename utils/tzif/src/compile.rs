@@ -0,0 +1,867 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::posix::{
+    DstTransitionInfo, PosixTzString, TransitionDate, TransitionDay, ZoneVariantInfo,
+};
+use crate::data::time::{
+    days_from_civil, days_in_month, weekday_from_days, year_from_days, Seconds, SECONDS_PER_DAY,
+};
+use crate::data::tzif::{DataBlock, LocalTimeTypeRecord, TzifData, TzifHeader};
+use crate::error::Error;
+use std::collections::{BTreeMap, HashMap};
+
+/// The last year for which transitions are generated for rules that apply indefinitely.
+/// Later transitions are described by the footer.
+const LAST_EXPLICIT_YEAR: i64 = 2037;
+
+/// The first year for which transitions are generated for rules that apply since `minimum`.
+const FIRST_EXPLICIT_YEAR: i64 = 1800;
+
+/// The years that may be given explicitly, which keeps the date arithmetic on them from
+/// overflowing. `minimum` and `maximum` are represented by [`i64::MIN`] and [`i64::MAX`].
+const EXPLICIT_YEARS: core::ops::RangeInclusive<i64> = -9999..=9999;
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "sunday",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+];
+
+/// The number of days before each month in a common year.
+const DAYS_BEFORE_MONTH: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+fn error(line: usize, message: &'static str) -> Error {
+    Error::Compile { line, message }
+}
+
+/// Returns the index of the keyword that `word` is a case-insensitive abbreviation of.
+///
+/// As with `zic`, keywords may be abbreviated to any unambiguous prefix.
+fn keyword(word: &str, keywords: &[&str]) -> Option<usize> {
+    let word = word.to_ascii_lowercase();
+    if word.is_empty() {
+        return None;
+    }
+    if let Some(index) = keywords.iter().position(|&keyword| keyword == word) {
+        return Some(index);
+    }
+    let mut matches = keywords
+        .iter()
+        .enumerate()
+        .filter(|(_, keyword)| keyword.starts_with(&word));
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+/// Indicates which local time a time of day is given in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeSuffix {
+    /// Wall-clock time, the default.
+    Wall,
+    /// Local standard time, designated by `s`.
+    Standard,
+    /// Universal time, designated by `u`, `g`, or `z`.
+    Universal,
+}
+
+/// Parses a time value of the form `[-]h[:mm[:ss]]` followed by an optional letter, or `-`
+/// for zero.
+///
+/// The value must fit in an `i32`, so that adding it to other times does not overflow.
+fn time(word: &str) -> Option<(i64, Option<char>)> {
+    let (word, suffix) = match word.chars().last() {
+        Some(suffix) if suffix.is_ascii_alphabetic() => {
+            (&word[..word.len() - suffix.len_utf8()], Some(suffix))
+        }
+        _ => (word, None),
+    };
+    if word == "-" {
+        return Some((0, suffix));
+    }
+    let (negative, word) = match word.strip_prefix('-') {
+        Some(word) => (true, word),
+        None => (false, word),
+    };
+    let mut seconds: i64 = 0;
+    let mut components = 0;
+    for (component, scale) in word.split(':').zip([3600, 60, 1]) {
+        if component.is_empty() || !component.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        seconds = component
+            .parse::<i64>()
+            .ok()?
+            .checked_mul(scale)
+            .and_then(|component| seconds.checked_add(component))?;
+        components += 1;
+    }
+    if components == 0 || word.split(':').count() > 3 || i32::try_from(seconds).is_err() {
+        return None;
+    }
+    Some((if negative { -seconds } else { seconds }, suffix))
+}
+
+/// Parses a time of day with an optional suffix indicating which local time it is given in.
+fn time_of_day(word: &str, line: usize) -> Result<(i64, TimeSuffix), Error> {
+    match time(word) {
+        Some((seconds, None | Some('w'))) => Ok((seconds, TimeSuffix::Wall)),
+        Some((seconds, Some('s'))) => Ok((seconds, TimeSuffix::Standard)),
+        Some((seconds, Some('u' | 'g' | 'z'))) => Ok((seconds, TimeSuffix::Universal)),
+        _ => Err(error(line, "invalid time of day")),
+    }
+}
+
+/// Parses an amount of time, such as an offset from UT or an amount of saved time.
+fn amount(word: &str, line: usize) -> Result<i64, Error> {
+    match time(word) {
+        // `s` and `d` may be used to mark saved time as standard or daylight time.
+        Some((seconds, None | Some('s' | 'd'))) => Ok(seconds),
+        _ => Err(error(line, "invalid amount of time")),
+    }
+}
+
+fn year(word: &str, line: usize) -> Result<i64, Error> {
+    match keyword(word, &["minimum", "maximum"]) {
+        Some(0) => Ok(i64::MIN),
+        Some(_) => Ok(i64::MAX),
+        None => word
+            .parse()
+            .ok()
+            .filter(|year| EXPLICIT_YEARS.contains(year))
+            .ok_or_else(|| error(line, "invalid year")),
+    }
+}
+
+fn month(word: &str, line: usize) -> Result<u16, Error> {
+    keyword(word, &MONTHS)
+        .map(|index| index as u16 + 1)
+        .ok_or_else(|| error(line, "invalid month"))
+}
+
+fn weekday(word: &str, line: usize) -> Result<u16, Error> {
+    keyword(word, &WEEKDAYS)
+        .map(|index| index as u16)
+        .ok_or_else(|| error(line, "invalid weekday"))
+}
+
+/// The day of the month on which a rule applies or a zone line ends.
+#[derive(Debug, Clone, Copy)]
+enum DaySpec {
+    /// A fixed day of the month, e.g. `5`.
+    Day(u16),
+    /// The last given weekday of the month, e.g. `lastSun`.
+    Last(u16),
+    /// The first given weekday on or after a day of the month, e.g. `Sun>=8`.
+    OnOrAfter(u16, u16),
+    /// The last given weekday on or before a day of the month, e.g. `Sun<=25`.
+    OnOrBefore(u16, u16),
+}
+
+impl DaySpec {
+    fn parse(word: &str, line: usize) -> Result<Self, Error> {
+        let day = |word: &str| {
+            word.parse::<u16>()
+                .ok()
+                .filter(|day| (1..=31).contains(day))
+                .ok_or_else(|| error(line, "invalid day of month"))
+        };
+        if let Some(weekday_name) = word
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("last"))
+            .and_then(|_| word.get(4..))
+        {
+            Ok(DaySpec::Last(weekday(weekday_name, line)?))
+        } else if let Some((weekday_name, day_of_month)) = word.split_once(">=") {
+            Ok(DaySpec::OnOrAfter(
+                weekday(weekday_name, line)?,
+                day(day_of_month)?,
+            ))
+        } else if let Some((weekday_name, day_of_month)) = word.split_once("<=") {
+            Ok(DaySpec::OnOrBefore(
+                weekday(weekday_name, line)?,
+                day(day_of_month)?,
+            ))
+        } else {
+            Ok(DaySpec::Day(day(word)?))
+        }
+    }
+
+    /// Returns the day in `month` of `year` in days since the UNIX epoch.
+    ///
+    /// The day may fall in an adjacent month for [`DaySpec::OnOrAfter`] and
+    /// [`DaySpec::OnOrBefore`].
+    fn days_since_epoch(self, year: i64, month: u16) -> i64 {
+        let first = days_from_civil(year, month, 1);
+        match self {
+            DaySpec::Day(day) => first + i64::from(day) - 1,
+            DaySpec::Last(weekday) => {
+                let last = first + days_in_month(year, month) - 1;
+                last - (weekday_from_days(last) - i64::from(weekday)).rem_euclid(7)
+            }
+            DaySpec::OnOrAfter(weekday, day) => {
+                let day = first + i64::from(day) - 1;
+                day + (i64::from(weekday) - weekday_from_days(day)).rem_euclid(7)
+            }
+            DaySpec::OnOrBefore(weekday, day) => {
+                let day = first + i64::from(day) - 1;
+                day - (weekday_from_days(day) - i64::from(weekday)).rem_euclid(7)
+            }
+        }
+    }
+}
+
+/// A `Rule` line.
+#[derive(Debug)]
+struct Rule {
+    from: i64,
+    to: i64,
+    month: u16,
+    day: DaySpec,
+    at: i64,
+    at_suffix: TimeSuffix,
+    save: i64,
+    letter: String,
+}
+
+impl Rule {
+    /// Parses the fields of a `Rule` line following the rule name:
+    /// `FROM TO - IN ON AT SAVE LETTER/S`
+    fn parse(fields: &[&str], line: usize) -> Result<Self, Error> {
+        let (from, to, kind, month_name, day, at, save, letter) = match *fields {
+            [from, to, kind, month_name, day, at, save, letter] => {
+                (from, to, kind, month_name, day, at, save, letter)
+            }
+            _ => return Err(error(line, "wrong number of fields on Rule line")),
+        };
+        if kind != "-" {
+            return Err(error(line, "unsupported rule type"));
+        }
+        let from = year(from, line)?;
+        let to = match keyword(to, &["only"]) {
+            Some(_) => from,
+            None => year(to, line)?,
+        };
+        let (at, at_suffix) = time_of_day(at, line)?;
+        Ok(Self {
+            from,
+            to,
+            month: month(month_name, line)?,
+            day: DaySpec::parse(day, line)?,
+            at,
+            at_suffix,
+            save: amount(save, line)?,
+            letter: if letter == "-" {
+                String::new()
+            } else {
+                letter.to_owned()
+            },
+        })
+    }
+
+    /// Returns the local time at which this rule applies in `year`, in seconds since the
+    /// UNIX epoch as if the local time were UT.
+    fn local_time_in_year(&self, year: i64) -> i64 {
+        self.day.days_since_epoch(year, self.month) * SECONDS_PER_DAY + self.at
+    }
+
+    /// Returns the date of this rule as a POSIX transition date, in the wall-clock time in
+    /// effect before the transition. Returns `None` if the date cannot be represented.
+    fn transition_date(&self, stdoff: i64, save_before: i64) -> Option<TransitionDate> {
+        let mut time = self.at
+            + match self.at_suffix {
+                TimeSuffix::Wall => 0,
+                TimeSuffix::Standard => save_before,
+                TimeSuffix::Universal => stdoff + save_before,
+            };
+        let day = match self.day {
+            DaySpec::Last(weekday) => TransitionDay::Mwd(self.month, 5, weekday),
+            DaySpec::Day(29) if self.month == 2 => return None,
+            DaySpec::Day(day) => {
+                TransitionDay::NoLeap(DAYS_BEFORE_MONTH.get(usize::from(self.month) - 1)? + day)
+            }
+            DaySpec::OnOrAfter(weekday, day) | DaySpec::OnOrBefore(weekday, day @ 7..) => {
+                let day = match self.day {
+                    DaySpec::OnOrBefore(..) => day - 6,
+                    _ => day,
+                };
+                // `Sun>=2` is the day after `Sat>=1`, which POSIX can express with a
+                // transition time 24 hours later.
+                let shift = (day - 1) % 7;
+                time += i64::from(shift) * SECONDS_PER_DAY;
+                let week = (day - shift - 1) / 7 + 1;
+                if week > 4 {
+                    return None;
+                }
+                TransitionDay::Mwd(self.month, week, (weekday + 7 - shift) % 7)
+            }
+            DaySpec::OnOrBefore(..) => return None,
+        };
+        if time.abs() > 167 * 3600 {
+            return None;
+        }
+        Some(TransitionDate {
+            day,
+            time: Seconds(time),
+        })
+    }
+}
+
+/// The rules of a zone line.
+#[derive(Debug)]
+enum ZoneRules {
+    /// A fixed amount of saved time, `-` for none.
+    Fixed(i64),
+    /// The name of a set of rules.
+    Named(String),
+}
+
+/// A `Zone` line or one of its continuation lines.
+#[derive(Debug)]
+struct ZoneLine {
+    line: usize,
+    stdoff: i64,
+    rules: ZoneRules,
+    format: String,
+    /// The local time until which this line applies, in seconds since the UNIX epoch as if the
+    /// local time were UT.
+    until: Option<(i64, TimeSuffix)>,
+}
+
+impl ZoneLine {
+    /// Parses the fields of a zone line following the zone name:
+    /// `STDOFF RULES FORMAT [UNTIL]`
+    fn parse(fields: &[&str], line: usize) -> Result<Self, Error> {
+        let (stdoff, rules, format, until) = match fields {
+            [stdoff, rules, format, until @ ..] if until.len() <= 4 => {
+                (stdoff, rules, format, until)
+            }
+            _ => return Err(error(line, "wrong number of fields on Zone line")),
+        };
+        let rules = if rules.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+            ZoneRules::Fixed(amount(rules, line)?)
+        } else {
+            ZoneRules::Named((*rules).to_owned())
+        };
+        let until = match until {
+            [] => None,
+            [until_year, rest @ ..] => {
+                let until_year = year(until_year, line)?;
+                let until_month = rest.first().map_or(Ok(1), |word| month(word, line))?;
+                let until_day = rest
+                    .get(1)
+                    .map_or(Ok(DaySpec::Day(1)), |word| DaySpec::parse(word, line))?;
+                let (at, suffix) = rest
+                    .get(2)
+                    .map_or(Ok((0, TimeSuffix::Wall)), |word| time_of_day(word, line))?;
+                if !EXPLICIT_YEARS.contains(&until_year) {
+                    return Err(error(line, "invalid UNTIL year"));
+                }
+                let until = until_day
+                    .days_since_epoch(until_year, until_month)
+                    .checked_mul(SECONDS_PER_DAY)
+                    .and_then(|until| until.checked_add(at))
+                    .ok_or_else(|| error(line, "invalid UNTIL time"))?;
+                Some((until, suffix))
+            }
+        };
+        Ok(Self {
+            line,
+            stdoff: amount(stdoff, line)?,
+            rules,
+            format: (*format).to_owned(),
+            until,
+        })
+    }
+
+    /// Returns the time zone designation for the given rule letter and saved time.
+    fn designation(&self, letter: &str, save: i64) -> String {
+        if let Some((std, dst)) = self.format.split_once('/') {
+            return if save == 0 { std } else { dst }.to_owned();
+        }
+        let utoff = self.stdoff + save;
+        let sign = if utoff < 0 { '-' } else { '+' };
+        let utoff = utoff.abs();
+        let mut numeric = format!("{sign}{:02}", utoff / 3600);
+        if utoff % 3600 != 0 {
+            numeric += &format!("{:02}", utoff / 60 % 60);
+            if utoff % 60 != 0 {
+                numeric += &format!("{:02}", utoff % 60);
+            }
+        }
+        self.format.replace("%s", letter).replace("%z", &numeric)
+    }
+
+    /// Converts a local time, given as if it were UT, to UT.
+    fn to_utc(&self, local: i64, suffix: TimeSuffix, save: i64) -> i64 {
+        match suffix {
+            TimeSuffix::Wall => local - self.stdoff - save,
+            TimeSuffix::Standard => local - self.stdoff,
+            TimeSuffix::Universal => local,
+        }
+    }
+}
+
+/// A local time type of a compiled zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalType {
+    utoff: i64,
+    is_dst: bool,
+    designation: String,
+}
+
+impl LocalType {
+    fn new(zone_line: &ZoneLine, letter: &str, save: i64) -> Self {
+        Self {
+            utoff: zone_line.stdoff + save,
+            is_dst: save != 0,
+            designation: zone_line.designation(letter, save),
+        }
+    }
+}
+
+/// Collects the transitions of a zone, dropping those that do not change the local time type.
+#[derive(Default)]
+struct Transitions {
+    initial: Option<LocalType>,
+    transitions: Vec<(i64, LocalType)>,
+}
+
+impl Transitions {
+    /// Sets the local time type from `time` on, or from the beginning of time if `time` is `None`.
+    fn push(&mut self, time: Option<i64>, local_type: LocalType) {
+        let time = match time {
+            Some(time) => time,
+            None => {
+                self.initial = Some(local_type);
+                return;
+            }
+        };
+        while matches!(self.transitions.last(), Some((last, _)) if *last >= time) {
+            self.transitions.pop();
+        }
+        // Like zic, a transition that takes effect no later in local time than the previous
+        // transition replaces it, so that no local time is skipped over twice.
+        if let Some(&(last_time, ref last_type)) = self.transitions.last() {
+            let before = self.before_last().cloned();
+            if matches!(&before, Some(before) if time + last_type.utoff <= last_time + before.utoff)
+            {
+                self.transitions.pop();
+                if before.as_ref() != Some(&local_type) {
+                    self.transitions.push((last_time, local_type));
+                }
+                return;
+            }
+        }
+        if self.last() != Some(&local_type) {
+            self.transitions.push((time, local_type));
+        }
+    }
+
+    fn before_last(&self) -> Option<&LocalType> {
+        self.transitions
+            .len()
+            .checked_sub(2)
+            .and_then(|index| self.transitions.get(index))
+            .map(|(_, local_type)| local_type)
+            .or(self.initial.as_ref())
+    }
+
+    fn last(&self) -> Option<&LocalType> {
+        self.transitions
+            .last()
+            .map(|(_, local_type)| local_type)
+            .or(self.initial.as_ref())
+    }
+}
+
+/// Compiles the lines of a zone into `TZif` data.
+fn compile_zone(
+    zone_lines: &[ZoneLine],
+    rules: &HashMap<&str, Vec<Rule>>,
+) -> Result<TzifData, Error> {
+    let mut transitions = Transitions::default();
+    let mut footer = None;
+    let mut start = None;
+    for (index, zone_line) in zone_lines.iter().enumerate() {
+        let is_last = index + 1 == zone_lines.len();
+        if zone_line.until.is_none() != is_last {
+            return Err(error(
+                zone_line.line,
+                "only the last line of a zone may omit UNTIL",
+            ));
+        }
+        let line_rules = match &zone_line.rules {
+            ZoneRules::Fixed(save) => {
+                let local_type = LocalType::new(zone_line, "", *save);
+                if is_last {
+                    footer = Some(PosixTzString {
+                        std_info: ZoneVariantInfo {
+                            name: local_type.designation.clone(),
+                            offset: Seconds(-local_type.utoff),
+                        },
+                        dst_info: None,
+                    });
+                }
+                transitions.push(start, local_type);
+                start = zone_line
+                    .until
+                    .map(|(local, suffix)| zone_line.to_utc(local, suffix, *save));
+                continue;
+            }
+            ZoneRules::Named(name) => rules
+                .get(name.as_str())
+                .ok_or_else(|| error(zone_line.line, "unknown rule"))?,
+        };
+
+        let finite_years = line_rules
+            .iter()
+            .flat_map(|rule| [rule.from, rule.to])
+            .filter(|&year| year != i64::MIN && year != i64::MAX);
+        // Rules that took effect before the start of the line determine its initial
+        // local time type, so all of their years are considered.
+        let first_year = finite_years.clone().min().unwrap_or(FIRST_EXPLICIT_YEAR);
+        let last_year = match zone_line.until {
+            Some((until, _)) => year_from_days(until.div_euclid(SECONDS_PER_DAY)),
+            None => finite_years.max().unwrap_or(0).max(LAST_EXPLICIT_YEAR),
+        };
+        let mut events = Vec::new();
+        for year in first_year..=last_year {
+            for rule in line_rules {
+                if rule.from <= year && year <= rule.to {
+                    events.push((rule.local_time_in_year(year), rule));
+                }
+            }
+        }
+        events.sort_by_key(|&(local, _)| local);
+        let mut save = 0;
+        let events: Vec<(i64, &Rule)> = events
+            .into_iter()
+            .map(|(local, rule)| {
+                let utc = zone_line.to_utc(local, rule.at_suffix, save);
+                save = rule.save;
+                (utc, rule)
+            })
+            .collect();
+
+        // Before any rule applies, standard time uses the letter of the earliest rule
+        // without saved time.
+        let default_letter = line_rules
+            .iter()
+            .filter(|rule| rule.save == 0)
+            .min_by_key(|rule| rule.from)
+            .map_or("", |rule| rule.letter.as_str());
+        let (mut save, start_letter) = start
+            .and_then(|start| events.iter().rev().find(|(utc, _)| *utc <= start))
+            .map_or((0, default_letter), |(_, rule)| {
+                (rule.save, rule.letter.as_str())
+            });
+        transitions.push(start, LocalType::new(zone_line, start_letter, save));
+
+        // UNTIL is interpreted using the saved time in effect just before it.
+        let until_utc = |save| {
+            zone_line
+                .until
+                .map(|(local, suffix)| zone_line.to_utc(local, suffix, save))
+        };
+        for &(utc, rule) in &events {
+            if matches!(until_utc(save), Some(until) if utc >= until) {
+                break;
+            }
+            if start.map_or(true, |start| utc > start) {
+                transitions.push(
+                    Some(utc),
+                    LocalType::new(zone_line, &rule.letter, rule.save),
+                );
+                save = rule.save;
+            }
+        }
+        let until = until_utc(save);
+
+        if is_last {
+            footer = Some(posix_footer(zone_line, line_rules, &transitions)?);
+        }
+        start = until;
+    }
+
+    let initial = transitions.initial.clone().ok_or_else(|| {
+        error(
+            zone_lines.first().map_or(0, |zone_line| zone_line.line),
+            "empty zone",
+        )
+    })?;
+    let version = match &footer {
+        Some(PosixTzString {
+            dst_info: Some(dst_info),
+            ..
+        }) if [dst_info.start_date.time, dst_info.end_date.time]
+            .iter()
+            .any(|time| time.0 < 0 || time.0 > 24 * 3600) =>
+        {
+            3
+        }
+        _ => 2,
+    };
+    Ok(tzif_data(
+        version,
+        initial,
+        &transitions.transitions,
+        footer,
+    ))
+}
+
+/// Returns the footer for the last line of a zone, which uses named rules.
+fn posix_footer(
+    zone_line: &ZoneLine,
+    line_rules: &[Rule],
+    transitions: &Transitions,
+) -> Result<PosixTzString, Error> {
+    let ongoing: Vec<&Rule> = line_rules
+        .iter()
+        .filter(|rule| rule.to == i64::MAX)
+        .collect();
+    match *ongoing {
+        [] => {
+            let last = transitions
+                .last()
+                .ok_or_else(|| error(zone_line.line, "empty zone"))?;
+            Ok(PosixTzString {
+                std_info: ZoneVariantInfo {
+                    name: last.designation.clone(),
+                    offset: Seconds(-last.utoff),
+                },
+                dst_info: None,
+            })
+        }
+        [first, second] if (first.save == 0) != (second.save == 0) => {
+            let (std_rule, dst_rule) = if first.save == 0 {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            let unsupported = || {
+                error(
+                    zone_line.line,
+                    "rules cannot be represented as a POSIX time-zone string",
+                )
+            };
+            Ok(PosixTzString {
+                std_info: ZoneVariantInfo {
+                    name: zone_line.designation(&std_rule.letter, 0),
+                    offset: Seconds(-zone_line.stdoff),
+                },
+                dst_info: Some(DstTransitionInfo {
+                    variant_info: ZoneVariantInfo {
+                        name: zone_line.designation(&dst_rule.letter, dst_rule.save),
+                        offset: Seconds(-zone_line.stdoff - dst_rule.save),
+                    },
+                    start_date: dst_rule
+                        .transition_date(zone_line.stdoff, 0)
+                        .ok_or_else(unsupported)?,
+                    end_date: std_rule
+                        .transition_date(zone_line.stdoff, dst_rule.save)
+                        .ok_or_else(unsupported)?,
+                }),
+            })
+        }
+        _ => Err(error(
+            zone_line.line,
+            "expected zero or two rules that apply indefinitely",
+        )),
+    }
+}
+
+/// Builds `TZif` data from the local time type at the beginning of time and the transitions.
+fn tzif_data(
+    version: usize,
+    initial: LocalType,
+    transitions: &[(i64, LocalType)],
+    footer: Option<PosixTzString>,
+) -> TzifData {
+    // Times before the first transition use the first local time type.
+    let mut local_types = vec![initial];
+    let mut transition_types = Vec::with_capacity(transitions.len());
+    for (_, local_type) in transitions {
+        let index = match local_types.iter().position(|t| t == local_type) {
+            Some(index) => index,
+            None => {
+                local_types.push(local_type.clone());
+                local_types.len() - 1
+            }
+        };
+        transition_types.push(index);
+    }
+
+    let mut charcnt = 0;
+    let mut designation_indices: Vec<(&str, usize)> = Vec::new();
+    let local_time_type_records: Vec<LocalTimeTypeRecord> = local_types
+        .iter()
+        .map(|local_type| {
+            let designation = local_type.designation.as_str();
+            let idx = match designation_indices.iter().find(|(d, _)| *d == designation) {
+                Some(&(_, idx)) => idx,
+                None => {
+                    designation_indices.push((designation, charcnt));
+                    charcnt += designation.len() + 1;
+                    charcnt - designation.len() - 1
+                }
+            };
+            LocalTimeTypeRecord {
+                utoff: Seconds(local_type.utoff),
+                is_dst: local_type.is_dst,
+                idx,
+            }
+        })
+        .collect();
+    let time_zone_designations: Vec<String> = local_types
+        .iter()
+        .map(|local_type| local_type.designation.clone())
+        .collect();
+
+    let block = |transitions: &mut dyn Iterator<Item = (i64, usize)>| {
+        let (transition_times, transition_types) = transitions
+            .map(|(time, index)| (Seconds(time), index))
+            .unzip();
+        DataBlock {
+            transition_times,
+            transition_types,
+            local_time_type_records: local_time_type_records.clone(),
+            time_zone_designations: time_zone_designations.clone(),
+            leap_second_records: Vec::new(),
+            standard_wall_indicators: Vec::new(),
+            ut_local_indicators: Vec::new(),
+        }
+    };
+    let all_transitions = transitions
+        .iter()
+        .map(|(time, _)| *time)
+        .zip(transition_types.iter().copied());
+    // The version-1 data block only has 32-bit transition times.
+    let data_block1 = block(
+        &mut all_transitions
+            .clone()
+            .filter(|(time, _)| i32::try_from(*time).is_ok()),
+    );
+    let data_block2 = block(&mut all_transitions.clone());
+    let header = |block: &DataBlock| TzifHeader {
+        version,
+        isutcnt: 0,
+        isstdcnt: 0,
+        leapcnt: 0,
+        timecnt: block.transition_times.len(),
+        typecnt: block.local_time_type_records.len(),
+        charcnt,
+    };
+    TzifData {
+        header1: header(&data_block1),
+        data_block1,
+        header2: Some(header(&data_block2)),
+        data_block2: Some(data_block2),
+        footer,
+    }
+}
+
+/// Compiles tzdata source text, in the input format of `zic`, into `TZif` data for each zone
+/// and link.
+///
+/// `Rule`, `Zone`, and `Link` lines are supported, with keywords, month names, and weekday
+/// names abbreviated as accepted by `zic`.
+pub fn tzdata(source: &str) -> Result<BTreeMap<String, TzifData>, Error> {
+    let mut rules: HashMap<&str, Vec<Rule>> = HashMap::new();
+    let mut zones: Vec<(&str, Vec<ZoneLine>)> = Vec::new();
+    let mut links = Vec::new();
+    let mut continuation = false;
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let fields: Vec<&str> = text
+            .split('#')
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect();
+        let (first, rest) = match fields.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+        if continuation {
+            let zone_line = ZoneLine::parse(&fields, line)?;
+            continuation = zone_line.until.is_some();
+            if let Some((_, zone_lines)) = zones.last_mut() {
+                zone_lines.push(zone_line);
+            }
+            continue;
+        }
+        match keyword(first, &["rule", "zone", "link"]) {
+            Some(0) => {
+                let (name, rule_fields) = rest
+                    .split_first()
+                    .ok_or_else(|| error(line, "wrong number of fields on Rule line"))?;
+                rules
+                    .entry(name)
+                    .or_default()
+                    .push(Rule::parse(rule_fields, line)?);
+            }
+            Some(1) => {
+                let (name, zone_fields) = rest
+                    .split_first()
+                    .ok_or_else(|| error(line, "wrong number of fields on Zone line"))?;
+                let zone_line = ZoneLine::parse(zone_fields, line)?;
+                continuation = zone_line.until.is_some();
+                zones.push((name, vec![zone_line]));
+            }
+            Some(_) => match *rest {
+                [target, name] => links.push((line, target, name)),
+                _ => return Err(error(line, "wrong number of fields on Link line")),
+            },
+            None => return Err(error(line, "unsupported line")),
+        }
+    }
+    if continuation {
+        return Err(error(
+            source.lines().count(),
+            "missing zone continuation line",
+        ));
+    }
+
+    let mut compiled = BTreeMap::new();
+    for (name, zone_lines) in &zones {
+        compiled.insert((*name).to_owned(), compile_zone(zone_lines, &rules)?);
+    }
+    // Links may point to other links.
+    while !links.is_empty() {
+        let count = links.len();
+        links.retain(|&(_, target, name)| match compiled.get(target).cloned() {
+            Some(data) => {
+                compiled.insert(name.to_owned(), data);
+                false
+            }
+            None => true,
+        });
+        if let (Some(&(line, ..)), true) = (links.first(), links.len() == count) {
+            return Err(error(line, "unknown link target"));
+        }
+    }
+    Ok(compiled)
+}
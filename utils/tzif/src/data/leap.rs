@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::time::Seconds;
+use super::tzif::LeapSecondRecord;
+
+/// The difference between TAI and UTC before the first leap second, which was inserted at
+/// the end of 1972-06-30.
+///
+/// The difference was not an integral number of seconds before 1972, which is ignored here.
+pub const TAI_MINUS_UTC_BASE: Seconds = Seconds(10);
+
+/// A UTC instant, as POSIX time together with whether it falls within a leap second.
+///
+/// POSIX time does not count leap seconds, so a positive leap second has the same POSIX
+/// time as the second before it: the leap second `23:59:60` is represented as the POSIX
+/// time of `23:59:59` with `is_leap_second` set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UtcTime {
+    /// The number of non-leap seconds since the UNIX epoch.
+    pub posix: Seconds,
+    /// Whether this instant is within a leap second, which is displayed as second 60.
+    pub is_leap_second: bool,
+}
+
+impl UtcTime {
+    /// Creates a [`UtcTime`] that is not within a leap second.
+    pub fn from_posix(posix: Seconds) -> Self {
+        Self {
+            posix,
+            is_leap_second: false,
+        }
+    }
+
+    /// Returns the second of the minute, from 0 to 60.
+    pub fn second(self) -> u8 {
+        // The remainder is always in 0..60.
+        self.posix.0.rem_euclid(60) as u8 + u8::from(self.is_leap_second)
+    }
+}
+
+/// A view of leap-second records that converts between UTC, TAI, and leap time.
+///
+/// Leap time, as used by the transition times of `TZif` files with leap-second records
+/// (such as those in the `right/` directory of the time zone database), counts every
+/// second since the UNIX epoch, including leap seconds. TAI is represented as seconds
+/// since 1970-01-01T00:00:00 TAI, and is [`TAI_MINUS_UTC_BASE`] ahead of leap time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LeapSeconds<'a> {
+    records: &'a [LeapSecondRecord],
+}
+
+impl<'a> LeapSeconds<'a> {
+    /// Creates a view of `records`, which must be sorted by occurrence as in a `TZif` file.
+    pub fn new(records: &'a [LeapSecondRecord]) -> Self {
+        Self { records }
+    }
+
+    /// Returns the leap-second records.
+    pub fn records(&self) -> &'a [LeapSecondRecord] {
+        self.records
+    }
+
+    /// Returns the correction in effect before the record at `index`.
+    fn correction_before(&self, index: usize) -> i64 {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.records.get(index))
+            .map_or(0, |record| i64::from(record.correction))
+    }
+
+    /// Converts leap time to UTC, flagging leap seconds.
+    pub fn utc_from_leap_time(&self, leap_time: Seconds) -> UtcTime {
+        let index = self
+            .records
+            .partition_point(|record| record.occurrence <= leap_time);
+        let record = match index.checked_sub(1).and_then(|i| self.records.get(i)) {
+            Some(record) => record,
+            None => return UtcTime::from_posix(leap_time),
+        };
+        let correction = i64::from(record.correction);
+        UtcTime {
+            posix: Seconds(leap_time.0 - correction),
+            is_leap_second: record.occurrence == leap_time
+                && correction > self.correction_before(index - 1),
+        }
+    }
+
+    /// Converts UTC to leap time.
+    ///
+    /// If `utc` is flagged as a leap second but there is no leap second at that time, the
+    /// flag is ignored.
+    pub fn leap_time_from_utc(&self, utc: UtcTime) -> Seconds {
+        let mut correction = 0;
+        for (index, record) in self.records.iter().enumerate() {
+            let next_correction = i64::from(record.correction);
+            let is_positive = next_correction > self.correction_before(index);
+            // The POSIX time of the leap second itself, or of the second after a negative
+            // leap second.
+            let posix = record.occurrence.0 - next_correction;
+            if is_positive && utc.is_leap_second && utc.posix.0 == posix {
+                return record.occurrence;
+            }
+            if utc.posix.0 < posix + i64::from(is_positive) {
+                break;
+            }
+            correction = next_correction;
+        }
+        Seconds(utc.posix.0 + correction)
+    }
+
+    /// Converts TAI to UTC, flagging leap seconds.
+    pub fn utc_from_tai(&self, tai: Seconds) -> UtcTime {
+        self.utc_from_leap_time(tai - TAI_MINUS_UTC_BASE)
+    }
+
+    /// Converts UTC to TAI.
+    ///
+    /// If `utc` is flagged as a leap second but there is no leap second at that time, the
+    /// flag is ignored.
+    pub fn tai_from_utc(&self, utc: UtcTime) -> Seconds {
+        self.leap_time_from_utc(utc) + TAI_MINUS_UTC_BASE
+    }
+
+    /// Converts POSIX time to TAI.
+    pub fn tai_from_posix(&self, posix: Seconds) -> Seconds {
+        self.tai_from_utc(UtcTime::from_posix(posix))
+    }
+
+    /// Converts TAI to POSIX time.
+    ///
+    /// A leap second has the same POSIX time as the second before it; use
+    /// [`Self::utc_from_tai`] to distinguish them.
+    pub fn posix_from_tai(&self, tai: Seconds) -> Seconds {
+        self.utc_from_tai(tai).posix
+    }
+
+    /// Returns the difference between TAI and UTC at `posix` seconds since the UNIX epoch.
+    pub fn tai_minus_utc(&self, posix: Seconds) -> Seconds {
+        self.tai_from_posix(posix) - posix
+    }
+
+    /// Returns whether `leap_time` is within a leap second.
+    pub fn is_leap_second(&self, leap_time: Seconds) -> bool {
+        self.utc_from_leap_time(leap_time).is_leap_second
+    }
+}
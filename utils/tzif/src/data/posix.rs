@@ -2,7 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use super::time::Seconds;
+use super::time::{
+    days_from_civil, days_in_month, is_leap_year, weekday_from_days, year_from_days, Seconds,
+    SECONDS_PER_DAY,
+};
+use std::fmt;
 
 /// A struct to hold a time-zone variant name and its offset.
 /// The offset is how many hours must be added to the time to reach UTC.
@@ -68,3 +72,182 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+/// The local time type in effect at a given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalTimeType<'a> {
+    /// The number of seconds to be added to UT in order to determine local time.
+    pub utoff: Seconds,
+    /// Whether local time is considered Daylight Saving Time (DST).
+    pub is_dst: bool,
+    /// The time-zone designation, such as "PST" or "PDT".
+    pub designation: &'a str,
+}
+
+/// A change of local time type at a given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition<'a> {
+    /// The UNIX time at which the transition occurs.
+    pub time: Seconds,
+    /// The local time type in effect from the time of the transition.
+    pub local_time_type: LocalTimeType<'a>,
+}
+
+impl TransitionDay {
+    /// Returns the zero-based day of the year on which this transition day falls in `year`.
+    ///
+    /// For [`TransitionDay::Mwd`], week `5` designates the last such weekday in the month.
+    pub fn day_of_year(self, year: i64) -> u16 {
+        match self {
+            // Feb. 29 is never counted, so March 1 is always J60.
+            TransitionDay::NoLeap(day) if is_leap_year(year) && day >= 60 => day,
+            TransitionDay::NoLeap(day) => day.saturating_sub(1),
+            TransitionDay::WithLeap(day) => day,
+            TransitionDay::Mwd(month, week, weekday) => {
+                let first_of_month = days_from_civil(year, month, 1);
+                let mut day = first_of_month
+                    + (i64::from(weekday) - weekday_from_days(first_of_month)).rem_euclid(7)
+                    + 7 * (i64::from(week) - 1);
+                while day >= first_of_month + days_in_month(year, month) {
+                    day -= 7;
+                }
+                (day - days_from_civil(year, 1, 1)) as u16
+            }
+        }
+    }
+}
+
+impl TransitionDate {
+    /// Returns the local time at which this transition occurs in `year`, in seconds
+    /// since the UNIX epoch as if the local time were UT.
+    pub fn local_time_in_year(&self, year: i64) -> Seconds {
+        let day = days_from_civil(year, 1, 1) + i64::from(self.day.day_of_year(year));
+        Seconds(day * SECONDS_PER_DAY) + self.time
+    }
+}
+
+impl PosixTzString {
+    /// Returns the local time type of standard time.
+    pub fn std_local_time_type(&self) -> LocalTimeType<'_> {
+        LocalTimeType {
+            // POSIX offsets are the amount to add to local time to reach UT.
+            utoff: Seconds(-self.std_info.offset.0),
+            is_dst: false,
+            designation: &self.std_info.name,
+        }
+    }
+
+    /// Returns the local time type of Daylight Saving Time, if this time zone observes it.
+    pub fn dst_local_time_type(&self) -> Option<LocalTimeType<'_>> {
+        self.dst_info.as_ref().map(|dst_info| LocalTimeType {
+            utoff: Seconds(-dst_info.variant_info.offset.0),
+            is_dst: true,
+            designation: &dst_info.variant_info.name,
+        })
+    }
+
+    /// Returns the transitions into and out of Daylight Saving Time whose rule dates fall
+    /// in `year`, sorted by time.
+    ///
+    /// Returns an empty [`Vec`] if this time zone does not observe Daylight Saving Time.
+    pub fn transitions_in_year(&self, year: i64) -> Vec<Transition<'_>> {
+        let (dst_info, dst) = match (&self.dst_info, self.dst_local_time_type()) {
+            (Some(dst_info), Some(dst)) => (dst_info, dst),
+            _ => return Vec::new(),
+        };
+        let std = self.std_local_time_type();
+        // The start date is given in local standard time, the end date in local DST.
+        let mut transitions = vec![
+            Transition {
+                time: dst_info.start_date.local_time_in_year(year) - std.utoff,
+                local_time_type: dst,
+            },
+            Transition {
+                time: dst_info.end_date.local_time_in_year(year) - dst.utoff,
+                local_time_type: std,
+            },
+        ];
+        transitions.sort_by_key(|transition| transition.time);
+        transitions
+    }
+
+    /// Returns the local time type in effect at `time` seconds since the UNIX epoch.
+    pub fn local_time_type_at(&self, time: Seconds) -> LocalTimeType<'_> {
+        let year = year_from_days(time.0.div_euclid(SECONDS_PER_DAY));
+        // A transition of the previous year may still be in effect at the start of the year,
+        // and one of the next year may already be in effect at its end.
+        (year - 1..=year + 1)
+            .flat_map(|year| self.transitions_in_year(year))
+            .rev()
+            .find(|transition| transition.time <= time)
+            .map_or_else(
+                || self.std_local_time_type(),
+                |transition| transition.local_time_type,
+            )
+    }
+}
+
+impl fmt::Display for TransitionDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionDay::NoLeap(day) => write!(f, "J{day}"),
+            TransitionDay::WithLeap(day) => write!(f, "{day}"),
+            TransitionDay::Mwd(month, week, day) => write!(f, "M{month}.{week}.{day}"),
+        }
+    }
+}
+
+impl fmt::Display for TransitionDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.day)?;
+        // The time defaults to 02:00:00.
+        if self.time != Seconds(2 * 60 * 60) {
+            write!(f, "/")?;
+            write_time(f, self.time)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the time-zone string in the form parsed by [`parse_posix_tz_string`](crate::parse_posix_tz_string),
+/// e.g. `PST8PDT,M3.2.0,M11.1.0`.
+impl fmt::Display for PosixTzString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_name(f, &self.std_info.name)?;
+        write_time(f, self.std_info.offset)?;
+        if let Some(dst_info) = &self.dst_info {
+            write_name(f, &dst_info.variant_info.name)?;
+            // The DST offset defaults to one hour ahead of standard time.
+            if dst_info.variant_info.offset != self.std_info.offset - Seconds(60 * 60) {
+                write_time(f, dst_info.variant_info.offset)?;
+            }
+            write!(f, ",{},{}", dst_info.start_date, dst_info.end_date)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a time-zone variant name, quoting it in angled brackets if it is not alphabetic.
+fn write_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    if name.bytes().all(|byte| byte.is_ascii_alphabetic()) {
+        write!(f, "{name}")
+    } else {
+        write!(f, "<{name}>")
+    }
+}
+
+/// Writes a time value of the form `[-]h[:mm[:ss]]`.
+fn write_time(f: &mut fmt::Formatter<'_>, time: Seconds) -> fmt::Result {
+    if time.0 < 0 {
+        write!(f, "-")?;
+    }
+    let seconds = time.0.abs();
+    write!(f, "{}", seconds / 3600)?;
+    if seconds % 3600 != 0 {
+        write!(f, ":{:02}", seconds / 60 % 60)?;
+        if seconds % 60 != 0 {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+    }
+    Ok(())
+}
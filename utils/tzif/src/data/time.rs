@@ -4,6 +4,8 @@
 
 use std::ops::{Add, Sub};
 
+pub(crate) const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
 /// The seconds unit of time.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Seconds(pub i64);
@@ -45,3 +47,47 @@ impl Hours {
         Seconds(self.0 * 60 * 60)
     }
 }
+
+pub(crate) fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i64, month: u16) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the day of the week of a day since the UNIX epoch, with `0` being Sunday.
+pub(crate) fn weekday_from_days(days: i64) -> i64 {
+    // 1970-01-01 was a Thursday.
+    (days + 4).rem_euclid(7)
+}
+
+/// Returns the number of days since the UNIX epoch of a date in the proleptic Gregorian calendar.
+pub(crate) fn days_from_civil(year: i64, month: u16, day: u16) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the year in the proleptic Gregorian calendar of a day since the UNIX epoch.
+pub(crate) fn year_from_days(days: i64) -> i64 {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    // Years in this algorithm start in March.
+    era * 400 + year_of_era + i64::from(month_index >= 10)
+}
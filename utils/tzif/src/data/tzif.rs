@@ -2,8 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use super::leap::LeapSeconds;
 use super::time::Seconds;
-use crate::data::posix::PosixTzString;
+use crate::data::posix::{LocalTimeType, PosixTzString};
 
 /// A `TZif` file header.
 /// See <https://datatracker.ietf.org/doc/html/rfc8536> for more information.
@@ -113,7 +114,7 @@ impl TzifHeader {
 /// >                     |   Footer    |
 /// >                     +-------------+
 /// > ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TzifData {
     /// The version-1 header, which is always present.
     pub header1: TzifHeader,
@@ -123,7 +124,8 @@ pub struct TzifData {
     pub header2: Option<TzifHeader>,
     /// The vesrion-2+ data block, which is present only in version 2 and 3 `TZif` files.
     pub data_block2: Option<DataBlock>,
-    /// The version-2+ footer, which is present only in version 2 and 3 `TZif` files.
+    /// The version-2+ footer, which is present only in version 2 and 3 `TZif` files, and only
+    /// if its POSIX time-zone string is not empty.
     pub footer: Option<PosixTzString>,
 }
 
@@ -150,6 +152,39 @@ impl TzifData {
             _ => self.header2.as_ref().map(TzifHeader::block_size::<V>),
         }
     }
+
+    /// Returns the local time type in effect at `time` seconds since the UNIX epoch.
+    ///
+    /// The transitions of the version-2+ data block are used if present, otherwise those of
+    /// the version-1 data block. Past the last transition, or if there are no transitions,
+    /// the footer's POSIX time-zone string is used if present.
+    ///
+    /// Returns [`None`] if the data block is inconsistent.
+    pub fn local_time_type_at(&self, time: Seconds) -> Option<LocalTimeType<'_>> {
+        let block = self.data_block2.as_ref().unwrap_or(&self.data_block1);
+        let index = block.transition_times.partition_point(|&t| t <= time);
+        let record_index = match (index, &self.footer) {
+            (index, Some(footer)) if index == block.transition_times.len() => {
+                return Some(footer.local_time_type_at(time))
+            }
+            // Times before the first transition use the first local time type.
+            (0, _) => 0,
+            (index, _) => *block.transition_types.get(index - 1)?,
+        };
+        let record = block.local_time_type_records.get(record_index)?;
+        Some(LocalTimeType {
+            utoff: record.utoff,
+            is_dst: record.is_dst,
+            designation: block.time_zone_designations.get(record_index)?,
+        })
+    }
+
+    /// Returns the leap-second records of the version-2+ data block if present, otherwise
+    /// those of the version-1 data block.
+    pub fn leap_seconds(&self) -> LeapSeconds<'_> {
+        let block = self.data_block2.as_ref().unwrap_or(&self.data_block1);
+        LeapSeconds::new(&block.leap_second_records)
+    }
 }
 
 /// A record specifying a local time type.
@@ -261,7 +296,7 @@ pub enum UtLocalIndicator {
 /// >      |  UT/local indicators       (isutcnt)                    |
 /// >      +---------------------------------------------------------+
 /// > ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct DataBlock {
     /// A series of four- or eight-byte UNIX leap-time
     /// values sorted in strictly ascending order.  Each value is used as
@@ -13,6 +13,13 @@ pub enum Error {
     Read(combine::stream::read::Error),
     /// A [`combine::error::UnexpectedParse`].
     Parse(combine::error::UnexpectedParse),
+    /// An error in tzdata source text.
+    Compile {
+        /// The line number, starting at 1, at which the error occurred.
+        line: usize,
+        /// A description of the error.
+        message: &'static str,
+    },
 }
 
 impl From<std::io::Error> for Error {
@@ -39,6 +46,7 @@ impl fmt::Display for Error {
             Error::Io(err) => write!(f, "{err}"),
             Error::Read(err) => write!(f, "{err}"),
             Error::Parse(err) => write!(f, "{err}"),
+            Error::Compile { line, message } => write!(f, "line {line}: {message}"),
         }
     }
 }
@@ -22,13 +22,62 @@
 //! let data =
 //!     tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
 //! ```
+//!
+//! ### Evaluate POSIX time-zone strings
+//! ```rust
+//! use tzif::data::time::Seconds;
+//!
+//! let data = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+//! // 2023-07-01T00:00:00Z
+//! let local_time_type = data.local_time_type_at(Seconds(1688169600));
+//! assert_eq!(local_time_type.designation, "PDT");
+//! assert_eq!(local_time_type.utoff, Seconds(-7 * 3600));
+//! ```
+//!
+//! ### Write TZif files
+//! ```no_run
+//! let data = tzif::parse_tzif_file("path_to_file").unwrap();
+//! tzif::write_tzif_file(&data, "path_to_copy").unwrap();
+//! ```
+//!
+//! ### Compile tzdata source files
+//! ```rust
+//! let source = "
+//! Rule  Japan  1948  only  -  May  Sat>=1   24:00  1:00  D
+//! Rule  Japan  1948  1951  -  Sep  Sat>=8   25:00  0     S
+//! Rule  Japan  1949  only  -  Apr  Sat>=1   24:00  1:00  D
+//! Rule  Japan  1950  1951  -  May  Sat>=1   24:00  1:00  D
+//! Zone  Asia/Tokyo  9:18:59  -      LMT  1887 Dec 31 15:00u
+//!                   9:00     Japan  J%sT
+//! ";
+//! let zones = tzif::compile_tzdata(source).unwrap();
+//! let footer = zones["Asia/Tokyo"].footer.as_ref().unwrap();
+//! assert_eq!(footer.to_string(), "JST-9");
+//!
+//! let mut bytes = Vec::new();
+//! tzif::write_tzif(&zones["Asia/Tokyo"], &mut bytes).unwrap();
+//! assert_eq!(tzif::parse_tzif(&bytes).unwrap(), zones["Asia/Tokyo"]);
+//! ```
+//!
+//! ### Convert between UTC and TAI
+//! ```no_run
+//! use tzif::data::time::Seconds;
+//!
+//! let data = tzif::parse_tzif_file("path_to_right_UTC").unwrap();
+//! // 2017-01-01T00:00:36 TAI
+//! let utc = data.leap_seconds().utc_from_tai(Seconds(1483228836));
+//! assert!(utc.is_leap_second);
+//! assert_eq!(utc.second(), 60);
+//! ```
 
 #![warn(missing_docs)]
 
 use combine::{stream, Parser};
 use data::{posix::PosixTzString, tzif::TzifData};
 use error::Error;
+use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 /// The parsed data representations.
@@ -40,6 +89,12 @@ pub mod parse;
 /// Error types an implementations.
 pub mod error;
 
+/// The writer implementation.
+pub mod write;
+
+/// The tzdata source compiler implementation.
+pub mod compile;
+
 /// Parses a `TZif` file at the provided `path`.
 pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     let file = File::open(path)?;
@@ -50,7 +105,30 @@ pub fn parse_tzif_file<P: AsRef<Path>>(path: P) -> Result<TzifData, Error> {
     Ok(parse::tzif::tzif().parse(stream)?.0)
 }
 
+/// Parses `TZif` data from the given bytes.
+pub fn parse_tzif(bytes: &[u8]) -> Result<TzifData, Error> {
+    Ok(parse::tzif::tzif().parse(bytes)?.0)
+}
+
 /// Parses a POSIX time-zone string from the given bytes.
 pub fn parse_posix_tz_string(bytes: &[u8]) -> Result<PosixTzString, Error> {
     Ok(parse::posix::posix_tz_string().parse(bytes)?.0)
 }
+
+/// Writes `data` as a `TZif` file to `writer`.
+pub fn write_tzif<W: Write>(data: &TzifData, mut writer: W) -> Result<(), Error> {
+    Ok(write::tzif(&mut writer, data)?)
+}
+
+/// Writes `data` as a `TZif` file at the provided `path`.
+pub fn write_tzif_file<P: AsRef<Path>>(data: &TzifData, path: P) -> Result<(), Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write::tzif(&mut writer, data)?;
+    Ok(writer.flush()?)
+}
+
+/// Compiles IANA tzdata source text, such as the `northamerica` file or `tzdata.zi`, into
+/// `TZif` data for each zone and link, keyed by name.
+pub fn compile_tzdata(source: &str) -> Result<BTreeMap<String, TzifData>, Error> {
+    compile::tzdata(source)
+}
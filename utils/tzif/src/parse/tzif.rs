@@ -13,7 +13,8 @@ use crate::data::tzif::{
 use combine::parser::byte::byte;
 use combine::parser::byte::num::{be_i32, be_i64, be_u32};
 use combine::{
-    any, between, choice, count_min_max, one_of, skip_count, value, ParseError, Parser, Stream,
+    any, between, choice, count_min_max, one_of, optional, skip_count, value, ParseError, Parser,
+    Stream,
 };
 
 /// Parses the four-byte ASCII \[RFC20\] sequence `"TZif"` (0x54 0x5A 0x69 0x42),
@@ -755,12 +756,12 @@ where
 /// The `TZif` footer is present only in version 2 and 3 files, as the
 /// obsolescent version 1 format was designed before the need for a
 /// footer was apparent.
-fn footer<Input>() -> impl Parser<Input, Output = PosixTzString>
+fn footer<Input>() -> impl Parser<Input, Output = Option<PosixTzString>>
 where
     Input: Stream<Token = u8>,
     Input::Error: ParseError<Input::Token, Input::Range, Input::Position>,
 {
-    between(byte(b'\n'), byte(b'\n'), posix_tz_string())
+    between(byte(b'\n'), byte(b'\n'), optional(posix_tz_string()))
 }
 
 /// Parses a `TZif` binary file according to the following specification:
@@ -805,7 +806,7 @@ where
                         data_block1: value(block1),
                         header2: value(header2),
                         data_block2: data_block::<2, _>(header).map(Some),
-                        footer: footer().map(Some),
+                        footer: footer(),
                     }
                 }
                 .left(),
@@ -815,7 +816,7 @@ where
                         data_block1: value(block1),
                         header2: value(header2),
                         data_block2: data_block::<3, _>(header).map(Some),
-                        footer: footer().map(Some),
+                        footer: footer(),
                     }
                 }
                 .right(),
@@ -1479,11 +1480,14 @@ mod test {
         // missing final newline
         assert_parse_err!(footer(), "\nEST+5EDT,M3.2.0/2,M11.1.0/2");
 
+        // empty footer
+        assert_parse_eq!(footer(), "\n\n", Option::<PosixTzString>::None);
+
         // valid footer
         assert_parse_eq!(
             footer(),
             "\nEST+5EDT,M3.2.0/2,M11.1.0/2\n",
-            PosixTzString {
+            Some(PosixTzString {
                 std_info: ZoneVariantInfo {
                     name: "EST".to_owned(),
                     offset: Hours(5).as_seconds(),
@@ -1501,8 +1505,8 @@ mod test {
                         day: TransitionDay::Mwd(11, 1, 0),
                         time: Hours(2).as_seconds(),
                     },
-                })
-            }
+                }),
+            })
         );
     }
 }
@@ -0,0 +1,153 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::data::time::Seconds;
+use crate::data::tzif::{DataBlock, StandardWallIndicator, TzifData, UtLocalIndicator};
+use std::io::{self, Write};
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Writes a `TZif` header for `block`.
+///
+/// The counts are computed from the contents of the data block rather than taken from
+/// the parsed header, so that modified data is written consistently.
+fn header<W: Write>(
+    writer: &mut W,
+    version: usize,
+    block: &DataBlock,
+    designations: &[u8],
+) -> io::Result<()> {
+    writer.write_all(b"TZif")?;
+    writer.write_all(&[match version {
+        1 => 0,
+        2 => b'2',
+        3 => b'3',
+        _ => return Err(invalid_data("unsupported TZif version")),
+    }])?;
+    writer.write_all(&[0; 15])?;
+    for count in [
+        block.ut_local_indicators.len(),
+        block.standard_wall_indicators.len(),
+        block.leap_second_records.len(),
+        block.transition_times.len(),
+        block.local_time_type_records.len(),
+        designations.len(),
+    ] {
+        let count =
+            u32::try_from(count).map_err(|_| invalid_data("too many items in data block"))?;
+        writer.write_all(&count.to_be_bytes())?;
+    }
+    Ok(())
+}
+
+/// Writes a four- or eight-byte time value depending on the data block version.
+fn time<const V: usize, W: Write>(writer: &mut W, time: Seconds) -> io::Result<()> {
+    match V {
+        1 => {
+            let time = i32::try_from(time.0)
+                .map_err(|_| invalid_data("time value out of range for a version 1 data block"))?;
+            writer.write_all(&time.to_be_bytes())
+        }
+        _ => writer.write_all(&time.0.to_be_bytes()),
+    }
+}
+
+/// Rebuilds the NUL-terminated time zone designation bytes, placing the designation of
+/// each local time type record at its index.
+fn designations(block: &DataBlock) -> io::Result<Vec<u8>> {
+    if block.time_zone_designations.len() != block.local_time_type_records.len() {
+        return Err(invalid_data(
+            "expected one time zone designation per local time type record",
+        ));
+    }
+    let mut bytes = Vec::new();
+    for (record, designation) in block
+        .local_time_type_records
+        .iter()
+        .zip(&block.time_zone_designations)
+    {
+        let end = record.idx + designation.len();
+        if bytes.len() <= end {
+            bytes.resize(end + 1, 0);
+        }
+        let slot = bytes
+            .get_mut(record.idx..=end)
+            .ok_or_else(|| invalid_data("invalid time zone designation index"))?;
+        let (text, nul) = slot.split_at_mut(designation.len());
+        // Designations may share bytes if one is a suffix of another.
+        if text.iter().any(|&byte| byte != 0) && text != designation.as_bytes() || nul != [0] {
+            return Err(invalid_data("overlapping time zone designations"));
+        }
+        text.copy_from_slice(designation.as_bytes());
+    }
+    if bytes.is_empty() {
+        bytes.push(0);
+    }
+    Ok(bytes)
+}
+
+/// Writes a `TZif` header and data block.
+fn data_block<const V: usize, W: Write>(
+    writer: &mut W,
+    version: usize,
+    block: &DataBlock,
+) -> io::Result<()> {
+    if block.transition_times.len() != block.transition_types.len() {
+        return Err(invalid_data(
+            "expected one transition type per transition time",
+        ));
+    }
+    let designations = designations(block)?;
+    header(writer, version, block, &designations)?;
+    for &transition_time in &block.transition_times {
+        time::<V, _>(writer, transition_time)?;
+    }
+    for &transition_type in &block.transition_types {
+        let transition_type = u8::try_from(transition_type)
+            .ok()
+            .filter(|&t| usize::from(t) < block.local_time_type_records.len())
+            .ok_or_else(|| invalid_data("invalid transition type"))?;
+        writer.write_all(&[transition_type])?;
+    }
+    for record in &block.local_time_type_records {
+        let utoff = i32::try_from(record.utoff.0).map_err(|_| invalid_data("invalid UT offset"))?;
+        writer.write_all(&utoff.to_be_bytes())?;
+        writer.write_all(&[u8::from(record.is_dst)])?;
+        let idx = u8::try_from(record.idx)
+            .map_err(|_| invalid_data("invalid time zone designation index"))?;
+        writer.write_all(&[idx])?;
+    }
+    writer.write_all(&designations)?;
+    for record in &block.leap_second_records {
+        time::<V, _>(writer, record.occurrence)?;
+        writer.write_all(&record.correction.to_be_bytes())?;
+    }
+    for indicator in &block.standard_wall_indicators {
+        writer.write_all(&[u8::from(*indicator == StandardWallIndicator::Standard)])?;
+    }
+    for indicator in &block.ut_local_indicators {
+        writer.write_all(&[u8::from(*indicator == UtLocalIndicator::Ut)])?;
+    }
+    Ok(())
+}
+
+/// Writes `TZif` data according to the following specification:
+/// <https://datatracker.ietf.org/doc/html/rfc8536>
+///
+/// The version-1 data block is always written. The version-2+ header, data block, and
+/// footer are written if `data` has a version-2+ data block.
+pub fn tzif<W: Write>(writer: &mut W, data: &TzifData) -> io::Result<()> {
+    data_block::<1, _>(writer, data.header1.version(), &data.data_block1)?;
+    if let Some(block) = &data.data_block2 {
+        data_block::<2, _>(writer, data.version_number(), block)?;
+        writer.write_all(b"\n")?;
+        if let Some(footer) = &data.footer {
+            write!(writer, "{footer}")?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
@@ -27,3 +27,319 @@ fn parse_tzif_testdata() -> Result<(), tzif::error::Error> {
 fn parse_posix_tz_string() {
     assert!(tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").is_ok());
 }
+
+#[test]
+fn transition_day_of_year() {
+    use tzif::data::posix::TransitionDay;
+
+    // J60 is always March 1
+    assert_eq!(TransitionDay::NoLeap(60).day_of_year(2023), 59);
+    assert_eq!(TransitionDay::NoLeap(60).day_of_year(2024), 60);
+    // 59 is March 1 in common years and February 29 in leap years
+    assert_eq!(TransitionDay::WithLeap(59).day_of_year(2023), 59);
+    assert_eq!(TransitionDay::WithLeap(59).day_of_year(2024), 59);
+    // The second Sunday in March and the first Sunday in November 2023
+    assert_eq!(TransitionDay::Mwd(3, 2, 0).day_of_year(2023), 70);
+    assert_eq!(TransitionDay::Mwd(11, 1, 0).day_of_year(2023), 308);
+    // Week 5 is the last Sunday in October 2023, which only has four
+    assert_eq!(TransitionDay::Mwd(10, 5, 0).day_of_year(2023), 301);
+}
+
+#[test]
+fn posix_tz_string_transitions() {
+    use tzif::data::time::Seconds;
+
+    let posix = tzif::parse_posix_tz_string(b"PST8PDT,M3.2.0,M11.1.0").unwrap();
+    let transitions = posix.transitions_in_year(2023);
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[0].time, Seconds(1678615200));
+    assert_eq!(transitions[0].local_time_type.utoff, Seconds(-7 * 3600));
+    assert_eq!(transitions[0].local_time_type.designation, "PDT");
+    assert_eq!(transitions[1].time, Seconds(1699174800));
+    assert_eq!(transitions[1].local_time_type.utoff, Seconds(-8 * 3600));
+    assert_eq!(transitions[1].local_time_type.designation, "PST");
+
+    assert!(!posix.local_time_type_at(Seconds(1678615199)).is_dst);
+    assert!(posix.local_time_type_at(Seconds(1678615200)).is_dst);
+    assert!(posix.local_time_type_at(Seconds(1699174799)).is_dst);
+    assert!(!posix.local_time_type_at(Seconds(1699174800)).is_dst);
+
+    // Zones without DST have no transitions
+    let posix = tzif::parse_posix_tz_string(b"JST-9").unwrap();
+    assert!(posix.transitions_in_year(2023).is_empty());
+    assert_eq!(
+        posix.local_time_type_at(Seconds(1688169600)).utoff,
+        Seconds(9 * 3600)
+    );
+}
+
+#[test]
+fn posix_tz_string_southern_hemisphere() {
+    use tzif::data::time::Seconds;
+
+    let posix = tzif::parse_posix_tz_string(b"AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    let transitions = posix.transitions_in_year(2023);
+    assert_eq!(transitions[0].time, Seconds(1680364800));
+    assert!(!transitions[0].local_time_type.is_dst);
+    assert_eq!(transitions[1].time, Seconds(1696089600));
+    assert!(transitions[1].local_time_type.is_dst);
+
+    // DST started in the previous year
+    let january = posix.local_time_type_at(Seconds(1673740800));
+    assert_eq!(january.designation, "AEDT");
+    assert_eq!(january.utoff, Seconds(11 * 3600));
+    assert_eq!(
+        posix.local_time_type_at(Seconds(1688169600)).designation,
+        "AEST"
+    );
+}
+
+#[test]
+fn posix_tz_string_julian_days() {
+    use tzif::data::time::Seconds;
+
+    // DST all year round
+    let posix = tzif::parse_posix_tz_string(b"EST5EDT,0/0,J365/25").unwrap();
+    for time in [1672531200, 1672549200, 1688169600, 1704067199] {
+        assert!(posix.local_time_type_at(Seconds(time)).is_dst);
+    }
+}
+
+#[test]
+fn tzif_local_time_type_at() {
+    use tzif::data::time::Seconds;
+
+    let data = tzif::parse_tzif_file("testdata/America/Los_Angeles").unwrap();
+
+    // Before the first transition
+    let lmt = data.local_time_type_at(Seconds(-3786825600)).unwrap();
+    assert_eq!(lmt.designation, "LMT");
+    assert_eq!(lmt.utoff, Seconds(-28378));
+
+    // From the transition table
+    let war_time = data.local_time_type_at(Seconds(-852076800)).unwrap();
+    assert_eq!(war_time.designation, "PWT");
+    assert!(war_time.is_dst);
+
+    // Past the last transition, from the footer
+    let pdt = data.local_time_type_at(Seconds(1688169600)).unwrap();
+    assert_eq!(pdt.designation, "PDT");
+    assert_eq!(pdt.utoff, Seconds(-7 * 3600));
+}
+
+#[test]
+fn write_tzif_testdata_round_trip() {
+    for entry in WalkDir::new("testdata").follow_links(true) {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            let bytes = std::fs::read(entry.path()).unwrap();
+            let data = tzif::parse_tzif_file(entry.path()).unwrap();
+            let mut written = Vec::new();
+            tzif::write_tzif(&data, &mut written).unwrap();
+            assert_eq!(written, bytes, "{:?}", entry.path());
+        }
+    }
+}
+
+#[test]
+fn posix_tz_string_display() {
+    for tz_string in [
+        "PST8PDT,M3.2.0,M11.1.0",
+        "AEST-10AEDT,M10.1.0,M4.1.0/3",
+        "<-03>3",
+        "IST-1GMT0,M10.5.0,M3.5.0/1",
+        "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0",
+        "WGT3WGST,M3.5.0/-2,M10.5.0/-1",
+        "EST5EDT,0/0,J365/25",
+    ] {
+        let posix = tzif::parse_posix_tz_string(tz_string.as_bytes()).unwrap();
+        assert_eq!(posix.to_string(), tz_string);
+    }
+}
+
+const LOS_ANGELES_SOURCE: &str = "
+# Rule  NAME  FROM  TO    -     IN   ON       AT    SAVE  LETTER/S
+Rule    US    1918  1919  -     Mar  lastSun  2:00  1:00  D
+Rule    US    1918  1919  -     Oct  lastSun  2:00  0     S
+Rule    US    1942  only  -     Feb  9        2:00  1:00  W # War
+Rule    US    1945  only  -     Aug  14       23:00u 1:00 P # Peace
+Rule    US    1945  only  -     Sep  30       2:00  0     S
+Rule    US    1967  2006  -     Oct  lastSun  2:00  0     S
+Rule    US    1967  1973  -     Apr  lastSun  2:00  1:00  D
+Rule    US    1974  only  -     Jan  6        2:00  1:00  D
+Rule    US    1975  only  -     Feb  lastSun  2:00  1:00  D
+Rule    US    1976  1986  -     Apr  lastSun  2:00  1:00  D
+Rule    US    1987  2006  -     Apr  Sun>=1   2:00  1:00  D
+Rule    US    2007  max   -     Mar  Sun>=8   2:00  1:00  D
+Rule    US    2007  max   -     Nov  Sun>=1   2:00  0     S
+
+Rule    CA    1948  only  -     Mar  14       2:01  1:00  D
+Rule    CA    1949  only  -     Jan   1       2:00  0     S
+Rule    CA    1950  1966  -     Apr  lastSun  1:00  1:00  D
+Rule    CA    1950  1961  -     Sep  lastSun  2:00  0     S
+Rule    CA    1962  1966  -     Oct  lastSun  2:00  0     S
+
+# Zone  NAME                STDOFF    RULES  FORMAT  [UNTIL]
+Zone    America/Los_Angeles -7:52:58  -      LMT     1883 Nov 18 20:00u
+                            -8:00     US     P%sT    1946
+                            -8:00     CA     P%sT    1967
+                            -8:00     US     P%sT
+
+Link    America/Los_Angeles US/Pacific
+";
+
+#[test]
+fn compile_tzdata_los_angeles() {
+    use tzif::data::time::Seconds;
+
+    let compiled = tzif::compile_tzdata(LOS_ANGELES_SOURCE).unwrap();
+    assert_eq!(
+        compiled.keys().collect::<Vec<_>>(),
+        ["America/Los_Angeles", "US/Pacific"]
+    );
+    let data = &compiled["America/Los_Angeles"];
+    assert_eq!(&compiled["US/Pacific"], data);
+    assert_eq!(
+        data.footer.as_ref().map(ToString::to_string).as_deref(),
+        Some("PST8PDT,M3.2.0,M11.1.0")
+    );
+
+    // The compiled zone agrees with zic's output every few hours from 1850 to 2100.
+    let expected = tzif::parse_tzif_file("testdata/America/Los_Angeles").unwrap();
+    for time in (-3786825600..4102444800).step_by(3 * 3600 + 17) {
+        assert_eq!(
+            data.local_time_type_at(Seconds(time)),
+            expected.local_time_type_at(Seconds(time)),
+            "{time}"
+        );
+    }
+
+    // The written data parses back to the same data.
+    let mut bytes = Vec::new();
+    tzif::write_tzif(data, &mut bytes).unwrap();
+    assert_eq!(&tzif::parse_tzif(&bytes).unwrap(), data);
+}
+
+#[test]
+fn compile_tzdata_errors() {
+    use tzif::error::Error;
+
+    assert!(matches!(
+        tzif::compile_tzdata("Zone Etc/Test 0 Nonexistent %s"),
+        Err(Error::Compile { line: 1, .. })
+    ));
+    assert!(matches!(
+        tzif::compile_tzdata("\nRule US 1918 1919 - Mar lastSun 2:00 1:00"),
+        Err(Error::Compile { line: 2, .. })
+    ));
+    assert!(matches!(
+        tzif::compile_tzdata("Link Etc/Nonexistent Etc/Test"),
+        Err(Error::Compile { line: 1, .. })
+    ));
+
+    // Values that would overflow the date and time arithmetic
+    for source in [
+        "Zone Test/Zone 0:00 - UTC 99999999999999999",
+        "Zone Test/Zone 0:00 - UTC max",
+        "Zone Test/Zone 0:00 - UTC 2000 Jan 1 9999999999999999:00",
+        "Zone Test/Zone 99999999999999999:00 - UTC",
+        "Zone Test/Zone 0:00 - UTC\nRule US 1918 99999999999 - Mar lastSun 2:00 1:00",
+        "Zone Test/Zone 0:00 - UTC\nRule US 1918 only - Mar lastSun 9999999999:00 1:00",
+    ] {
+        assert!(
+            matches!(tzif::compile_tzdata(source), Err(Error::Compile { .. })),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn leap_second_conversions() {
+    use tzif::data::leap::UtcTime;
+    use tzif::data::time::Seconds;
+
+    let data = tzif::parse_tzif_file("testdata/right/UTC").unwrap();
+    let leap_seconds = data.leap_seconds();
+    assert_eq!(leap_seconds.records().len(), 27);
+
+    // 2017-01-01T00:00:00Z, just after the leap second 2016-12-31T23:59:60Z
+    let new_year = Seconds(1483228800);
+    assert_eq!(
+        leap_seconds.tai_minus_utc(new_year - Seconds(1)),
+        Seconds(36)
+    );
+    assert_eq!(leap_seconds.tai_minus_utc(new_year), Seconds(37));
+    // Before the first leap second
+    assert_eq!(leap_seconds.tai_minus_utc(Seconds(0)), Seconds(10));
+
+    let leap_second = UtcTime {
+        posix: new_year - Seconds(1),
+        is_leap_second: true,
+    };
+    assert_eq!(leap_second.second(), 60);
+    let tai = leap_seconds.tai_from_utc(leap_second);
+    assert_eq!(tai, Seconds(1483228836));
+    assert_eq!(leap_seconds.utc_from_tai(tai), leap_second);
+    assert_eq!(
+        leap_seconds.utc_from_tai(tai - Seconds(1)),
+        UtcTime::from_posix(new_year - Seconds(1))
+    );
+    assert_eq!(
+        leap_seconds.utc_from_tai(tai + Seconds(1)),
+        UtcTime::from_posix(new_year)
+    );
+    assert_eq!(leap_seconds.posix_from_tai(tai), new_year - Seconds(1));
+    assert_eq!(leap_seconds.tai_from_posix(new_year), tai + Seconds(1));
+    assert!(leap_seconds.is_leap_second(tai - Seconds(10)));
+
+    // Leap time and UTC round-trip around every leap second
+    for record in leap_seconds.records() {
+        for offset in -2..=2 {
+            let leap_time = record.occurrence + Seconds(offset);
+            let utc = leap_seconds.utc_from_leap_time(leap_time);
+            assert_eq!(utc.is_leap_second, offset == 0);
+            assert_eq!(leap_seconds.leap_time_from_utc(utc), leap_time);
+        }
+    }
+
+    // A flag on a second that is not a leap second is ignored
+    assert_eq!(
+        leap_seconds.tai_from_utc(UtcTime {
+            posix: new_year,
+            is_leap_second: true,
+        }),
+        tai + Seconds(1)
+    );
+}
+
+#[test]
+fn negative_leap_second_conversions() {
+    use tzif::data::leap::{LeapSeconds, UtcTime};
+    use tzif::data::time::Seconds;
+    use tzif::data::tzif::LeapSecondRecord;
+
+    // A positive leap second at the end of day 9, and a negative one at the end of day 19
+    let records = [
+        LeapSecondRecord {
+            occurrence: Seconds(10 * 86400),
+            correction: 1,
+        },
+        LeapSecondRecord {
+            occurrence: Seconds(20 * 86400),
+            correction: 0,
+        },
+    ];
+    let leap_seconds = LeapSeconds::new(&records);
+
+    // 23:59:58 on day 19 is followed by 00:00:00 on day 20
+    let before = leap_seconds.utc_from_leap_time(Seconds(20 * 86400 - 1));
+    let after = leap_seconds.utc_from_leap_time(Seconds(20 * 86400));
+    assert_eq!(before, UtcTime::from_posix(Seconds(20 * 86400 - 2)));
+    assert_eq!(after, UtcTime::from_posix(Seconds(20 * 86400)));
+    assert_eq!(
+        leap_seconds.leap_time_from_utc(before),
+        Seconds(20 * 86400 - 1)
+    );
+    assert_eq!(leap_seconds.leap_time_from_utc(after), Seconds(20 * 86400));
+    assert_eq!(leap_seconds.tai_minus_utc(Seconds(20 * 86400)), Seconds(10));
+}
@@ -313,6 +313,49 @@ where
         let key1_index = self.get_key1_index_by(predicate)?;
         self.get1_copied_at(key1_index)
     }
+
+    /// Gets the greatest key1 that is less than or equal to `key1`, and a copy of its value,
+    /// or `None` if all keys1 of this cursor are greater than `key1`.
+    ///
+    /// ```rust
+    /// use zerovec::ule::AsULE;
+    /// use zerovec::ZeroMap2d;
+    ///
+    /// let mut map: ZeroMap2d<u16, u16, u16> = ZeroMap2d::new();
+    /// map.insert(&1, &2, &3);
+    /// map.insert(&1, &4, &5);
+    ///
+    /// let cursor = map.get0(&1).unwrap();
+    /// let get = |key1| {
+    ///     cursor
+    ///         .get1_copied_at_or_before(&key1)
+    ///         .map(|(k, v)| (u16::from_unaligned(*k), v))
+    /// };
+    /// assert_eq!(get(1), None);
+    /// assert_eq!(get(2), Some((2, 3)));
+    /// assert_eq!(get(3), Some((2, 3)));
+    /// assert_eq!(get(9), Some((4, 5)));
+    /// ```
+    pub fn get1_copied_at_or_before(&self, key1: &K1) -> Option<(&'l K1::GetType, V)> {
+        let range = self.get_range();
+        debug_assert!(range.start < range.end); // '<' because every key0 should have a key1
+        debug_assert!(range.end <= self.keys1.zvl_len());
+        let start = range.start;
+        #[allow(clippy::expect_used)] // protected by the debug_assert above
+        let key1_index = match self
+            .keys1
+            .zvl_binary_search_in_range(key1, range)
+            .expect("in-bounds range")
+        {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        } + start;
+        Some((
+            self.keys1.zvl_get(key1_index)?,
+            self.get1_copied_at(key1_index)?,
+        ))
+    }
 }
 
 // We can't use the default PartialEq because ZeroMap2d is invariant
@@ -356,3 +399,58 @@ where
             .finish()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::ule::AsULE;
+    use crate::ZeroMap2d;
+
+    fn get(zm2d: &ZeroMap2d<u16, u16, u16>, key0: u16, key1: u16) -> Option<(u16, u16)> {
+        zm2d.get0(&key0)?
+            .get1_copied_at_or_before(&key1)
+            .map(|(k, v)| (u16::from_unaligned(*k), v))
+    }
+
+    #[test]
+    fn get1_copied_at_or_before() {
+        let mut zm2d = ZeroMap2d::<u16, u16, u16>::new();
+        zm2d.insert(&3, &10, &100);
+        zm2d.insert(&3, &20, &200);
+        zm2d.insert(&5, &15, &150);
+        zm2d.insert(&7, &1, &10);
+        zm2d.insert(&7, &30, &300);
+        zm2d.insert(&7, &40, &400);
+
+        // Empty key1 range: a key0 without key1s has no cursor
+        assert_eq!(zm2d.get0(&4), None);
+        assert_eq!(get(&zm2d, 4, 15), None);
+
+        // Below the first key1 of each range, even if a previous range has smaller keys1
+        assert_eq!(get(&zm2d, 3, 9), None);
+        assert_eq!(get(&zm2d, 5, 14), None);
+        assert_eq!(get(&zm2d, 7, 0), None);
+
+        // Exact matches
+        assert_eq!(get(&zm2d, 3, 10), Some((10, 100)));
+        assert_eq!(get(&zm2d, 3, 20), Some((20, 200)));
+        assert_eq!(get(&zm2d, 5, 15), Some((15, 150)));
+        assert_eq!(get(&zm2d, 7, 1), Some((1, 10)));
+        assert_eq!(get(&zm2d, 7, 30), Some((30, 300)));
+        assert_eq!(get(&zm2d, 7, 40), Some((40, 400)));
+
+        // Between keys1
+        assert_eq!(get(&zm2d, 3, 19), Some((10, 100)));
+        assert_eq!(get(&zm2d, 7, 29), Some((1, 10)));
+        assert_eq!(get(&zm2d, 7, 31), Some((30, 300)));
+
+        // Past the last key1 of each range, even if a following range has larger keys1
+        assert_eq!(get(&zm2d, 3, 21), Some((20, 200)));
+        assert_eq!(get(&zm2d, 5, u16::MAX), Some((15, 150)));
+        assert_eq!(get(&zm2d, 7, u16::MAX), Some((40, 400)));
+
+        // A key0 whose only key1 was removed has no cursor either
+        zm2d.remove(&5, &15);
+        assert_eq!(zm2d.get0(&5), None);
+        assert_eq!(get(&zm2d, 5, 15), None);
+    }
+}